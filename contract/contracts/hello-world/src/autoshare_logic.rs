@@ -1,15 +1,29 @@
 use crate::base::errors::Error;
 use crate::base::events::{
     emit_contribution, emit_distribution, AdminTransferred, AutoshareCreated, AutoshareUpdated,
-    ContractPaused, ContractUnpaused, FundraisingStarted, GroupActivated, GroupDeactivated,
-    GroupDeleted, GroupNameUpdated, Withdrawal,
+    ContractPaused, ContractUnpaused, FundingRoundCreated, FundingRoundFinalized,
+    FundingRoundJoined, FundraisingCancelled, FundraisingClosed, FundraisingDeadlineExtended,
+    FundraisingStarted, FundraisingTargetUpdated, GroupActivated, GroupDeactivated, GroupDeleted,
+    GroupNameUpdated, GroupTtlExtended, InviteAccepted, InviteCancelled, ManagerRemoved,
+    ManagerUpdated, MatchingPoolFunded, MatchingPoolReleased, MemberInvited, MemberLeft,
+    OwnershipTransferProposed, OwnershipTransferred, PayoutAddressUpdated, PledgeCancelled,
+    PledgeCreated, ProposalCreated, ProposalResolved, SplitChangeCancelled, SplitChangeDropped,
+    SplitChangeQueued, VestingScheduleUpdated, VoteCast, Withdrawal,
 };
 
 use crate::base::types::{
-    AutoShareDetails, DistributionHistory, DistributionRecord, FundraisingConfig,
-    FundraisingContribution, GroupMember, GroupStats, MemberAmount, PaymentHistory,
+    AutoShareDetails, ContributionRules, DistributionHistory, DistributionRecord, FundingRound,
+    FundraisingConfig, FundraisingContribution, GovernanceConfig, GroupCursor, GroupFilter,
+    GroupManager, GroupMember, GroupQueryPage, GroupStats, GroupStatsView, GroupTtlCursor,
+    GroupTtlProgress, GroupTtlReport, ManagerScope, MatchingPool, MemberAmount,
+    MemberGroupOverview, MemberInvite, MemberOverview, NoticePeriod, OverfundingPolicy,
+    PaymentHistory, PendingSplitChange, Pledge, Proposal, ProposalAction, ProposalStatus,
+    ProtocolStats, RebalanceStrategy, ReportBucket, ReportInterval, RoundGroupTally, SplitVersion,
+    TokenAmount, UnvestedRedirect, VestingSchedule,
+};
+use soroban_sdk::{
+    contracttype, token, Address, BytesN, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec,
 };
-use soroban_sdk::{contracttype, token, Address, BytesN, Env, String, Vec};
 
 extern crate alloc;
 use alloc::string::String as AllocString;
@@ -18,27 +32,180 @@ use alloc::string::ToString;
 #[contracttype]
 pub enum DataKey {
     AutoShare(BytesN<32>),
-    AllGroups,
+    AllGroups, // Legacy id list, moved into `RegistryKey` entries on first access
     Admin,
     SupportedTokens,
     UsageFee,
+    // Legacy single-entry histories, read in place until `migrate_history` moves them into pages
     UserPaymentHistory(Address),
     GroupPaymentHistory(BytesN<32>),
     GroupDistributionHistory(BytesN<32>),
     MemberDistributions(Address),
-    MemberGroupEarnings(Address, BytesN<32>),
+    MemberGroupEarnings(Address, BytesN<32>), // Legacy sum over all tokens, see `EarningsKey`
     GroupFundraising(BytesN<32>),
-    GroupContributions(BytesN<32>),
+    GroupContributions(BytesN<32>), // Legacy single-entry list, see `HistoryLog::GroupContributions`
     UserContributions(Address),
     GroupStats(BytesN<32>),
     IsPaused,
     MemberGroups(Address),
+    ContributionRules(BytesN<32>),
+    FundraisingAllowlist(BytesN<32>),
+    CampaignContributorTotal(BytesN<32>, u32, Address),
+    PledgeCount,
+    Pledge(u32),
+    GroupPledges(BytesN<32>),
+    MatchingPool(BytesN<32>),
+    RoundCount,
+    FundingRound(u32),
+    RoundGroupTally(u32, BytesN<32>),
+    RoundContribution(u32, BytesN<32>, Address),
+    GroupRound(BytesN<32>),
+    GroupInvites(BytesN<32>),
+    GroupManagers(BytesN<32>),
+    PendingOwner(BytesN<32>),
+    NoticePeriod(BytesN<32>),
+    PendingSplitChange(BytesN<32>),
+    SplitVersionCount(BytesN<32>),
+    SplitVersion(BytesN<32>, u32),
+    MemberVesting(BytesN<32>, Address),
+    CreatorGroups(Address),
+    GroupTokens(BytesN<32>),
+    GroupExtendedUntil(BytesN<32>),
+    GroupTtlProgress(BytesN<32>),
+    VestingCount(BytesN<32>), // Members of a group with a vesting schedule
+}
+
+/// Storage keys for group governance, kept apart from `DataKey` to leave room in that enum.
+#[contracttype]
+#[derive(Clone)]
+pub enum GovernanceKey {
+    Config(BytesN<32>),
+    ProposalCount,
+    Proposal(u32),
+    GroupProposals(BytesN<32>),
+    Vote(u32, Address),
+    /// Members and their percentages when a proposal was opened, used to weigh its votes.
+    Weights(u32),
+}
+
+/// Storage keys for the group registry: a dense index of group ids so that adding, removing and
+/// paging through groups touches a fixed number of entries.
+#[contracttype]
+#[derive(Clone)]
+pub enum RegistryKey {
+    Count,
+    IdAt(u32),
+    IndexOf(BytesN<32>),
+    /// Marks the id of a deleted group. Its statistics, earnings and reports outlive it, so the
+    /// id is never handed out again.
+    Deleted(BytesN<32>),
+}
+
+/// Storage keys for member earnings, tracked per token.
+#[contracttype]
+#[derive(Clone)]
+pub enum EarningsKey {
+    /// Earnings of a member from one group in one token.
+    Group(Address, BytesN<32>, Address),
+    /// Earnings of a member from all groups in one token.
+    Lifetime(Address, Address),
+}
+
+/// Storage keys for deployment-wide statistics counters.
+#[contracttype]
+#[derive(Clone)]
+pub enum StatsKey {
+    ActiveGroups,
+    TotalMembers,
+    TotalDistributions,
+    TotalContributions,
+    Tokens,
+    DistributedVolume(Address),
+    FeesCollected(Address),
+    AmountRaised(Address),
+    GroupDistributionCount(BytesN<32>),
+    GroupDistributed(BytesN<32>, Address),
+    GroupRaised(BytesN<32>, Address),
+    GroupSenderCount(BytesN<32>),
+    GroupSender(BytesN<32>, Address),
+    GroupLastActivity(BytesN<32>),
+    // Set once a group's activity and members are included in ActiveGroups and TotalMembers
+    GroupCounted(BytesN<32>),
+}
+
+/// Storage keys for time-bucketed report totals, by interval and bucket number.
+#[contracttype]
+#[derive(Clone)]
+pub enum ReportKey {
+    GroupVolume(BytesN<32>, Address, ReportInterval, u32),
+    // A member's daily earnings in a token within one `YYYYMM` month, so a payout updates one
+    // entry per member
+    MemberEarnings(Address, Address, u32),
+    FeeRevenue(Address, ReportInterval, u32),
+}
+
+/// The append-only histories kept by the contract.
+#[contracttype]
+#[derive(Clone)]
+pub enum HistoryLog {
+    UserPayments(Address),
+    GroupPayments(BytesN<32>),
+    GroupDistributions(BytesN<32>),
+    MemberDistributions(Address),
+    GroupContributions(BytesN<32>),
+}
+
+/// Storage keys for paged histories: an entry counter plus fixed-size pages of entries.
+#[contracttype]
+#[derive(Clone)]
+pub enum HistoryKey {
+    Count(HistoryLog),
+    Page(HistoryLog, u32),
+    Migrated(HistoryLog), // Legacy pages already handled by `migrate_history`
+}
+
+/// Layout of `FundraisingConfig` before campaigns gained deadlines, overfunding policies and
+/// campaign ids. Configs stored in this shape are upgraded when read.
+#[contracttype]
+#[derive(Clone)]
+pub struct LegacyFundraisingConfig {
+    pub target_amount: i128,
+    pub total_raised: i128,
+    pub is_active: bool,
+}
+
+/// Layout of `DistributionHistory` entries recorded before distributions referenced a split
+/// version. Such entries are upgraded when a legacy history is moved into pages.
+#[contracttype]
+#[derive(Clone)]
+pub struct LegacyDistributionHistory {
+    pub group_id: BytesN<32>,
+    pub sender: Address,
+    pub total_amount: i128,
+    pub token: Address,
+    pub member_amounts: Vec<MemberAmount>,
+    pub timestamp: u64,
+    pub distribution_number: u32,
 }
 
 const DAY_IN_LEDGERS: u32 = 17280;
 const PERSISTENT_BUMP_THRESHOLD: u32 = 7 * DAY_IN_LEDGERS; // 1 week
 const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS; // 30 days
 const MAX_MEMBERS: u32 = 50; // Maximum number of members per group to prevent DoS
+const MAX_ALLOWLIST_SIZE: u32 = 100; // Maximum number of allowlisted contributors per group
+const MAX_ROUND_GROUPS: u32 = 50; // Maximum number of groups in one funding round
+const MAX_MANAGERS: u32 = 20; // Maximum number of managers per group
+const MAX_GROUP_PLEDGES: u32 = 50; // Maximum number of active pledges per group
+const MAX_QUERY_LIMIT: u32 = 50; // Maximum groups returned by one query
+const MAX_QUERY_SCAN: u32 = 100; // Maximum groups examined by one query
+const MAX_REPORT_BUCKETS: u32 = 100; // Maximum buckets covered by one report query
+const MAX_TTL_SCAN: u32 = 30; // Maximum storage keys examined by one group TTL call
+const SECONDS_PER_DAY: u64 = 86_400;
+const OVERVIEW_RECENT_DISTRIBUTIONS: u32 = 10; // Payouts included in a member overview
+const HISTORY_PAGE_SIZE: u32 = 50; // Entries stored per history page
+const DISTRIBUTION_PAGE_SIZE: u32 = 10; // Entries per page of histories appended by `distribute`
+const MAX_HISTORY_LIMIT: u32 = 50; // Maximum entries returned by one history query
+const VESTING_SCALE: i128 = 10_000; // Vested fractions are tracked in basis points
 
 fn bump_persistent<K: soroban_sdk::IntoVal<Env, soroban_sdk::Val>>(env: &Env, key: &K) {
     if env.storage().persistent().has(key) {
@@ -50,6 +217,416 @@ fn bump_persistent<K: soroban_sdk::IntoVal<Env, soroban_sdk::Val>>(env: &Env, ke
     }
 }
 
+// ============================================================================
+// Storage TTL
+// ============================================================================
+
+// Sections of a group's storage walked by `extend_group_ttl` and `get_group_ttl`, in order
+const TTL_FIXED: u32 = 0; // One entry per item, see `group_fixed_keys`
+const TTL_SPLIT_VERSIONS: u32 = 1; // Item is the version number minus one
+const TTL_MEMBERS: u32 = 2; // Item is the member's position
+const TTL_MEMBER_HISTORY: u32 = 3; // Item is the member's position, offset the page
+const TTL_GROUP_HISTORY: u32 = 4; // Item is the log (payments, distributions), offset the page
+const TTL_DISTRIBUTIONS: u32 = 5; // Item is a distribution history page
+const TTL_PROPOSALS: u32 = 6; // Item is the proposal's position
+const TTL_PLEDGES: u32 = 7; // Item is the pledge's position
+const TTL_CONTRIBUTIONS: u32 = 8; // Item is a contribution history page, offset the entry
+const TTL_SECTIONS: u32 = 9;
+
+/// Extends the next `limit` storage entries belonging to a group to the maximum TTL, continuing
+/// the walk left by the previous call. Returns the ledger the walk extended entries to at least
+/// once it is complete, or `None` while entries remain. Open to anyone, since the caller pays for the
+/// extension.
+pub fn extend_group_ttl(env: Env, id: BytesN<32>, limit: u32) -> Result<Option<u32>, Error> {
+    let details = get_autoshare(env.clone(), id.clone())?;
+    let progress_key = DataKey::GroupTtlProgress(id.clone());
+    let progress: GroupTtlProgress =
+        env.storage()
+            .persistent()
+            .get(&progress_key)
+            .unwrap_or(GroupTtlProgress {
+                cursor: GroupTtlCursor {
+                    section: TTL_FIXED,
+                    item: 0,
+                    offset: 0,
+                },
+                live_until_ledger: env.ledger().max_live_until_ledger(),
+            });
+    let (keys, cursor) = walk_group_keys(&env, &details, progress.cursor, limit);
+
+    let max_ttl = env.storage().max_ttl();
+    for key in keys.iter() {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, max_ttl, max_ttl);
+    }
+
+    GroupTtlExtended {
+        id: id.clone(),
+        live_until_ledger: env.ledger().max_live_until_ledger(),
+        entries: keys.len(),
+    }
+    .publish(&env);
+
+    if cursor.section < TTL_SECTIONS {
+        env.storage().persistent().set(
+            &progress_key,
+            &GroupTtlProgress {
+                cursor,
+                live_until_ledger: progress.live_until_ledger,
+            },
+        );
+        bump_persistent(&env, &progress_key);
+        return Ok(None);
+    }
+
+    env.storage().persistent().remove(&progress_key);
+    let extended_key = DataKey::GroupExtendedUntil(id);
+    env.storage()
+        .persistent()
+        .set(&extended_key, &progress.live_until_ledger);
+    env.storage()
+        .persistent()
+        .extend_ttl(&extended_key, max_ttl, max_ttl);
+    Ok(Some(progress.live_until_ledger))
+}
+
+/// Lists a page of the group's storage keys with `extended_until`, the ledger recorded by the last
+/// completed `extend_group_ttl` walk. Entries written or created since may live for less.
+pub fn get_group_ttl(
+    env: Env,
+    id: BytesN<32>,
+    cursor: Option<GroupTtlCursor>,
+    limit: u32,
+) -> Result<GroupTtlReport, Error> {
+    let details = get_autoshare(env.clone(), id.clone())?;
+    let extended_until: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::GroupExtendedUntil(id))
+        .unwrap_or(0);
+    let cursor = cursor.unwrap_or(GroupTtlCursor {
+        section: TTL_FIXED,
+        item: 0,
+        offset: 0,
+    });
+    let (keys, next_cursor) = walk_group_keys(&env, &details, cursor, limit);
+
+    Ok(GroupTtlReport {
+        extended_until,
+        current_ledger: env.ledger().sequence(),
+        keys,
+        complete: next_cursor.section >= TTL_SECTIONS,
+        next_cursor,
+    })
+}
+
+/// Keys collected by a group storage walk, and how many candidates it checked.
+struct KeyWalk {
+    keys: Vec<Val>,
+    examined: u32,
+}
+
+impl KeyWalk {
+    fn push<K>(&mut self, env: &Env, key: K)
+    where
+        K: IntoVal<Env, Val>,
+    {
+        let key: Val = key.into_val(env);
+        self.examined += 1;
+        if env.storage().persistent().has(&key) && !self.keys.contains(key) {
+            self.keys.push_back(key);
+        }
+    }
+}
+
+/// Collects the keys of existing persistent entries that belong to a group, starting at
+/// `cursor` and stopping once about `limit` keys were examined (at most `MAX_TTL_SCAN`). Returns
+/// the keys and the cursor to continue from. Entries added to a list behind the cursor during
+/// a walk are picked up by the next one.
+fn walk_group_keys(
+    env: &Env,
+    details: &AutoShareDetails,
+    cursor: GroupTtlCursor,
+    limit: u32,
+) -> (Vec<Val>, GroupTtlCursor) {
+    let id = &details.id;
+    let limit = limit.clamp(1, MAX_TTL_SCAN);
+    let storage = env.storage().persistent();
+    let fixed = group_fixed_keys(env, details);
+    let tokens = get_group_tokens(env, id);
+    // Indexes are only loaded once the walk reaches their section, contributions a page at a time
+    let mut proposals: Option<Vec<u32>> = None;
+    let mut pledges: Option<Vec<u32>> = None;
+    let contributions_log = HistoryLog::GroupContributions(id.clone());
+    let mut contribution_count: Option<u32> = None;
+    let mut contribution_page: Option<(u32, Vec<FundraisingContribution>)> = None;
+    let campaign_id = read_fundraising(env, id).map(|config| config.campaign_id);
+    let round_id: Option<u32> = storage.get(&DataKey::GroupRound(id.clone()));
+    let histories = [
+        HistoryLog::GroupPayments(id.clone()),
+        HistoryLog::GroupDistributions(id.clone()),
+    ];
+    let pages = |log: &HistoryLog| -> u32 {
+        let count: u32 = storage.get(&HistoryKey::Count(log.clone())).unwrap_or(0);
+        count.div_ceil(history_page_size(log))
+    };
+
+    let mut walk = KeyWalk {
+        keys: Vec::new(env),
+        examined: 0,
+    };
+    let mut cursor = cursor;
+    while cursor.section < TTL_SECTIONS && walk.examined < limit {
+        let item = cursor.item;
+        let member = details.members.get(item).map(|member| member.address);
+        // Whether the current item has a further page to visit
+        let mut next_offset = false;
+        let mut found = true;
+        match cursor.section {
+            TTL_FIXED => match fixed.get(item) {
+                Some(key) => walk.push(env, key),
+                None => found = false,
+            },
+            TTL_SPLIT_VERSIONS => {
+                if item < get_current_split_version(env, id) {
+                    walk.push(env, DataKey::SplitVersion(id.clone(), item + 1));
+                } else {
+                    found = false;
+                }
+            }
+            TTL_MEMBERS => match member {
+                Some(address) => {
+                    walk.push(env, DataKey::MemberGroups(address.clone()));
+                    walk.push(env, DataKey::MemberVesting(id.clone(), address.clone()));
+                    walk.push(
+                        env,
+                        DataKey::MemberGroupEarnings(address.clone(), id.clone()),
+                    );
+                    walk.push(
+                        env,
+                        HistoryKey::Count(HistoryLog::MemberDistributions(address.clone())),
+                    );
+                    for token in tokens.iter() {
+                        walk.push(
+                            env,
+                            EarningsKey::Group(address.clone(), id.clone(), token.clone()),
+                        );
+                        walk.push(env, EarningsKey::Lifetime(address.clone(), token));
+                    }
+                }
+                None => found = false,
+            },
+            TTL_MEMBER_HISTORY => match member {
+                Some(address) => {
+                    let log = HistoryLog::MemberDistributions(address);
+                    if cursor.offset < pages(&log) {
+                        walk.push(env, HistoryKey::Page(log, cursor.offset));
+                        next_offset = true;
+                    }
+                }
+                None => found = false,
+            },
+            TTL_GROUP_HISTORY => match histories.get(item as usize) {
+                Some(log) => {
+                    if cursor.offset < pages(log) {
+                        walk.push(env, HistoryKey::Page(log.clone(), cursor.offset));
+                        next_offset = true;
+                    }
+                }
+                None => found = false,
+            },
+            TTL_DISTRIBUTIONS => {
+                let log = HistoryLog::GroupDistributions(id.clone());
+                if item < pages(&log) {
+                    let page: Vec<DistributionHistory> = storage
+                        .get(&HistoryKey::Page(log, item))
+                        .unwrap_or(Vec::new(env));
+                    for entry in page.iter() {
+                        walk.push(env, StatsKey::GroupSender(id.clone(), entry.sender));
+                        for interval in [ReportInterval::Daily, ReportInterval::Monthly] {
+                            walk.push(
+                                env,
+                                ReportKey::GroupVolume(
+                                    id.clone(),
+                                    entry.token.clone(),
+                                    interval,
+                                    report_period(interval, entry.timestamp),
+                                ),
+                            );
+                        }
+                    }
+                } else {
+                    found = false;
+                }
+            }
+            TTL_PROPOSALS => match proposals
+                .get_or_insert_with(|| {
+                    storage
+                        .get(&GovernanceKey::GroupProposals(id.clone()))
+                        .unwrap_or(Vec::new(env))
+                })
+                .get(item)
+            {
+                Some(proposal_id) => {
+                    walk.push(env, GovernanceKey::Proposal(proposal_id));
+                    walk.push(env, GovernanceKey::Weights(proposal_id));
+                    let voters: Vec<GroupMember> = storage
+                        .get(&GovernanceKey::Weights(proposal_id))
+                        .unwrap_or(Vec::new(env));
+                    for voter in voters.iter() {
+                        walk.push(env, GovernanceKey::Vote(proposal_id, voter.address));
+                    }
+                }
+                None => found = false,
+            },
+            // The pledge index holds at most `MAX_GROUP_PLEDGES` ids
+            TTL_PLEDGES => match pledges
+                .get_or_insert_with(|| {
+                    storage
+                        .get(&DataKey::GroupPledges(id.clone()))
+                        .unwrap_or(Vec::new(env))
+                })
+                .get(item)
+            {
+                Some(pledge_id) => walk.push(env, DataKey::Pledge(pledge_id)),
+                None => found = false,
+            },
+            TTL_CONTRIBUTIONS => {
+                let count =
+                    *contribution_count.get_or_insert_with(|| history_len(env, &contributions_log));
+                let page_size = history_page_size(&contributions_log);
+                if item * page_size + cursor.offset < count {
+                    if cursor.offset == 0 {
+                        walk.push(env, HistoryKey::Page(contributions_log.clone(), item));
+                    }
+                    if !matches!(contribution_page, Some((page_number, _)) if page_number == item) {
+                        contribution_page =
+                            Some((item, read_history_page(env, &contributions_log, item)));
+                    }
+                    let contributor = contribution_page
+                        .as_ref()
+                        .and_then(|(_, page)| page.get(cursor.offset))
+                        .map(|contribution| contribution.contributor);
+                    if let Some(contributor) = contributor {
+                        if let Some(campaign_id) = campaign_id {
+                            walk.push(
+                                env,
+                                DataKey::CampaignContributorTotal(
+                                    id.clone(),
+                                    campaign_id,
+                                    contributor.clone(),
+                                ),
+                            );
+                        }
+                        if let Some(round_id) = round_id {
+                            walk.push(
+                                env,
+                                DataKey::RoundContribution(round_id, id.clone(), contributor),
+                            );
+                        }
+                    }
+                    next_offset = cursor.offset + 1 < page_size;
+                } else {
+                    found = false;
+                }
+            }
+            _ => found = false,
+        }
+
+        cursor = if !found {
+            GroupTtlCursor {
+                section: cursor.section + 1,
+                item: 0,
+                offset: 0,
+            }
+        } else if next_offset {
+            GroupTtlCursor {
+                offset: cursor.offset + 1,
+                ..cursor
+            }
+        } else {
+            GroupTtlCursor {
+                item: item + 1,
+                offset: 0,
+                ..cursor
+            }
+        };
+    }
+    (walk.keys, cursor)
+}
+
+/// Keys of the group's entries that exist at most once per group: its record, indexes,
+/// settings, fundraising, history counts, governance and statistics.
+fn group_fixed_keys(env: &Env, details: &AutoShareDetails) -> Vec<Val> {
+    let id = &details.id;
+    let mut keys: Vec<Val> = Vec::new(env);
+
+    keys.push_back(DataKey::AutoShare(id.clone()).into_val(env));
+    keys.push_back(DataKey::CreatorGroups(details.creator.clone()).into_val(env));
+    keys.push_back(RegistryKey::IndexOf(id.clone()).into_val(env));
+    if let Some(index) = env
+        .storage()
+        .persistent()
+        .get::<_, u32>(&RegistryKey::IndexOf(id.clone()))
+    {
+        keys.push_back(RegistryKey::IdAt(index).into_val(env));
+    }
+
+    for key in [
+        DataKey::GroupTokens(id.clone()),
+        DataKey::GroupInvites(id.clone()),
+        DataKey::GroupManagers(id.clone()),
+        DataKey::PendingOwner(id.clone()),
+        DataKey::NoticePeriod(id.clone()),
+        DataKey::PendingSplitChange(id.clone()),
+        DataKey::SplitVersionCount(id.clone()),
+        DataKey::GroupFundraising(id.clone()),
+        DataKey::GroupContributions(id.clone()),
+        DataKey::GroupStats(id.clone()),
+        DataKey::ContributionRules(id.clone()),
+        DataKey::FundraisingAllowlist(id.clone()),
+        DataKey::GroupPledges(id.clone()),
+        DataKey::MatchingPool(id.clone()),
+        DataKey::GroupRound(id.clone()),
+        DataKey::VestingCount(id.clone()),
+        DataKey::GroupPaymentHistory(id.clone()),
+        DataKey::GroupDistributionHistory(id.clone()),
+    ] {
+        keys.push_back(key.into_val(env));
+    }
+    if let Some(round_id) = env
+        .storage()
+        .persistent()
+        .get::<_, u32>(&DataKey::GroupRound(id.clone()))
+    {
+        keys.push_back(DataKey::RoundGroupTally(round_id, id.clone()).into_val(env));
+    }
+
+    for log in [
+        HistoryLog::GroupPayments(id.clone()),
+        HistoryLog::GroupDistributions(id.clone()),
+        HistoryLog::GroupContributions(id.clone()),
+    ] {
+        keys.push_back(HistoryKey::Count(log).into_val(env));
+    }
+    keys.push_back(GovernanceKey::Config(id.clone()).into_val(env));
+    keys.push_back(GovernanceKey::GroupProposals(id.clone()).into_val(env));
+
+    for key in [
+        StatsKey::GroupDistributionCount(id.clone()),
+        StatsKey::GroupSenderCount(id.clone()),
+        StatsKey::GroupLastActivity(id.clone()),
+    ] {
+        keys.push_back(key.into_val(env));
+    }
+    for token in get_group_tokens(env, id).iter() {
+        keys.push_back(StatsKey::GroupDistributed(id.clone(), token.clone()).into_val(env));
+        keys.push_back(StatsKey::GroupRaised(id.clone(), token).into_val(env));
+    }
+    keys
+}
+
 fn is_valid_name(name: &String) -> bool {
     let alloc_str: AllocString = name.to_string();
     let trimmed = alloc_str.trim();
@@ -88,6 +665,14 @@ pub fn create_autoshare(
         bump_persistent(&env, &key);
         return Err(Error::AlreadyExists);
     }
+    // A deleted group's id would inherit the state it left behind
+    if env
+        .storage()
+        .persistent()
+        .has(&RegistryKey::Deleted(id.clone()))
+    {
+        return Err(Error::AlreadyExists);
+    }
 
     // Validate usage count
     if usage_count == 0 {
@@ -121,16 +706,11 @@ pub fn create_autoshare(
     env.storage().persistent().set(&key, &details);
     bump_persistent(&env, &key);
 
-    // Add to all groups list
-    let all_groups_key = DataKey::AllGroups;
-    let mut all_groups: Vec<BytesN<32>> = env
-        .storage()
-        .persistent()
-        .get(&all_groups_key)
-        .unwrap_or(Vec::new(&env));
-    all_groups.push_back(id.clone());
-    env.storage().persistent().set(&all_groups_key, &all_groups);
-    bump_persistent(&env, &all_groups_key);
+    // Add to the group registry and the creator's index
+    register_group(&env, &id);
+    add_group_to_creator_index(&env, &creator, &id);
+    mark_group_counted(&env, &id);
+    add_to_stat(&env, StatsKey::ActiveGroups, 1);
 
     // Record payment history
     record_payment(
@@ -139,6 +719,7 @@ pub fn create_autoshare(
         id.clone(),
         usage_count,
         total_cost,
+        payment_token,
     );
 
     AutoshareCreated {
@@ -158,22 +739,91 @@ pub fn get_autoshare(env: Env, id: BytesN<32>) -> Result<AutoShareDetails, Error
     result.ok_or(Error::NotFound)
 }
 
-fn get_all_group_ids(env: &Env) -> Vec<BytesN<32>> {
-    let all_groups_key = DataKey::AllGroups;
-    let group_ids: Vec<BytesN<32>> = env
-        .storage()
+// ============================================================================
+// Group Registry
+// ============================================================================
+
+/// Returns the number of registered groups, moving a legacy `AllGroups` list into the registry
+/// the first time it is touched.
+fn registry_len(env: &Env) -> u32 {
+    let count_key = RegistryKey::Count;
+    if let Some(count) = env.storage().persistent().get::<_, u32>(&count_key) {
+        bump_persistent(env, &count_key);
+        return count;
+    }
+
+    let legacy: Option<Vec<BytesN<32>>> = env.storage().persistent().get(&DataKey::AllGroups);
+    let Some(legacy) = legacy else {
+        return 0;
+    };
+    for (index, id) in legacy.iter().enumerate() {
+        write_registry_slot(env, index as u32, &id);
+    }
+    env.storage().persistent().set(&count_key, &legacy.len());
+    bump_persistent(env, &count_key);
+    env.storage().persistent().remove(&DataKey::AllGroups);
+    legacy.len()
+}
+
+fn write_registry_slot(env: &Env, index: u32, id: &BytesN<32>) {
+    let id_key = RegistryKey::IdAt(index);
+    env.storage().persistent().set(&id_key, id);
+    bump_persistent(env, &id_key);
+    let index_key = RegistryKey::IndexOf(id.clone());
+    env.storage().persistent().set(&index_key, &index);
+    bump_persistent(env, &index_key);
+}
+
+fn register_group(env: &Env, id: &BytesN<32>) {
+    let count = registry_len(env);
+    write_registry_slot(env, count, id);
+    env.storage()
         .persistent()
-        .get(&all_groups_key)
-        .unwrap_or(Vec::new(env));
-    if !group_ids.is_empty() {
-        bump_persistent(env, &all_groups_key);
+        .set(&RegistryKey::Count, &(count + 1));
+    bump_persistent(env, &RegistryKey::Count);
+}
+
+/// Removes a group by moving the last registered group into its slot.
+fn unregister_group(env: &Env, id: &BytesN<32>) {
+    let count = registry_len(env);
+    let index_key = RegistryKey::IndexOf(id.clone());
+    let Some(index) = env.storage().persistent().get::<_, u32>(&index_key) else {
+        return;
+    };
+
+    let last = count - 1;
+    if index != last {
+        if let Some(last_id) = registry_id_at(env, last) {
+            write_registry_slot(env, index, &last_id);
+        }
+    }
+    env.storage().persistent().remove(&RegistryKey::IdAt(last));
+    env.storage().persistent().remove(&index_key);
+    env.storage().persistent().set(&RegistryKey::Count, &last);
+    bump_persistent(env, &RegistryKey::Count);
+}
+
+fn registry_id_at(env: &Env, index: u32) -> Option<BytesN<32>> {
+    let key = RegistryKey::IdAt(index);
+    let result: Option<BytesN<32>> = env.storage().persistent().get(&key);
+    if result.is_some() {
+        bump_persistent(env, &key);
+    }
+    result
+}
+
+fn get_all_group_ids(env: &Env) -> Vec<BytesN<32>> {
+    let mut group_ids: Vec<BytesN<32>> = Vec::new(env);
+    for index in 0..registry_len(env) {
+        if let Some(id) = registry_id_at(env, index) {
+            group_ids.push_back(id);
+        }
     }
     group_ids
 }
 
 pub fn get_group_count(env: Env) -> u32 {
-    let group_ids = get_all_group_ids(&env);
-    group_ids.len()
+    registry_len(&env)
 }
 
 pub fn get_all_groups(env: Env) -> Vec<AutoShareDetails> {
@@ -188,163 +838,452 @@ pub fn get_all_groups(env: Env) -> Vec<AutoShareDetails> {
 }
 
 pub fn get_groups_by_creator(env: Env, creator: Address) -> Vec<AutoShareDetails> {
-    let group_ids = get_all_group_ids(&env);
+    let group_ids = get_creator_group_ids(&env, &creator);
     let mut result: Vec<AutoShareDetails> = Vec::new(&env);
 
     for id in group_ids.iter() {
         if let Ok(group) = get_autoshare(env.clone(), id) {
-            if group.creator == creator {
-                result.push_back(group);
-            }
+            result.push_back(group);
         }
     }
     result
 }
 
+/// Offset pagination over the registry, kept for existing clients on top of `query_groups`.
+/// Offsets count from the newest group, so a deletion between calls can shift groups across
+/// pages.
 pub fn get_groups_paginated(
     env: Env,
     start_index: u32,
     limit: u32,
 ) -> crate::base::types::GroupPage {
-    let group_ids = get_all_group_ids(&env);
-    let total = group_ids.len();
+    let total = registry_len(&env);
 
     // Cap limit at 20 as per requirement
     let actual_limit = limit.min(20);
 
-    let mut groups: Vec<AutoShareDetails> = Vec::new(&env);
-
-    if actual_limit > 0 && start_index < total {
-        let end = start_index.saturating_add(actual_limit).min(total);
-        for i in start_index..end {
-            if let Some(id) = group_ids.get(i) {
-                if let Ok(details) = get_autoshare(env.clone(), id) {
-                    groups.push_back(details);
-                }
-            }
-        }
-    }
+    let cursor = GroupCursor {
+        remaining: total.saturating_sub(start_index),
+        last_id: None,
+    };
+    let page = query_groups(env, group_filter(None), Some(cursor), actual_limit);
 
     crate::base::types::GroupPage {
-        groups,
+        groups: page.groups,
         total,
         offset: start_index,
         limit: actual_limit,
     }
 }
 
+/// Returns up to `limit` (at most 20) groups created by `creator`, newest first, continuing from
+/// `cursor` like `query_groups`.
 pub fn get_groups_by_creator_paginated(
     env: Env,
     creator: Address,
-    offset: u32,
+    cursor: Option<GroupCursor>,
     limit: u32,
-) -> crate::base::types::GroupPage {
-    let group_ids = get_all_group_ids(&env);
+) -> GroupQueryPage {
+    query_groups(env, group_filter(Some(creator)), cursor, limit.min(20))
+}
 
-    // Cap limit at 20 as per requirement
-    let actual_limit = limit.min(20);
-    if actual_limit == 0 {
-        return crate::base::types::GroupPage {
-            groups: Vec::new(&env),
-            total: 0,
-            offset,
-            limit: actual_limit,
-        };
+/// A filter matching every group, or every group of one creator.
+fn group_filter(creator: Option<Address>) -> GroupFilter {
+    GroupFilter {
+        is_active: None,
+        creator,
+        member: None,
+        fundraising_active: false,
+        usages_below: None,
+        token: None,
     }
+}
 
-    let mut groups: Vec<AutoShareDetails> = Vec::new(&env);
-    let mut total_matches = 0;
-    let mut matches_returned = 0;
-
-    for id in group_ids.iter() {
-        if let Ok(details) = get_autoshare(env.clone(), id) {
-            if details.creator == creator {
-                if total_matches >= offset && matches_returned < actual_limit {
-                    groups.push_back(details);
-                    matches_returned += 1;
-                }
-                total_matches += 1;
-            }
-        }
+fn get_creator_group_ids(env: &Env, creator: &Address) -> Vec<BytesN<32>> {
+    let key = DataKey::CreatorGroups(creator.clone());
+    let group_ids: Vec<BytesN<32>> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    if !group_ids.is_empty() {
+        bump_persistent(env, &key);
     }
+    group_ids
+}
 
-    crate::base::types::GroupPage {
-        groups,
-        total: total_matches,
-        offset,
-        limit: actual_limit,
+fn add_group_to_creator_index(env: &Env, creator: &Address, id: &BytesN<32>) {
+    let key = DataKey::CreatorGroups(creator.clone());
+    let mut group_ids: Vec<BytesN<32>> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+
+    if !group_ids.contains(id) {
+        group_ids.push_back(id.clone());
+        env.storage().persistent().set(&key, &group_ids);
     }
+    bump_persistent(env, &key);
 }
 
-pub fn get_groups_by_member(env: Env, member: Address) -> Vec<AutoShareDetails> {
-    let key = DataKey::MemberGroups(member);
-    let group_ids: Vec<BytesN<32>> = env
+fn remove_group_from_creator_index(env: &Env, creator: &Address, id: &BytesN<32>) {
+    let key = DataKey::CreatorGroups(creator.clone());
+    let mut group_ids: Vec<BytesN<32>> = env
         .storage()
         .persistent()
         .get(&key)
-        .unwrap_or(Vec::new(&env));
+        .unwrap_or(Vec::new(env));
 
-    if !group_ids.is_empty() {
-        bump_persistent(&env, &key);
+    if let Some(index) = group_ids.first_index_of(id) {
+        group_ids.remove(index);
+        env.storage().persistent().set(&key, &group_ids);
+        bump_persistent(env, &key);
     }
+}
 
-    let mut result: Vec<AutoShareDetails> = Vec::new(&env);
+/// Returns up to `limit` groups matching `filter`, newest first. Groups are examined from the end
+/// of their list towards the start, continuing from `cursor` (`None` for the first call), and at
+/// most `MAX_QUERY_SCAN` are examined per call, so a page may come back short while `next_cursor`
+/// still has groups remaining. Deletions only ever move examined groups into unexamined slots,
+/// so a walk never skips a group, although a group moved by a deletion can be returned twice.
+/// Member and creator filters walk their indexes instead of the full registry.
+pub fn query_groups(
+    env: Env,
+    filter: GroupFilter,
+    cursor: Option<GroupCursor>,
+    limit: u32,
+) -> GroupQueryPage {
+    let source = if let Some(member) = &filter.member {
+        let key = DataKey::MemberGroups(member.clone());
+        let ids: Option<Vec<BytesN<32>>> = env.storage().persistent().get(&key);
+        if ids.is_some() {
+            bump_persistent(&env, &key);
+        }
+        Some(ids.unwrap_or(Vec::new(&env)))
+    } else {
+        filter
+            .creator
+            .as_ref()
+            .map(|creator| get_creator_group_ids(&env, creator))
+    };
+    let total = match &source {
+        Some(ids) => ids.len(),
+        None => registry_len(&env),
+    };
+    let id_at = |position: u32| match &source {
+        Some(ids) => ids.get(position),
+        None => registry_id_at(&env, position),
+    };
 
-    for id in group_ids.iter() {
-        if let Ok(group) = get_autoshare(env.clone(), id.clone()) {
-            if group.is_active {
-                result.push_back(group);
-            } else {
-                // To match behavior where inactive groups are still returned by `get_autoshare`,
-                // we include them. If they should be skipped, we add a check here.
-                result.push_back(group);
+    let (mut position, mut last_id) = match cursor {
+        Some(cursor) => (cursor.remaining.min(total), cursor.last_id),
+        None => (total, None),
+    };
+    // Removing an earlier entry from an index shifts the last examined group down into the next
+    // slot, so step over it
+    if position > 0 && last_id.is_some() && id_at(position - 1) == last_id {
+        position -= 1;
+    }
+
+    let limit = limit.min(MAX_QUERY_LIMIT);
+    let mut groups: Vec<AutoShareDetails> = Vec::new(&env);
+    let scan_end = position.saturating_sub(MAX_QUERY_SCAN);
+    while position > scan_end && groups.len() < limit {
+        position -= 1;
+        let Some(id) = id_at(position) else {
+            continue;
+        };
+        if let Ok(details) = get_autoshare(env.clone(), id.clone()) {
+            if group_matches(&env, &details, &filter) {
+                groups.push_back(details);
             }
         }
+        last_id = Some(id);
     }
 
-    result
+    GroupQueryPage {
+        groups,
+        next_cursor: GroupCursor {
+            remaining: position,
+            last_id,
+        },
+    }
 }
 
-pub fn is_group_member(env: Env, id: BytesN<32>, address: Address) -> Result<bool, Error> {
-    let details = get_autoshare(env, id)?;
-
-    for member in details.members.iter() {
-        if member.address == address {
-            return Ok(true);
+fn group_matches(env: &Env, details: &AutoShareDetails, filter: &GroupFilter) -> bool {
+    if filter
+        .is_active
+        .is_some_and(|active| active != details.is_active)
+    {
+        return false;
+    }
+    if filter
+        .creator
+        .as_ref()
+        .is_some_and(|creator| *creator != details.creator)
+    {
+        return false;
+    }
+    if filter
+        .member
+        .as_ref()
+        .is_some_and(|member| member_weight(&details.members, member) == 0)
+    {
+        return false;
+    }
+    if filter
+        .usages_below
+        .is_some_and(|usages| details.usage_count >= usages)
+    {
+        return false;
+    }
+    if filter.fundraising_active
+        && !get_fundraising_status(env.clone(), details.id.clone()).is_active
+    {
+        return false;
+    }
+    if let Some(token) = &filter.token {
+        if !get_group_tokens(env, &details.id).contains(token) {
+            return false;
         }
     }
-    Ok(false)
+    true
 }
 
-pub fn get_group_members(env: Env, id: BytesN<32>) -> Result<Vec<GroupMember>, Error> {
-    let details = get_autoshare(env, id)?;
-    Ok(details.members)
+fn get_group_tokens(env: &Env, id: &BytesN<32>) -> Vec<Address> {
+    let key = DataKey::GroupTokens(id.clone());
+    let tokens: Option<Vec<Address>> = env.storage().persistent().get(&key);
+    if tokens.is_some() {
+        bump_persistent(env, &key);
+    }
+    tokens.unwrap_or(Vec::new(env))
 }
 
-pub fn add_group_member(
+fn add_group_token(env: &Env, id: &BytesN<32>, token: &Address) {
+    let key = DataKey::GroupTokens(id.clone());
+    let mut tokens = get_group_tokens(env, id);
+    if !tokens.contains(token) {
+        tokens.push_back(token.clone());
+        env.storage().persistent().set(&key, &tokens);
+        bump_persistent(env, &key);
+    }
+}
+
+/// Adds up to `limit` registered groups, starting at registry position `start_index`, to their
+/// creators' indexes. Lets deployments that predate the creator index backfill it in batches.
+/// Admin only.
+pub fn backfill_creator_index(
     env: Env,
-    id: BytesN<32>,
-    caller: Address,
+    admin: Address,
+    start_index: u32,
+    limit: u32,
+) -> Result<u32, Error> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    let end = start_index.saturating_add(limit).min(registry_len(&env));
+    let mut indexed = 0;
+    for i in start_index..end {
+        if let Some(id) = registry_id_at(&env, i) {
+            if let Ok(details) = get_autoshare(env.clone(), id.clone()) {
+                add_group_to_creator_index(&env, &details.creator, &id);
+                indexed += 1;
+            }
+        }
+    }
+    Ok(indexed)
+}
+
+/// Indexes the tokens of up to `limit` entries of a group's distribution history, starting at
+/// entry `start_index`, so the `query_groups` token filter also sees payouts made before the token
+/// index existed. Returns how many entries were examined. Admin only.
+pub fn backfill_group_tokens(
+    env: Env,
+    admin: Address,
+    id: BytesN<32>,
+    start_index: u32,
+    limit: u32,
+) -> Result<u32, Error> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    let entries: Vec<DistributionHistory> = read_history(
+        &env,
+        HistoryLog::GroupDistributions(id.clone()),
+        start_index,
+        limit,
+    );
+    for entry in entries.iter() {
+        add_group_token(&env, &id, &entry.token);
+    }
+    Ok(entries.len())
+}
+
+pub fn get_groups_by_member(env: Env, member: Address) -> Vec<AutoShareDetails> {
+    let key = DataKey::MemberGroups(member);
+    let group_ids: Vec<BytesN<32>> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(&env));
+
+    if !group_ids.is_empty() {
+        bump_persistent(&env, &key);
+    }
+
+    let mut result: Vec<AutoShareDetails> = Vec::new(&env);
+
+    for id in group_ids.iter() {
+        if let Ok(group) = get_autoshare(env.clone(), id.clone()) {
+            if group.is_active {
+                result.push_back(group);
+            } else {
+                // To match behavior where inactive groups are still returned by `get_autoshare`,
+                // we include them. If they should be skipped, we add a check here.
+                result.push_back(group);
+            }
+        }
+    }
+
+    result
+}
+
+/// Returns every group the member belongs to with their share, the group's status and their
+/// per-token earnings from it, plus their most recent payouts.
+pub fn get_member_overview(env: Env, member: Address) -> MemberOverview {
+    let mut groups: Vec<MemberGroupOverview> = Vec::new(&env);
+    for group in get_groups_by_member(env.clone(), member.clone()).iter() {
+        let mut earnings: Vec<TokenAmount> = Vec::new(&env);
+        for token in get_group_tokens(&env, &group.id).iter() {
+            let amount = read_earnings(
+                &env,
+                &EarningsKey::Group(member.clone(), group.id.clone(), token.clone()),
+            );
+            if amount > 0 {
+                earnings.push_back(TokenAmount { token, amount });
+            }
+        }
+        let unattributed_earnings = read_earnings(
+            &env,
+            &DataKey::MemberGroupEarnings(member.clone(), group.id.clone()),
+        );
+        groups.push_back(MemberGroupOverview {
+            percentage: member_weight(&group.members, &member),
+            group_id: group.id,
+            name: group.name,
+            is_active: group.is_active,
+            earnings,
+            unattributed_earnings,
+        });
+    }
+
+    let log = HistoryLog::MemberDistributions(member);
+    let count = history_len(&env, &log);
+    let recent_distributions = read_history(
+        &env,
+        log,
+        count.saturating_sub(OVERVIEW_RECENT_DISTRIBUTIONS),
+        OVERVIEW_RECENT_DISTRIBUTIONS,
+    );
+
+    MemberOverview {
+        groups,
+        recent_distributions,
+    }
+}
+
+pub fn is_group_member(env: Env, id: BytesN<32>, address: Address) -> Result<bool, Error> {
+    let details = get_autoshare(env, id)?;
+
+    for member in details.members.iter() {
+        if member.address == address {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+pub fn get_group_members(env: Env, id: BytesN<32>) -> Result<Vec<GroupMember>, Error> {
+    let details = get_autoshare(env, id)?;
+    Ok(details.members)
+}
+
+pub fn add_group_member(
+    env: Env,
+    id: BytesN<32>,
+    caller: Address,
     address: Address,
     percentage: u32,
+    strategy: RebalanceStrategy,
 ) -> Result<(), Error> {
     // Require caller auth and check pause
     caller.require_auth();
+    // The new member has to consent to joining, like accepting an invite
+    if address != caller {
+        address.require_auth();
+    }
 
     if get_paused_status(&env) {
         return Err(Error::ContractPaused);
     }
 
     let key = DataKey::AutoShare(id.clone());
-    let mut details: AutoShareDetails = env
+    let details: AutoShareDetails = env
         .storage()
         .persistent()
         .get(&key)
         .ok_or(Error::NotFound)?;
     bump_persistent(&env, &key);
 
-    // Only the group creator can add members
-    if details.creator != caller {
+    // Only the creator or a member manager can add members
+    if !has_permission(&env, &details, &caller, Permission::ManageMembers) {
+        return Err(Error::Unauthorized);
+    }
+
+    if !details.is_active {
+        return Err(Error::GroupInactive);
+    }
+
+    if is_governed(&env, &id) {
+        return Err(Error::GovernanceRequired);
+    }
+
+    // Any strategy other than `Exact` takes the newcomer's share from existing members
+    if get_notice_period(env.clone(), id.clone()) > 0 && strategy != RebalanceStrategy::Exact {
+        return queue_split_change(
+            &env,
+            id,
+            ProposalAction::AddMember(address, percentage, strategy),
+            caller,
+        );
+    }
+
+    insert_group_member(&env, id, details, address, percentage, strategy, caller)
+}
+
+pub fn remove_group_member(
+    env: Env,
+    id: BytesN<32>,
+    caller: Address,
+    member_address: Address,
+    strategy: RebalanceStrategy,
+) -> Result<(), Error> {
+    caller.require_auth();
+
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let key = DataKey::AutoShare(id.clone());
+    let details: AutoShareDetails = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::NotFound)?;
+    bump_persistent(&env, &key);
+
+    if !has_permission(&env, &details, &caller, Permission::ManageMembers) {
         return Err(Error::Unauthorized);
     }
 
@@ -352,6 +1291,37 @@ pub fn add_group_member(
         return Err(Error::GroupInactive);
     }
 
+    if is_governed(&env, &id) {
+        return Err(Error::GovernanceRequired);
+    }
+
+    if get_notice_period(env.clone(), id.clone()) > 0
+        && member_weight(&details.members, &member_address) > 0
+    {
+        return queue_split_change(
+            &env,
+            id,
+            ProposalAction::RemoveMember(member_address, strategy),
+            caller,
+        );
+    }
+
+    drop_group_member(&env, id, details, member_address, strategy, caller)
+}
+
+/// Appends a member to an active group and adds the group to their index. `strategy` decides
+/// which existing shares shrink to make room for the newcomer.
+fn insert_group_member(
+    env: &Env,
+    id: BytesN<32>,
+    mut details: AutoShareDetails,
+    address: Address,
+    percentage: u32,
+    strategy: RebalanceStrategy,
+    updater: Address,
+) -> Result<(), Error> {
+    let key = DataKey::AutoShare(id.clone());
+
     // Check if already a member
     for member in details.members.iter() {
         if member.address == address {
@@ -365,6 +1335,7 @@ pub fn add_group_member(
     }
 
     // Add new member
+    details.members = make_room_for_member(env, &details.members, percentage, strategy)?;
     details.members.push_back(GroupMember {
         address: address.clone(),
         percentage,
@@ -375,7 +1346,9 @@ pub fn add_group_member(
 
     // Save updated details
     env.storage().persistent().set(&key, &details);
-    bump_persistent(&env, &key);
+    bump_persistent(env, &key);
+    add_group_stat(env, &id, StatsKey::TotalMembers, 1);
+    record_split_version(env, &id, &details.members, &updater);
 
     // Update MemberGroups index
     let member_groups_key = DataKey::MemberGroups(address.clone());
@@ -383,61 +1356,47 @@ pub fn add_group_member(
         .storage()
         .persistent()
         .get(&member_groups_key)
-        .unwrap_or(Vec::new(&env));
+        .unwrap_or(Vec::new(env));
 
     member_groups.push_back(id.clone());
     env.storage()
         .persistent()
         .set(&member_groups_key, &member_groups);
-    bump_persistent(&env, &member_groups_key);
+    bump_persistent(env, &member_groups_key);
 
     Ok(())
 }
 
-pub fn remove_group_member(
-    env: Env,
+/// Removes a member from an active group and drops the group from their index. `strategy`
+/// decides who receives the freed share.
+fn drop_group_member(
+    env: &Env,
     id: BytesN<32>,
-    caller: Address,
+    mut details: AutoShareDetails,
     member_address: Address,
+    strategy: RebalanceStrategy,
+    updater: Address,
 ) -> Result<(), Error> {
-    caller.require_auth();
-
-    if get_paused_status(&env) {
-        return Err(Error::ContractPaused);
-    }
-
     let key = DataKey::AutoShare(id.clone());
-    let mut details: AutoShareDetails = env
-        .storage()
-        .persistent()
-        .get(&key)
-        .ok_or(Error::NotFound)?;
-    bump_persistent(&env, &key);
-
-    if details.creator != caller {
-        return Err(Error::Unauthorized);
-    }
-
-    if !details.is_active {
-        return Err(Error::GroupInactive);
-    }
 
-    let mut found = false;
-    let mut new_members: Vec<GroupMember> = Vec::new(&env);
+    let mut freed: Option<u32> = None;
+    let mut new_members: Vec<GroupMember> = Vec::new(env);
     for member in details.members.iter() {
         if member.address == member_address {
-            found = true;
+            freed = Some(member.percentage);
         } else {
             new_members.push_back(member.clone());
         }
     }
-    if !found {
-        return Err(Error::MemberNotFound);
-    }
+    let freed = freed.ok_or(Error::MemberNotFound)?;
+    let new_members = hand_out_freed_share(env, &new_members, freed, strategy)?;
+    remove_vesting(env, &id, &member_address);
 
     details.members = new_members.clone();
     env.storage().persistent().set(&key, &details);
-    bump_persistent(&env, &key);
+    bump_persistent(env, &key);
+    add_group_stat(env, &id, StatsKey::TotalMembers, -1);
+    record_split_version(env, &id, &details.members, &updater);
 
     // Update MemberGroups index
     let member_groups_key = DataKey::MemberGroups(member_address.clone());
@@ -445,9 +1404,9 @@ pub fn remove_group_member(
         .storage()
         .persistent()
         .get(&member_groups_key)
-        .unwrap_or(Vec::new(&env));
+        .unwrap_or(Vec::new(env));
 
-    let mut new_member_groups: Vec<BytesN<32>> = Vec::new(&env);
+    let mut new_member_groups: Vec<BytesN<32>> = Vec::new(env);
     let mut group_removed = false;
     for group_id in member_groups.iter() {
         if group_id != id {
@@ -461,268 +1420,342 @@ pub fn remove_group_member(
         env.storage()
             .persistent()
             .set(&member_groups_key, &new_member_groups);
-        bump_persistent(&env, &member_groups_key);
+        bump_persistent(env, &member_groups_key);
     }
 
     AutoshareUpdated {
         id: id.clone(),
-        updater: caller,
+        updater,
     }
-    .publish(&env);
+    .publish(env);
     Ok(())
 }
 
-// ============================================================================
-// Admin Management
-// ============================================================================
-
-pub fn initialize_admin(env: Env, admin: Address) {
-    admin.require_auth();
-    let admin_key = DataKey::Admin;
-
-    // Only set if not already initialized
-    if !env.storage().persistent().has(&admin_key) {
-        env.storage().persistent().set(&admin_key, &admin);
-        bump_persistent(&env, &admin_key);
+/// Replaces the full member list of an active group and keeps member indexes in sync.
+fn replace_group_members(
+    env: &Env,
+    id: BytesN<32>,
+    mut details: AutoShareDetails,
+    new_members: Vec<GroupMember>,
+    updater: Address,
+) -> Result<(), Error> {
+    let key = DataKey::AutoShare(id.clone());
 
-        // Initialize default usage fee (10 tokens per usage)
-        let usage_fee_key = DataKey::UsageFee;
-        env.storage().persistent().set(&usage_fee_key, &10u32);
-        bump_persistent(&env, &usage_fee_key);
+    // Validate new members
+    if new_members.is_empty() {
+        return Err(Error::EmptyMembers);
+    }
 
-        // Initialize empty supported tokens list
-        let tokens_key = DataKey::SupportedTokens;
-        let empty_tokens: Vec<Address> = Vec::new(&env);
-        env.storage().persistent().set(&tokens_key, &empty_tokens);
-        bump_persistent(&env, &tokens_key);
-    } else {
-        bump_persistent(&env, &admin_key);
+    // Check if new members count exceeds MAX_MEMBERS
+    if new_members.len() > MAX_MEMBERS {
+        return Err(Error::MaxMembersExceeded);
     }
-}
 
-fn require_admin(env: &Env, caller: &Address) -> Result<(), Error> {
-    let admin_key = DataKey::Admin;
-    let admin: Address = env
-        .storage()
-        .persistent()
-        .get(&admin_key)
-        .ok_or(Error::Unauthorized)?;
-    bump_persistent(env, &admin_key);
+    let mut total_percentage: u32 = 0;
+    let mut seen_addresses = Vec::new(env);
 
-    if admin != *caller {
-        return Err(Error::Unauthorized);
-    }
+    for member in new_members.iter() {
+        if member.percentage == 0 {
+            return Err(Error::InvalidInput);
+        }
+        total_percentage += member.percentage;
 
-    Ok(())
-}
+        for seen in seen_addresses.iter() {
+            if seen == member.address {
+                return Err(Error::DuplicateMember);
+            }
+        }
+        seen_addresses.push_back(member.address.clone());
+    }
 
-pub fn get_admin(env: Env) -> Result<Address, Error> {
-    let admin_key = DataKey::Admin;
-    let result: Option<Address> = env.storage().persistent().get(&admin_key);
-    if result.is_some() {
-        bump_persistent(&env, &admin_key);
+    if total_percentage != 100 {
+        return Err(Error::InvalidTotalPercentage);
     }
-    result.ok_or(Error::NotFound)
-}
 
-pub fn transfer_admin(env: Env, current_admin: Address, new_admin: Address) -> Result<(), Error> {
-    current_admin.require_auth();
-    require_admin(&env, &current_admin)?;
+    // Determine old members for index updating
+    let old_members = details.members.clone();
 
-    let admin_key = DataKey::Admin;
-    env.storage().persistent().set(&admin_key, &new_admin);
-    bump_persistent(&env, &admin_key);
-    AdminTransferred {
-        old_admin: current_admin,
-        new_admin,
-    }
-    .publish(&env);
-    Ok(())
-}
+    // Update members in details
+    details.members = new_members.clone();
+    env.storage().persistent().set(&key, &details);
+    bump_persistent(env, &key);
+    add_group_stat(
+        env,
+        &id,
+        StatsKey::TotalMembers,
+        new_members.len() as i128 - old_members.len() as i128,
+    );
+    record_split_version(env, &id, &details.members, &updater);
 
-// ============================================================================
-// Pause Management
-// ============================================================================
+    // Update MemberGroups index for removed and added members
+    for old_member in old_members.iter() {
+        let mut found_in_new = false;
+        for new_member in new_members.iter() {
+            if old_member.address == new_member.address {
+                found_in_new = true;
+                break;
+            }
+        }
+        if !found_in_new {
+            // Member was removed, drop their schedule and remove group from their index
+            remove_vesting(env, &id, &old_member.address);
+            let member_groups_key = DataKey::MemberGroups(old_member.address.clone());
+            let member_groups: Vec<BytesN<32>> = env
+                .storage()
+                .persistent()
+                .get(&member_groups_key)
+                .unwrap_or(Vec::new(env));
 
-pub fn pause(env: Env, admin: Address) -> Result<(), Error> {
-    admin.require_auth();
-    require_admin(&env, &admin)?;
+            let mut updated_member_groups: Vec<BytesN<32>> = Vec::new(env);
+            let mut group_removed = false;
+            for group_id in member_groups.iter() {
+                if group_id != id {
+                    updated_member_groups.push_back(group_id);
+                } else {
+                    group_removed = true;
+                }
+            }
+            if group_removed {
+                env.storage()
+                    .persistent()
+                    .set(&member_groups_key, &updated_member_groups);
+                bump_persistent(env, &member_groups_key);
+            }
+        }
+    }
 
-    let pause_key = DataKey::IsPaused;
-    let is_paused: bool = env.storage().persistent().get(&pause_key).unwrap_or(false);
-    bump_persistent(&env, &pause_key);
+    for new_member in new_members.iter() {
+        let mut found_in_old = false;
+        for old_member in old_members.iter() {
+            if new_member.address == old_member.address {
+                found_in_old = true;
+                break;
+            }
+        }
+        if !found_in_old {
+            // Member was added, add group to their index
+            let member_groups_key = DataKey::MemberGroups(new_member.address.clone());
+            let mut member_groups: Vec<BytesN<32>> = env
+                .storage()
+                .persistent()
+                .get(&member_groups_key)
+                .unwrap_or(Vec::new(env));
 
-    if is_paused {
-        return Err(Error::AlreadyPaused);
+            member_groups.push_back(id.clone());
+            env.storage()
+                .persistent()
+                .set(&member_groups_key, &member_groups);
+            bump_persistent(env, &member_groups_key);
+        }
     }
 
-    env.storage().persistent().set(&pause_key, &true);
-    bump_persistent(&env, &pause_key);
-    ContractPaused {}.publish(&env);
+    AutoshareUpdated {
+        id: id.clone(),
+        updater,
+    }
+    .publish(env);
     Ok(())
 }
 
-pub fn unpause(env: Env, admin: Address) -> Result<(), Error> {
-    admin.require_auth();
-    require_admin(&env, &admin)?;
-
-    let pause_key = DataKey::IsPaused;
-    let is_paused: bool = env.storage().persistent().get(&pause_key).unwrap_or(false);
-    bump_persistent(&env, &pause_key);
-
-    if !is_paused {
-        return Err(Error::NotPaused);
+/// Shrinks existing shares so that a newcomer with `percentage` fits into a full split.
+fn make_room_for_member(
+    env: &Env,
+    members: &Vec<GroupMember>,
+    percentage: u32,
+    strategy: RebalanceStrategy,
+) -> Result<Vec<GroupMember>, Error> {
+    match strategy {
+        RebalanceStrategy::Exact => Ok(members.clone()),
+        RebalanceStrategy::ProRata => {
+            if percentage >= 100 {
+                return Err(Error::InvalidInput);
+            }
+            scale_split(env, members, 100 - percentage)
+        }
+        RebalanceStrategy::Member(donor) => {
+            let mut updated = members.clone();
+            for (idx, member) in members.iter().enumerate() {
+                if member.address == donor {
+                    if member.percentage <= percentage {
+                        return Err(Error::InvalidInput);
+                    }
+                    updated.set(
+                        idx as u32,
+                        GroupMember {
+                            address: member.address,
+                            percentage: member.percentage - percentage,
+                        },
+                    );
+                    return Ok(updated);
+                }
+            }
+            Err(Error::MemberNotFound)
+        }
     }
-
-    env.storage().persistent().set(&pause_key, &false);
-    bump_persistent(&env, &pause_key);
-    ContractUnpaused {}.publish(&env);
-    Ok(())
 }
 
-pub fn get_paused_status(env: &Env) -> bool {
-    let pause_key = DataKey::IsPaused;
-    let is_paused: bool = env.storage().persistent().get(&pause_key).unwrap_or(false);
-    if is_paused {
-        bump_persistent(env, &pause_key);
+/// Gives the share freed by a departing member back to the remaining members.
+fn hand_out_freed_share(
+    env: &Env,
+    remaining: &Vec<GroupMember>,
+    freed: u32,
+    strategy: RebalanceStrategy,
+) -> Result<Vec<GroupMember>, Error> {
+    match strategy {
+        RebalanceStrategy::Exact => Ok(remaining.clone()),
+        RebalanceStrategy::ProRata => scale_split(env, remaining, 100),
+        RebalanceStrategy::Member(recipient) => {
+            let mut updated = remaining.clone();
+            for (idx, member) in remaining.iter().enumerate() {
+                if member.address == recipient {
+                    updated.set(
+                        idx as u32,
+                        GroupMember {
+                            address: member.address,
+                            percentage: member.percentage + freed,
+                        },
+                    );
+                    return Ok(updated);
+                }
+            }
+            Err(Error::MemberNotFound)
+        }
     }
-    is_paused
 }
 
 // ============================================================================
-// Supported Tokens Management
+// Split Versions
 // ============================================================================
 
-pub fn add_supported_token(env: Env, token: Address, admin: Address) -> Result<(), Error> {
-    admin.require_auth();
-    require_admin(&env, &admin)?;
+/// Stores the member set after a change as the next numbered split version.
+fn record_split_version(env: &Env, id: &BytesN<32>, members: &Vec<GroupMember>, author: &Address) {
+    let version = get_current_split_version(env, id) + 1;
+    let split = SplitVersion {
+        version,
+        members: members.clone(),
+        created_at: env.ledger().timestamp(),
+        author: author.clone(),
+    };
+    let key = DataKey::SplitVersion(id.clone(), version);
+    env.storage().persistent().set(&key, &split);
+    bump_persistent(env, &key);
 
-    let tokens_key = DataKey::SupportedTokens;
-    let mut tokens: Vec<Address> = env
+    let count_key = DataKey::SplitVersionCount(id.clone());
+    env.storage().persistent().set(&count_key, &version);
+    bump_persistent(env, &count_key);
+}
+
+/// Returns the number of the split currently in force, or 0 if the members never changed.
+fn get_current_split_version(env: &Env, id: &BytesN<32>) -> u32 {
+    let key = DataKey::SplitVersionCount(id.clone());
+    let result: Option<u32> = env.storage().persistent().get(&key);
+    if result.is_some() {
+        bump_persistent(env, &key);
+    }
+    result.unwrap_or(0)
+}
+
+pub fn get_split_version(env: Env, id: BytesN<32>, version: u32) -> Result<SplitVersion, Error> {
+    let key = DataKey::SplitVersion(id, version);
+    let split: SplitVersion = env
         .storage()
         .persistent()
-        .get(&tokens_key)
-        .unwrap_or(Vec::new(&env));
-    if !tokens.is_empty() {
-        bump_persistent(&env, &tokens_key);
-    }
+        .get(&key)
+        .ok_or(Error::NotFound)?;
+    bump_persistent(&env, &key);
+    Ok(split)
+}
 
-    // Check if token is already supported
-    for existing_token in tokens.iter() {
-        if existing_token == token {
-            return Err(Error::AlreadyExists);
+pub fn get_split_versions(env: Env, id: BytesN<32>) -> Vec<SplitVersion> {
+    let mut versions: Vec<SplitVersion> = Vec::new(&env);
+    for version in 1..=get_current_split_version(&env, &id) {
+        if let Ok(split) = get_split_version(env.clone(), id.clone(), version) {
+            versions.push_back(split);
         }
     }
-
-    tokens.push_back(token);
-    env.storage().persistent().set(&tokens_key, &tokens);
-    bump_persistent(&env, &tokens_key);
-    Ok(())
+    versions
 }
 
-pub fn remove_supported_token(env: Env, token: Address, admin: Address) -> Result<(), Error> {
-    admin.require_auth();
-    require_admin(&env, &admin)?;
+// ============================================================================
+// Member Self-Service
+// ============================================================================
 
-    let tokens_key = DataKey::SupportedTokens;
-    let tokens: Vec<Address> = env
+/// Removes the calling member from a group. Their share is handed to the remaining members
+/// pro-rata so the split keeps summing to 100. Governed groups change members through proposals,
+/// the last member cannot leave, and a queued split change is cancelled since it was written for
+/// the old member set.
+pub fn leave_group(env: Env, id: BytesN<32>, member: Address) -> Result<(), Error> {
+    member.require_auth();
+
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let key = DataKey::AutoShare(id.clone());
+    let mut details: AutoShareDetails = env
         .storage()
         .persistent()
-        .get(&tokens_key)
-        .unwrap_or(Vec::new(&env));
-    if !tokens.is_empty() {
-        bump_persistent(&env, &tokens_key);
-    }
+        .get(&key)
+        .ok_or(Error::NotFound)?;
+    bump_persistent(&env, &key);
 
-    let mut new_tokens: Vec<Address> = Vec::new(&env);
+    let mut remaining: Vec<GroupMember> = Vec::new(&env);
     let mut found = false;
-
-    for existing_token in tokens.iter() {
-        if existing_token != token {
-            new_tokens.push_back(existing_token);
-        } else {
+    for existing in details.members.iter() {
+        if existing.address == member {
             found = true;
+        } else {
+            remaining.push_back(existing);
         }
     }
-
     if !found {
-        return Err(Error::NotFound);
+        return Err(Error::MemberNotFound);
     }
-
-    env.storage().persistent().set(&tokens_key, &new_tokens);
-    bump_persistent(&env, &tokens_key);
-    Ok(())
-}
-
-pub fn get_supported_tokens(env: Env) -> Vec<Address> {
-    let tokens_key = DataKey::SupportedTokens;
-    let result: Option<Vec<Address>> = env.storage().persistent().get(&tokens_key);
-    if result.is_some() {
-        bump_persistent(&env, &tokens_key);
+    if remaining.is_empty() {
+        return Err(Error::EmptyMembers);
     }
-    result.unwrap_or(Vec::new(&env))
-}
-
-pub fn is_token_supported(env: Env, token: Address) -> bool {
-    let tokens = get_supported_tokens(env);
-    for supported_token in tokens.iter() {
-        if supported_token == token {
-            return true;
-        }
+    if is_governed(&env, &id) {
+        return Err(Error::GovernanceRequired);
     }
-    false
-}
 
-// ============================================================================
-// Payment Configuration
-// ============================================================================
+    details.members = scale_split(&env, &remaining, 100)?;
+    env.storage().persistent().set(&key, &details);
+    bump_persistent(&env, &key);
+    add_group_stat(&env, &id, StatsKey::TotalMembers, -1);
+    record_split_version(&env, &id, &details.members, &member);
 
-pub fn set_usage_fee(env: Env, fee: u32, admin: Address) -> Result<(), Error> {
-    admin.require_auth();
-    require_admin(&env, &admin)?;
-    if fee == 0 {
-        return Err(Error::InvalidAmount);
+    remove_group_from_member_index(&env, &member, &id);
+    remove_vesting(&env, &id, &member);
+
+    if get_pending_split_change(env.clone(), id.clone()).is_some() {
+        clear_split_change(&env, &id);
+        SplitChangeCancelled {
+            id: id.clone(),
+            caller: member.clone(),
+        }
+        .publish(&env);
     }
 
-    let fee_key = DataKey::UsageFee;
-    env.storage().persistent().set(&fee_key, &fee);
-    bump_persistent(&env, &fee_key);
+    MemberLeft { id, member }.publish(&env);
     Ok(())
 }
 
-pub fn get_usage_fee(env: Env) -> u32 {
-    let fee_key = DataKey::UsageFee;
-    let result: Option<u32> = env.storage().persistent().get(&fee_key);
-    if result.is_some() {
-        bump_persistent(&env, &fee_key);
-    }
-    result.unwrap_or(10u32)
-}
-
-// ============================================================================
-// Subscription Management
-// ============================================================================
-
-pub fn topup_subscription(
+/// Replaces a member's payout address in a group with another address they control.
+/// Both addresses must authorize. Earnings recorded for the group move to the new address, and a
+/// queued split change is rewritten to use it. Not available in governed groups.
+pub fn update_payout_address(
     env: Env,
     id: BytesN<32>,
-    additional_usages: u32,
-    payment_token: Address,
-    payer: Address,
+    member: Address,
+    new_address: Address,
 ) -> Result<(), Error> {
-    payer.require_auth();
+    member.require_auth();
+    new_address.require_auth();
 
-    // Check if contract is paused
     if get_paused_status(&env) {
         return Err(Error::ContractPaused);
     }
 
-    // Validate usage count
-    if additional_usages == 0 {
-        return Err(Error::InvalidUsageCount);
-    }
-
-    // Verify group exists
     let key = DataKey::AutoShare(id.clone());
     let mut details: AutoShareDetails = env
         .storage()
@@ -731,235 +1764,326 @@ pub fn topup_subscription(
         .ok_or(Error::NotFound)?;
     bump_persistent(&env, &key);
 
-    // Verify token is supported
-    if !is_token_supported(env.clone(), payment_token.clone()) {
-        return Err(Error::UnsupportedToken);
+    let mut updated: Vec<GroupMember> = Vec::new(&env);
+    let mut found = false;
+    for existing in details.members.iter() {
+        if existing.address == new_address {
+            return Err(Error::DuplicateMember);
+        }
+        if existing.address == member {
+            found = true;
+            updated.push_back(GroupMember {
+                address: new_address.clone(),
+                percentage: existing.percentage,
+            });
+        } else {
+            updated.push_back(existing);
+        }
+    }
+    if !found {
+        return Err(Error::MemberNotFound);
+    }
+    if is_governed(&env, &id) {
+        return Err(Error::GovernanceRequired);
     }
 
-    // Calculate cost
-    let usage_fee = get_usage_fee(env.clone());
-    let total_cost = (additional_usages as i128) * (usage_fee as i128);
+    details.members = updated;
+    env.storage().persistent().set(&key, &details);
+    bump_persistent(&env, &key);
+    record_split_version(&env, &id, &details.members, &member);
+    rename_in_split_change(&env, &id, &member, &new_address);
+
+    remove_group_from_member_index(&env, &member, &id);
+    add_group_to_member_index(&env, &new_address, &id);
+
+    // A vesting schedule follows the share to the new address
+    let old_vesting_key = DataKey::MemberVesting(id.clone(), member.clone());
+    let vesting: Option<VestingSchedule> = env.storage().persistent().get(&old_vesting_key);
+    if let Some(schedule) = vesting {
+        let new_vesting_key = DataKey::MemberVesting(id.clone(), new_address.clone());
+        env.storage().persistent().set(&new_vesting_key, &schedule);
+        bump_persistent(&env, &new_vesting_key);
+        env.storage().persistent().remove(&old_vesting_key);
+    }
 
-    // Transfer tokens from payer to contract
-    let token_client = token::Client::new(&env, &payment_token);
-    token_client.transfer(&payer, env.current_contract_address(), &total_cost);
-
-    // Update usage counts
-    details.usage_count += additional_usages;
-    details.total_usages_paid += additional_usages;
-
-    // Save updated details
-    env.storage().persistent().set(&key, &details);
-    bump_persistent(&env, &key);
-
-    // Record payment history
-    record_payment(env, payer, id, additional_usages, total_cost);
+    // Carry the group's earnings totals over to the new address
+    let legacy_earnings = take_earnings(
+        &env,
+        &DataKey::MemberGroupEarnings(member.clone(), id.clone()),
+    );
+    if legacy_earnings > 0 {
+        add_earnings(
+            &env,
+            &DataKey::MemberGroupEarnings(new_address.clone(), id.clone()),
+            legacy_earnings,
+        );
+    }
+    for token in get_group_tokens(&env, &id).iter() {
+        let earnings = take_earnings(
+            &env,
+            &EarningsKey::Group(member.clone(), id.clone(), token.clone()),
+        );
+        if earnings > 0 {
+            add_earnings(
+                &env,
+                &EarningsKey::Group(new_address.clone(), id.clone(), token.clone()),
+                earnings,
+            );
+            add_earnings(
+                &env,
+                &EarningsKey::Lifetime(new_address.clone(), token.clone()),
+                earnings,
+            );
+            add_earnings(
+                &env,
+                &EarningsKey::Lifetime(member.clone(), token),
+                -earnings,
+            );
+        }
+    }
 
+    PayoutAddressUpdated {
+        id,
+        old_address: member,
+        new_address,
+    }
+    .publish(&env);
     Ok(())
 }
 
-// ============================================================================
-// Payment History
-// ============================================================================
+/// Scales member percentages pro-rata so they sum to `target`. Rounding leftovers go to the last
+/// member, mirroring how `perform_distribution` hands out remainders. No share is rounded down
+/// to 0%: small shares keep 1%, paid for by the largest shares.
+fn scale_split(
+    env: &Env,
+    members: &Vec<GroupMember>,
+    target: u32,
+) -> Result<Vec<GroupMember>, Error> {
+    let mut current_total: u32 = 0;
+    for member in members.iter() {
+        current_total += member.percentage;
+    }
 
-fn record_payment(
-    env: Env,
-    user: Address,
-    group_id: BytesN<32>,
-    usages_purchased: u32,
-    amount_paid: i128,
-) {
-    let timestamp = env.ledger().timestamp();
+    let mut scaled: Vec<GroupMember> = Vec::new(env);
+    if current_total == 0 {
+        return Ok(scaled);
+    }
+    if target < members.len() {
+        return Err(Error::InvalidInput);
+    }
 
-    let payment = PaymentHistory {
-        user: user.clone(),
-        group_id: group_id.clone(),
-        usages_purchased,
-        amount_paid,
-        timestamp,
-    };
+    let mut assigned: u32 = 0;
+    for member in members.iter() {
+        let percentage = (member.percentage * target / current_total).max(1);
+        assigned += percentage;
+        scaled.push_back(GroupMember {
+            address: member.address,
+            percentage,
+        });
+    }
 
-    // Add to user's payment history
-    let user_history_key = DataKey::UserPaymentHistory(user.clone());
-    let mut user_history: Vec<PaymentHistory> = env
-        .storage()
-        .persistent()
-        .get(&user_history_key)
-        .unwrap_or(Vec::new(&env));
-    if !user_history.is_empty() {
-        bump_persistent(&env, &user_history_key);
+    let last = scaled.len() - 1;
+    if assigned <= target {
+        let mut member = scaled.get(last).unwrap();
+        member.percentage += target - assigned;
+        scaled.set(last, member);
     }
-    user_history.push_back(payment.clone());
-    env.storage()
-        .persistent()
-        .set(&user_history_key, &user_history);
-    bump_persistent(&env, &user_history_key);
+    while assigned > target {
+        let mut largest = 0;
+        for idx in 1..scaled.len() {
+            if scaled.get(idx).unwrap().percentage > scaled.get(largest).unwrap().percentage {
+                largest = idx;
+            }
+        }
+        let mut member = scaled.get(largest).unwrap();
+        member.percentage -= 1;
+        scaled.set(largest, member);
+        assigned -= 1;
+    }
+    Ok(scaled)
+}
 
-    // Add to group's payment history
-    let group_history_key = DataKey::GroupPaymentHistory(group_id);
-    let mut group_history: Vec<PaymentHistory> = env
+fn add_group_to_member_index(env: &Env, member: &Address, id: &BytesN<32>) {
+    let member_groups_key = DataKey::MemberGroups(member.clone());
+    let mut member_groups: Vec<BytesN<32>> = env
         .storage()
         .persistent()
-        .get(&group_history_key)
-        .unwrap_or(Vec::new(&env));
-    if !group_history.is_empty() {
-        bump_persistent(&env, &group_history_key);
-    }
-    group_history.push_back(payment);
-    env.storage()
-        .persistent()
-        .set(&group_history_key, &group_history);
-    bump_persistent(&env, &group_history_key);
-}
+        .get(&member_groups_key)
+        .unwrap_or(Vec::new(env));
 
-pub fn get_user_payment_history(env: Env, user: Address) -> Vec<PaymentHistory> {
-    let user_history_key = DataKey::UserPaymentHistory(user);
-    let result: Option<Vec<PaymentHistory>> = env.storage().persistent().get(&user_history_key);
-    if result.is_some() {
-        bump_persistent(&env, &user_history_key);
+    if !member_groups.contains(id) {
+        member_groups.push_back(id.clone());
+        env.storage()
+            .persistent()
+            .set(&member_groups_key, &member_groups);
     }
-    result.unwrap_or(Vec::new(&env))
+    bump_persistent(env, &member_groups_key);
 }
 
-pub fn get_group_payment_history(env: Env, id: BytesN<32>) -> Vec<PaymentHistory> {
-    let group_history_key = DataKey::GroupPaymentHistory(id);
-    let result: Option<Vec<PaymentHistory>> = env.storage().persistent().get(&group_history_key);
-    if result.is_some() {
-        bump_persistent(&env, &group_history_key);
+fn remove_group_from_member_index(env: &Env, member: &Address, id: &BytesN<32>) {
+    let member_groups_key = DataKey::MemberGroups(member.clone());
+    let mut member_groups: Vec<BytesN<32>> = env
+        .storage()
+        .persistent()
+        .get(&member_groups_key)
+        .unwrap_or(Vec::new(env));
+
+    if let Some(index) = member_groups.first_index_of(id) {
+        member_groups.remove(index);
+        env.storage()
+            .persistent()
+            .set(&member_groups_key, &member_groups);
+        bump_persistent(env, &member_groups_key);
     }
-    result.unwrap_or(Vec::new(&env))
 }
 
 // ============================================================================
-// Distribution History
+// Member Invitations
 // ============================================================================
 
-fn record_distribution(
+/// Invites an address to join a group with the given percentage. Re-inviting a pending invitee
+/// replaces their previous offer.
+pub fn invite_member(
     env: Env,
-    group_id: BytesN<32>,
-    sender: Address,
-    total_amount: i128,
-    token: Address,
-    member_amounts: Vec<MemberAmount>,
-    distribution_number: u32,
-) {
-    let timestamp = env.ledger().timestamp();
-
-    let distribution = DistributionHistory {
-        group_id: group_id.clone(),
-        sender: sender.clone(),
-        total_amount,
-        token: token.clone(),
-        member_amounts: member_amounts.clone(),
-        timestamp,
-        distribution_number,
-    };
+    id: BytesN<32>,
+    caller: Address,
+    invitee: Address,
+    percentage: u32,
+    expires_at: u64,
+) -> Result<(), Error> {
+    caller.require_auth();
 
-    // Add to group's distribution history
-    let group_history_key = DataKey::GroupDistributionHistory(group_id.clone());
-    let mut group_history: Vec<DistributionHistory> = env
-        .storage()
-        .persistent()
-        .get(&group_history_key)
-        .unwrap_or(Vec::new(&env));
-    group_history.push_back(distribution.clone());
-    env.storage()
-        .persistent()
-        .set(&group_history_key, &group_history);
+    let details = load_group_with_permission(&env, &id, &caller, Permission::ManageMembers)?;
+    if !details.is_active {
+        return Err(Error::GroupInactive);
+    }
+    if is_governed(&env, &id) {
+        return Err(Error::GovernanceRequired);
+    }
+    if percentage == 0 || percentage > 100 {
+        return Err(Error::InvalidInput);
+    }
+    if expires_at <= env.ledger().timestamp() {
+        return Err(Error::InvalidDeadline);
+    }
+    for member in details.members.iter() {
+        if member.address == invitee {
+            return Err(Error::AlreadyExists);
+        }
+    }
 
-    // Add to each member's distribution history
-    for member_amount in member_amounts.iter() {
-        let member_history_key = DataKey::MemberDistributions(member_amount.address.clone());
-        let mut member_history: Vec<DistributionRecord> = env
-            .storage()
-            .persistent()
-            .get(&member_history_key)
-            .unwrap_or(Vec::new(&env));
-        let record = DistributionRecord {
-            group_id: group_id.clone(),
-            amount: member_amount.amount,
-            token: token.clone(),
-            timestamp,
-        };
-        member_history.push_back(record);
-        env.storage()
-            .persistent()
-            .set(&member_history_key, &member_history);
+    let mut invites = get_pending_invites(env.clone(), id.clone());
+    if let Some(index) = find_invite(&invites, &invitee) {
+        invites.remove(index);
+    }
+    if details.members.len() + invites.len() >= MAX_MEMBERS {
+        return Err(Error::MaxMembersExceeded);
     }
-}
 
-pub fn get_group_distributions(env: Env, id: BytesN<32>) -> Vec<DistributionHistory> {
-    let group_history_key = DataKey::GroupDistributionHistory(id);
-    env.storage()
-        .persistent()
-        .get(&group_history_key)
-        .unwrap_or(Vec::new(&env))
-}
+    invites.push_back(MemberInvite {
+        invitee: invitee.clone(),
+        percentage,
+        invited_by: caller,
+        expires_at,
+    });
+    save_invites(&env, &id, &invites);
 
-pub fn get_member_distributions(env: Env, member: Address) -> Vec<DistributionRecord> {
-    let member_history_key = DataKey::MemberDistributions(member);
-    env.storage()
-        .persistent()
-        .get(&member_history_key)
-        .unwrap_or(Vec::new(&env))
+    MemberInvited {
+        id,
+        invitee,
+        percentage,
+        expires_at,
+    }
+    .publish(&env);
+    Ok(())
 }
 
-// ============================================================================
-// Usage Tracking
-// ============================================================================
+/// Accepts a pending invite. Existing members are scaled down pro-rata to make room for the
+/// invited percentage, and the group is added to the new member's index. Since that lowers
+/// every other share, the join waits out the group's notice period when one is set, and is
+/// refused once the group is in governance mode.
+pub fn accept_invite(env: Env, id: BytesN<32>, invitee: Address) -> Result<(), Error> {
+    invitee.require_auth();
 
-pub fn get_remaining_usages(env: Env, id: BytesN<32>) -> Result<u32, Error> {
-    let key = DataKey::AutoShare(id);
-    let details: AutoShareDetails = env
-        .storage()
-        .persistent()
-        .get(&key)
-        .ok_or(Error::NotFound)?;
-    bump_persistent(&env, &key);
-    Ok(details.usage_count)
-}
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
 
-pub fn get_total_usages_paid(env: Env, id: BytesN<32>) -> Result<u32, Error> {
-    let key = DataKey::AutoShare(id);
+    let key = DataKey::AutoShare(id.clone());
     let details: AutoShareDetails = env
         .storage()
         .persistent()
         .get(&key)
         .ok_or(Error::NotFound)?;
     bump_persistent(&env, &key);
-    Ok(details.total_usages_paid)
-}
 
-#[cfg(test)]
-#[allow(dead_code)]
-pub fn reduce_usage(env: Env, id: BytesN<32>) -> Result<(), Error> {
-    let key = DataKey::AutoShare(id);
-    let mut details: AutoShareDetails = env
+    if !details.is_active {
+        return Err(Error::GroupInactive);
+    }
+    if is_governed(&env, &id) {
+        return Err(Error::GovernanceRequired);
+    }
+
+    let invites_key = DataKey::GroupInvites(id.clone());
+    let mut invites: Vec<MemberInvite> = env
         .storage()
         .persistent()
-        .get(&key)
-        .ok_or(Error::NotFound)?;
-    bump_persistent(&env, &key);
+        .get(&invites_key)
+        .unwrap_or(Vec::new(&env));
+    let index = find_invite(&invites, &invitee).ok_or(Error::NotFound)?;
+    let invite = invites.get(index).unwrap();
+    if invite.expires_at <= env.ledger().timestamp() {
+        return Err(Error::InviteExpired);
+    }
+    if details.members.len() >= MAX_MEMBERS {
+        return Err(Error::MaxMembersExceeded);
+    }
 
-    if details.usage_count == 0 {
-        return Err(Error::NoUsagesRemaining);
+    let mut members = make_room_for_member(
+        &env,
+        &details.members,
+        invite.percentage,
+        RebalanceStrategy::ProRata,
+    )?;
+    members.push_back(GroupMember {
+        address: invitee.clone(),
+        percentage: invite.percentage,
+    });
+    validate_members(&members)?;
+
+    invites.remove(index);
+    save_invites(&env, &id, &invites);
+
+    let action = ProposalAction::AddMember(
+        invitee.clone(),
+        invite.percentage,
+        RebalanceStrategy::ProRata,
+    );
+    if get_notice_period(env.clone(), id.clone()) > 0 {
+        // An invitee must not replace a change the group has already queued
+        if get_pending_split_change(env.clone(), id.clone()).is_some() {
+            return Err(Error::NoticePeriodActive);
+        }
+        queue_split_change(&env, id.clone(), action, invitee.clone())?;
+    } else {
+        apply_member_action(&env, id.clone(), details, action, invitee.clone())?;
     }
 
-    details.usage_count -= 1;
-    env.storage().persistent().set(&key, &details);
-    bump_persistent(&env, &key);
+    InviteAccepted {
+        id,
+        member: invitee,
+        percentage: invite.percentage,
+    }
+    .publish(&env);
     Ok(())
 }
 
-// ============================================================================
-// Group Activation Management
-// ============================================================================
-
-pub fn update_members(
+/// Withdraws a pending invite. Either the group creator or the invitee may cancel it.
+pub fn cancel_invite(
     env: Env,
     id: BytesN<32>,
     caller: Address,
-    new_members: Vec<GroupMember>,
+    invitee: Address,
 ) -> Result<(), Error> {
     caller.require_auth();
 
@@ -967,426 +2091,3645 @@ pub fn update_members(
         return Err(Error::ContractPaused);
     }
 
-    let key = DataKey::AutoShare(id.clone());
-    let mut details: AutoShareDetails = env
+    let details = get_autoshare(env.clone(), id.clone())?;
+    if caller != invitee && !has_permission(&env, &details, &caller, Permission::ManageMembers) {
+        return Err(Error::Unauthorized);
+    }
+
+    let invites_key = DataKey::GroupInvites(id.clone());
+    let mut invites: Vec<MemberInvite> = env
+        .storage()
+        .persistent()
+        .get(&invites_key)
+        .unwrap_or(Vec::new(&env));
+    let index = find_invite(&invites, &invitee).ok_or(Error::NotFound)?;
+    invites.remove(index);
+    save_invites(&env, &id, &invites);
+
+    InviteCancelled { id, invitee }.publish(&env);
+    Ok(())
+}
+
+/// Returns the invites of a group that can still be accepted. Expired invites are skipped.
+pub fn get_pending_invites(env: Env, id: BytesN<32>) -> Vec<MemberInvite> {
+    let key = DataKey::GroupInvites(id);
+    let invites: Vec<MemberInvite> = env
         .storage()
         .persistent()
         .get(&key)
-        .ok_or(Error::NotFound)?;
+        .unwrap_or(Vec::new(&env));
     bump_persistent(&env, &key);
 
-    if details.creator != caller {
-        return Err(Error::Unauthorized);
+    let now = env.ledger().timestamp();
+    let mut pending: Vec<MemberInvite> = Vec::new(&env);
+    for invite in invites.iter() {
+        if invite.expires_at > now {
+            pending.push_back(invite);
+        }
     }
+    pending
+}
 
-    if !details.is_active {
-        return Err(Error::GroupInactive);
+fn find_invite(invites: &Vec<MemberInvite>, invitee: &Address) -> Option<u32> {
+    for (idx, invite) in invites.iter().enumerate() {
+        if invite.invitee == *invitee {
+            return Some(idx as u32);
+        }
     }
+    None
+}
 
-    // Validate new members
-    if new_members.is_empty() {
-        return Err(Error::EmptyMembers);
+/// Stores the invite list, dropping expired entries so the list does not grow unbounded.
+fn save_invites(env: &Env, id: &BytesN<32>, invites: &Vec<MemberInvite>) {
+    let now = env.ledger().timestamp();
+    let mut live: Vec<MemberInvite> = Vec::new(env);
+    for invite in invites.iter() {
+        if invite.expires_at > now {
+            live.push_back(invite);
+        }
     }
 
-    // Check if new members count exceeds MAX_MEMBERS
-    if new_members.len() > MAX_MEMBERS {
-        return Err(Error::MaxMembersExceeded);
+    let key = DataKey::GroupInvites(id.clone());
+    if live.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &live);
+        bump_persistent(env, &key);
     }
+}
 
-    let mut total_percentage: u32 = 0;
-    let mut seen_addresses = Vec::new(&env);
+// ============================================================================
+// Group Managers
+// ============================================================================
 
-    for member in new_members.iter() {
-        if member.percentage == 0 {
-            return Err(Error::InvalidInput);
-        }
-        total_percentage += member.percentage;
+/// Group actions that a creator can delegate through a `ManagerScope`.
+#[derive(Clone, Copy)]
+enum Permission {
+    ManageMembers,
+    ChangeSplits,
+    Rename,
+    Fundraising,
+    Activation,
+}
 
-        for seen in seen_addresses.iter() {
-            if seen == member.address {
-                return Err(Error::DuplicateMember);
-            }
-        }
-        seen_addresses.push_back(member.address.clone());
+/// Returns whether `caller` may perform `permission` on the group. The creator holds every
+/// permission; managers hold the ones enabled in their scope.
+fn has_permission(
+    env: &Env,
+    details: &AutoShareDetails,
+    caller: &Address,
+    permission: Permission,
+) -> bool {
+    if details.creator == *caller {
+        return true;
     }
 
-    if total_percentage != 100 {
-        return Err(Error::InvalidTotalPercentage);
+    for manager in get_managers(env.clone(), details.id.clone()).iter() {
+        if manager.address == *caller {
+            return match permission {
+                Permission::ManageMembers => manager.scope.manage_members,
+                Permission::ChangeSplits => manager.scope.change_splits,
+                Permission::Rename => manager.scope.rename,
+                Permission::Fundraising => manager.scope.fundraising,
+                Permission::Activation => manager.scope.activation,
+            };
+        }
     }
+    false
+}
 
-    // Determine old members for index updating
-    let old_members = details.members.clone();
-
-    // Update members in details
-    details.members = new_members.clone();
-    env.storage().persistent().set(&key, &details);
-    bump_persistent(&env, &key);
+/// Grants or replaces a manager's scope. Only the creator can manage managers.
+pub fn set_manager(
+    env: Env,
+    id: BytesN<32>,
+    caller: Address,
+    manager: Address,
+    scope: ManagerScope,
+) -> Result<(), Error> {
+    caller.require_auth();
 
-    // Update MemberGroups index for removed and added members
-    for old_member in old_members.iter() {
-        let mut found_in_new = false;
-        for new_member in new_members.iter() {
-            if old_member.address == new_member.address {
-                found_in_new = true;
-                break;
-            }
-        }
-        if !found_in_new {
-            // Member was removed, remove group from their index
-            let member_groups_key = DataKey::MemberGroups(old_member.address.clone());
-            let member_groups: Vec<BytesN<32>> = env
-                .storage()
-                .persistent()
-                .get(&member_groups_key)
-                .unwrap_or(Vec::new(&env));
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
 
-            let mut updated_member_groups: Vec<BytesN<32>> = Vec::new(&env);
-            let mut group_removed = false;
-            for group_id in member_groups.iter() {
-                if group_id != id {
-                    updated_member_groups.push_back(group_id);
-                } else {
-                    group_removed = true;
-                }
-            }
-            if group_removed {
-                env.storage()
-                    .persistent()
-                    .set(&member_groups_key, &updated_member_groups);
-                bump_persistent(&env, &member_groups_key);
-            }
-        }
+    let details = get_autoshare(env.clone(), id.clone())?;
+    if details.creator != caller {
+        return Err(Error::Unauthorized);
+    }
+    if manager == details.creator {
+        return Err(Error::InvalidInput);
     }
 
-    for new_member in new_members.iter() {
-        let mut found_in_old = false;
-        for old_member in old_members.iter() {
-            if new_member.address == old_member.address {
-                found_in_old = true;
-                break;
-            }
+    let mut managers = get_managers(env.clone(), id.clone());
+    let mut replaced = false;
+    for (idx, existing) in managers.iter().enumerate() {
+        if existing.address == manager {
+            managers.set(
+                idx as u32,
+                GroupManager {
+                    address: manager.clone(),
+                    scope: scope.clone(),
+                },
+            );
+            replaced = true;
+            break;
         }
-        if !found_in_old {
-            // Member was added, add group to their index
-            let member_groups_key = DataKey::MemberGroups(new_member.address.clone());
-            let mut member_groups: Vec<BytesN<32>> = env
-                .storage()
-                .persistent()
-                .get(&member_groups_key)
-                .unwrap_or(Vec::new(&env));
-
-            member_groups.push_back(id.clone());
-            env.storage()
-                .persistent()
-                .set(&member_groups_key, &member_groups);
-            bump_persistent(&env, &member_groups_key);
+    }
+    if !replaced {
+        if managers.len() >= MAX_MANAGERS {
+            return Err(Error::MaxManagersExceeded);
         }
+        managers.push_back(GroupManager {
+            address: manager.clone(),
+            scope: scope.clone(),
+        });
     }
 
-    AutoshareUpdated {
-        id: id.clone(),
-        updater: caller,
-    }
-    .publish(&env);
+    let key = DataKey::GroupManagers(id.clone());
+    env.storage().persistent().set(&key, &managers);
+    bump_persistent(&env, &key);
+
+    ManagerUpdated { id, manager, scope }.publish(&env);
     Ok(())
 }
 
-pub fn deactivate_group(env: Env, id: BytesN<32>, caller: Address) -> Result<(), Error> {
+/// Revokes all delegated permissions from a manager. Only the creator can manage managers.
+pub fn remove_manager(
+    env: Env,
+    id: BytesN<32>,
+    caller: Address,
+    manager: Address,
+) -> Result<(), Error> {
     caller.require_auth();
 
     if get_paused_status(&env) {
         return Err(Error::ContractPaused);
     }
 
-    let key = DataKey::AutoShare(id.clone());
-    let mut details: AutoShareDetails = env
-        .storage()
-        .persistent()
-        .get(&key)
-        .ok_or(Error::NotFound)?;
-    bump_persistent(&env, &key);
-
+    let details = get_autoshare(env.clone(), id.clone())?;
     if details.creator != caller {
         return Err(Error::Unauthorized);
     }
 
-    if !details.is_active {
-        return Err(Error::GroupAlreadyInactive);
+    let mut managers = get_managers(env.clone(), id.clone());
+    let mut found = false;
+    for (idx, existing) in managers.iter().enumerate() {
+        if existing.address == manager {
+            managers.remove(idx as u32);
+            found = true;
+            break;
+        }
+    }
+    if !found {
+        return Err(Error::NotFound);
     }
 
-    details.is_active = false;
-    env.storage().persistent().set(&key, &details);
-    bump_persistent(&env, &key);
+    let key = DataKey::GroupManagers(id.clone());
+    if managers.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &managers);
+        bump_persistent(&env, &key);
+    }
 
-    GroupDeactivated {
-        id: id.clone(),
-        creator: caller,
+    ManagerRemoved { id, manager }.publish(&env);
+    Ok(())
+}
+
+pub fn get_managers(env: Env, id: BytesN<32>) -> Vec<GroupManager> {
+    let key = DataKey::GroupManagers(id);
+    let result: Option<Vec<GroupManager>> = env.storage().persistent().get(&key);
+    if result.is_some() {
+        bump_persistent(&env, &key);
+    }
+    result.unwrap_or(Vec::new(&env))
+}
+
+// ============================================================================
+// Governance
+// ============================================================================
+
+fn is_governed(env: &Env, id: &BytesN<32>) -> bool {
+    get_governance_config(env.clone(), id.clone()).enabled
+}
+
+fn validate_governance_config(config: &GovernanceConfig) -> Result<(), Error> {
+    if config.quorum > 100
+        || config.threshold == 0
+        || config.threshold > 100
+        || config.voting_period == 0
+    {
+        return Err(Error::InvalidInput);
     }
-    .publish(&env);
     Ok(())
 }
 
-pub fn activate_group(env: Env, id: BytesN<32>, caller: Address) -> Result<(), Error> {
+/// Turns governance mode on or reconfigures it while it is off. Once enabled, member changes
+/// and governance changes can only be made through proposals.
+pub fn set_governance(
+    env: Env,
+    id: BytesN<32>,
+    caller: Address,
+    config: GovernanceConfig,
+) -> Result<(), Error> {
     caller.require_auth();
 
     if get_paused_status(&env) {
         return Err(Error::ContractPaused);
     }
 
-    let key = DataKey::AutoShare(id.clone());
-    let mut details: AutoShareDetails = env
-        .storage()
-        .persistent()
-        .get(&key)
-        .ok_or(Error::NotFound)?;
-    bump_persistent(&env, &key);
-
+    let details = get_autoshare(env.clone(), id.clone())?;
     if details.creator != caller {
         return Err(Error::Unauthorized);
     }
-
-    if details.is_active {
-        return Err(Error::GroupAlreadyActive);
+    if is_governed(&env, &id) {
+        return Err(Error::GovernanceRequired);
     }
+    validate_governance_config(&config)?;
 
-    details.is_active = true;
-    env.storage().persistent().set(&key, &details);
+    let key = GovernanceKey::Config(id);
+    env.storage().persistent().set(&key, &config);
     bump_persistent(&env, &key);
+    Ok(())
+}
 
-    GroupActivated {
-        id: id.clone(),
-        creator: caller,
+pub fn get_governance_config(env: Env, id: BytesN<32>) -> GovernanceConfig {
+    let key = GovernanceKey::Config(id);
+    let result: Option<GovernanceConfig> = env.storage().persistent().get(&key);
+    if result.is_some() {
+        bump_persistent(&env, &key);
     }
-    .publish(&env);
-    Ok(())
+    result.unwrap_or(GovernanceConfig {
+        enabled: false,
+        quorum: 0,
+        threshold: 0,
+        voting_period: 0,
+    })
 }
 
-pub fn update_group_name(
+/// Opens a proposal for a member or governance change. Any member of a governed group, or its
+/// creator, can propose.
+pub fn propose_change(
     env: Env,
     id: BytesN<32>,
-    caller: Address,
-    new_name: String,
-) -> Result<(), Error> {
-    caller.require_auth();
+    proposer: Address,
+    action: ProposalAction,
+) -> Result<u32, Error> {
+    proposer.require_auth();
 
     if get_paused_status(&env) {
         return Err(Error::ContractPaused);
     }
 
-    let key = DataKey::AutoShare(id.clone());
-    let mut details: AutoShareDetails = env
+    let details = get_autoshare(env.clone(), id.clone())?;
+    let config = get_governance_config(env.clone(), id.clone());
+    if !config.enabled {
+        return Err(Error::InvalidInput);
+    }
+    if details.creator != proposer && member_weight(&details.members, &proposer) == 0 {
+        return Err(Error::Unauthorized);
+    }
+
+    match &action {
+        ProposalAction::UpdateMembers(members) => validate_members(members)?,
+        ProposalAction::AddMember(_, percentage, _) if *percentage == 0 => {
+            return Err(Error::InvalidInput)
+        }
+        ProposalAction::SetGovernance(new_config) if new_config.enabled => {
+            validate_governance_config(new_config)?
+        }
+        _ => {}
+    }
+
+    let count_key = GovernanceKey::ProposalCount;
+    let proposal_id: u32 = env.storage().persistent().get(&count_key).unwrap_or(0) + 1;
+    env.storage().persistent().set(&count_key, &proposal_id);
+    bump_persistent(&env, &count_key);
+
+    let now = env.ledger().timestamp();
+    let proposal = Proposal {
+        proposal_id,
+        group_id: id.clone(),
+        proposer,
+        action,
+        votes_for: 0,
+        votes_against: 0,
+        created_at: now,
+        ends_at: now + config.voting_period,
+        status: ProposalStatus::Open,
+    };
+    let key = GovernanceKey::Proposal(proposal_id);
+    env.storage().persistent().set(&key, &proposal);
+    bump_persistent(&env, &key);
+
+    let weights_key = GovernanceKey::Weights(proposal_id);
+    env.storage()
+        .persistent()
+        .set(&weights_key, &details.members);
+    bump_persistent(&env, &weights_key);
+
+    let group_key = GovernanceKey::GroupProposals(id.clone());
+    let mut group_proposals: Vec<u32> = env
         .storage()
         .persistent()
-        .get(&key)
-        .ok_or(Error::NotFound)?;
+        .get(&group_key)
+        .unwrap_or(Vec::new(&env));
+    group_proposals.push_back(proposal_id);
+    env.storage().persistent().set(&group_key, &group_proposals);
+    bump_persistent(&env, &group_key);
 
-    if details.creator != caller {
-        return Err(Error::Unauthorized);
+    ProposalCreated { id, proposal_id }.publish(&env);
+    Ok(proposal_id)
+}
+
+/// Records a member's vote, weighted by their percentage when the proposal was opened. Votes
+/// are counted per address of that snapshot, so moving a share to another payout address does
+/// not earn it a second vote.
+pub fn vote(env: Env, proposal_id: u32, voter: Address, approve: bool) -> Result<(), Error> {
+    voter.require_auth();
+
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
     }
 
-    if !details.is_active {
-        return Err(Error::GroupInactive);
+    let mut proposal = get_proposal(env.clone(), proposal_id)?;
+    if proposal.status != ProposalStatus::Open || env.ledger().timestamp() >= proposal.ends_at {
+        return Err(Error::ProposalNotActive);
     }
 
-    if !is_valid_name(&new_name) {
-        return Err(Error::EmptyName);
+    let vote_key = GovernanceKey::Vote(proposal_id, voter.clone());
+    if env.storage().persistent().has(&vote_key) {
+        return Err(Error::AlreadyExists);
     }
 
-    details.name = new_name;
-    env.storage().persistent().set(&key, &details);
+    let weight = member_weight(&get_proposal_weights(&env, proposal_id)?, &voter);
+    if weight == 0 {
+        return Err(Error::MemberNotFound);
+    }
 
-    GroupNameUpdated {
-        id: id.clone(),
-        updater: caller,
+    if approve {
+        proposal.votes_for += weight;
+    } else {
+        proposal.votes_against += weight;
+    }
+    env.storage().persistent().set(&vote_key, &approve);
+    bump_persistent(&env, &vote_key);
+
+    let key = GovernanceKey::Proposal(proposal_id);
+    env.storage().persistent().set(&key, &proposal);
+    bump_persistent(&env, &key);
+
+    VoteCast {
+        proposal_id,
+        voter,
+        approve,
+        weight,
     }
     .publish(&env);
     Ok(())
 }
 
-pub fn is_group_active(env: Env, id: BytesN<32>) -> Result<bool, Error> {
-    let key = DataKey::AutoShare(id);
-    let details: AutoShareDetails = env
+/// Resolves a proposal. It can be executed early once the votes in favour meet quorum and
+/// threshold against the group's full weight; otherwise it is decided when voting ends.
+/// Returns whether the change was applied. An approved change that no longer fits the group
+/// marks the proposal as failed instead of leaving it open.
+pub fn execute_proposal(env: Env, proposal_id: u32) -> Result<bool, Error> {
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let mut proposal = get_proposal(env.clone(), proposal_id)?;
+    if proposal.status != ProposalStatus::Open {
+        return Err(Error::ProposalNotActive);
+    }
+
+    let id = proposal.group_id.clone();
+    let details = get_autoshare(env.clone(), id.clone())?;
+    let config = get_governance_config(env.clone(), id.clone());
+
+    let mut total_weight: u32 = 0;
+    for member in get_proposal_weights(&env, proposal_id)?.iter() {
+        total_weight += member.percentage;
+    }
+    let cast = proposal.votes_for + proposal.votes_against;
+    let decisive = proposal.votes_for > 0
+        && proposal.votes_for * 100 >= total_weight * config.quorum.max(config.threshold);
+
+    let passed = if decisive {
+        true
+    } else if env.ledger().timestamp() < proposal.ends_at {
+        return Err(Error::VotingNotEnded);
+    } else {
+        cast > 0
+            && cast * 100 >= total_weight * config.quorum
+            && proposal.votes_for * 100 >= cast * config.threshold
+    };
+
+    let mut executed = false;
+    if passed {
+        if !details.is_active {
+            return Err(Error::GroupInactive);
+        }
+        let applied = apply_member_action(
+            &env,
+            id,
+            details,
+            proposal.action.clone(),
+            proposal.proposer.clone(),
+        );
+        executed = applied.is_ok();
+        proposal.status = if executed {
+            ProposalStatus::Executed
+        } else {
+            ProposalStatus::Failed
+        };
+    } else {
+        proposal.status = ProposalStatus::Rejected;
+    }
+
+    let key = GovernanceKey::Proposal(proposal_id);
+    env.storage().persistent().set(&key, &proposal);
+    bump_persistent(&env, &key);
+
+    ProposalResolved {
+        proposal_id,
+        executed,
+    }
+    .publish(&env);
+    Ok(executed)
+}
+
+fn get_proposal_weights(env: &Env, proposal_id: u32) -> Result<Vec<GroupMember>, Error> {
+    let key = GovernanceKey::Weights(proposal_id);
+    let weights: Vec<GroupMember> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::NotFound)?;
+    bump_persistent(env, &key);
+    Ok(weights)
+}
+
+pub fn get_proposal(env: Env, proposal_id: u32) -> Result<Proposal, Error> {
+    let key = GovernanceKey::Proposal(proposal_id);
+    let proposal: Proposal = env
         .storage()
         .persistent()
         .get(&key)
         .ok_or(Error::NotFound)?;
     bump_persistent(&env, &key);
-    Ok(details.is_active)
+    Ok(proposal)
+}
+
+pub fn get_group_proposals(env: Env, id: BytesN<32>) -> Vec<Proposal> {
+    let key = GovernanceKey::GroupProposals(id);
+    let proposal_ids: Vec<u32> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(&env));
+    bump_persistent(&env, &key);
+
+    let mut proposals: Vec<Proposal> = Vec::new(&env);
+    for proposal_id in proposal_ids.iter() {
+        if let Ok(proposal) = get_proposal(env.clone(), proposal_id) {
+            proposals.push_back(proposal);
+        }
+    }
+    proposals
+}
+
+/// Applies an approved or matured member change to a group.
+fn apply_member_action(
+    env: &Env,
+    id: BytesN<32>,
+    details: AutoShareDetails,
+    action: ProposalAction,
+    updater: Address,
+) -> Result<(), Error> {
+    match action {
+        ProposalAction::UpdateMembers(members) => {
+            replace_group_members(env, id, details, members, updater)
+        }
+        ProposalAction::AddMember(address, percentage, strategy) => {
+            insert_group_member(env, id, details, address, percentage, strategy, updater)
+        }
+        ProposalAction::RemoveMember(address, strategy) => {
+            drop_group_member(env, id, details, address, strategy, updater)
+        }
+        ProposalAction::SetGovernance(new_config) => {
+            let key = GovernanceKey::Config(id);
+            env.storage().persistent().set(&key, &new_config);
+            bump_persistent(env, &key);
+            Ok(())
+        }
+    }
+}
+
+fn member_weight(members: &Vec<GroupMember>, address: &Address) -> u32 {
+    for member in members.iter() {
+        if member.address == *address {
+            return member.percentage;
+        }
+    }
+    0
+}
+
+// ============================================================================
+// Split Change Notice
+// ============================================================================
+
+/// Sets how long split reductions wait before taking effect. A shorter period only applies once
+/// the previous period has run out, so it cannot be used to skip a notice.
+pub fn set_notice_period(
+    env: Env,
+    id: BytesN<32>,
+    caller: Address,
+    period: u64,
+) -> Result<(), Error> {
+    caller.require_auth();
+
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let details = get_autoshare(env.clone(), id.clone())?;
+    if details.creator != caller {
+        return Err(Error::Unauthorized);
+    }
+
+    let notice = NoticePeriod {
+        period,
+        previous: get_notice_period(env.clone(), id.clone()),
+        changed_at: env.ledger().timestamp(),
+    };
+    let key = DataKey::NoticePeriod(id);
+    env.storage().persistent().set(&key, &notice);
+    bump_persistent(&env, &key);
+    Ok(())
+}
+
+/// Returns the notice period currently in force for split reductions.
+pub fn get_notice_period(env: Env, id: BytesN<32>) -> u64 {
+    let key = DataKey::NoticePeriod(id);
+    let result: Option<NoticePeriod> = env.storage().persistent().get(&key);
+    match result {
+        Some(notice) => {
+            bump_persistent(&env, &key);
+            if env.ledger().timestamp() < notice.changed_at + notice.previous {
+                notice.period.max(notice.previous)
+            } else {
+                notice.period
+            }
+        }
+        None => 0,
+    }
+}
+
+/// Returns whether moving from `old_members` to `new_members` lowers any existing member's share.
+fn reduces_any_share(old_members: &Vec<GroupMember>, new_members: &Vec<GroupMember>) -> bool {
+    for old_member in old_members.iter() {
+        let mut new_percentage = 0;
+        for new_member in new_members.iter() {
+            if new_member.address == old_member.address {
+                new_percentage = new_member.percentage;
+                break;
+            }
+        }
+        if new_percentage < old_member.percentage {
+            return true;
+        }
+    }
+    false
+}
+
+/// Queues a member change until the notice period has elapsed, replacing any queued change.
+fn queue_split_change(
+    env: &Env,
+    id: BytesN<32>,
+    action: ProposalAction,
+    updater: Address,
+) -> Result<(), Error> {
+    let now = env.ledger().timestamp();
+    let effective_at = now + get_notice_period(env.clone(), id.clone());
+    let pending = PendingSplitChange {
+        action,
+        updater: updater.clone(),
+        queued_at: now,
+        effective_at,
+    };
+    let key = DataKey::PendingSplitChange(id.clone());
+    env.storage().persistent().set(&key, &pending);
+    bump_persistent(env, &key);
+
+    SplitChangeQueued {
+        id,
+        updater,
+        effective_at,
+    }
+    .publish(env);
+    Ok(())
+}
+
+/// Points a queued split change at a member's new payout address.
+fn rename_in_split_change(env: &Env, id: &BytesN<32>, old: &Address, new: &Address) {
+    let Some(mut pending) = get_pending_split_change(env.clone(), id.clone()) else {
+        return;
+    };
+    let rename = |address: Address| {
+        if address == *old {
+            new.clone()
+        } else {
+            address
+        }
+    };
+    let rename_strategy = |strategy: RebalanceStrategy| match strategy {
+        RebalanceStrategy::Member(address) => RebalanceStrategy::Member(rename(address)),
+        other => other,
+    };
+    pending.action = match pending.action {
+        ProposalAction::UpdateMembers(members) => {
+            let mut renamed: Vec<GroupMember> = Vec::new(env);
+            for member in members.iter() {
+                renamed.push_back(GroupMember {
+                    address: rename(member.address),
+                    percentage: member.percentage,
+                });
+            }
+            ProposalAction::UpdateMembers(renamed)
+        }
+        ProposalAction::AddMember(address, percentage, strategy) => {
+            ProposalAction::AddMember(address, percentage, rename_strategy(strategy))
+        }
+        ProposalAction::RemoveMember(address, strategy) => {
+            ProposalAction::RemoveMember(rename(address), rename_strategy(strategy))
+        }
+        other => other,
+    };
+    let key = DataKey::PendingSplitChange(id.clone());
+    env.storage().persistent().set(&key, &pending);
+    bump_persistent(env, &key);
+}
+
+fn clear_split_change(env: &Env, id: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PendingSplitChange(id.clone()));
+}
+
+/// Applies the queued change if its notice period is over. Does nothing when no change is due.
+/// A change that no longer fits the group, e.g. removing a member who has since left, is
+/// discarded so that it cannot hold up distributions.
+fn apply_due_split_change(env: &Env, id: &BytesN<32>) -> Result<(), Error> {
+    let pending = match get_pending_split_change(env.clone(), id.clone()) {
+        Some(pending) if env.ledger().timestamp() >= pending.effective_at => pending,
+        _ => return Ok(()),
+    };
+    let details = get_autoshare(env.clone(), id.clone())?;
+    if !details.is_active {
+        return Ok(());
+    }
+
+    clear_split_change(env, id);
+    let updater = pending.updater.clone();
+    if apply_member_action(env, id.clone(), details, pending.action, pending.updater).is_err() {
+        SplitChangeDropped {
+            id: id.clone(),
+            updater,
+        }
+        .publish(env);
+    }
+    Ok(())
+}
+
+/// Applies a queued split change once its notice period has elapsed. Anyone can call this;
+/// distributions also apply a due change before paying out.
+pub fn apply_split_change(env: Env, id: BytesN<32>) -> Result<(), Error> {
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let pending = get_pending_split_change(env.clone(), id.clone()).ok_or(Error::NotFound)?;
+    if env.ledger().timestamp() < pending.effective_at {
+        return Err(Error::NoticePeriodActive);
+    }
+    if !get_autoshare(env.clone(), id.clone())?.is_active {
+        return Err(Error::GroupInactive);
+    }
+    apply_due_split_change(&env, &id)
+}
+
+/// Drops a queued split change. Creator or split manager only.
+pub fn cancel_split_change(env: Env, id: BytesN<32>, caller: Address) -> Result<(), Error> {
+    caller.require_auth();
+
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let details = get_autoshare(env.clone(), id.clone())?;
+    if !has_permission(&env, &details, &caller, Permission::ChangeSplits) {
+        return Err(Error::Unauthorized);
+    }
+    if get_pending_split_change(env.clone(), id.clone()).is_none() {
+        return Err(Error::NotFound);
+    }
+
+    clear_split_change(&env, &id);
+    SplitChangeCancelled { id, caller }.publish(&env);
+    Ok(())
+}
+
+pub fn get_pending_split_change(env: Env, id: BytesN<32>) -> Option<PendingSplitChange> {
+    let key = DataKey::PendingSplitChange(id);
+    let result: Option<PendingSplitChange> = env.storage().persistent().get(&key);
+    if result.is_some() {
+        bump_persistent(&env, &key);
+    }
+    result
+}
+
+// ============================================================================
+// Group Ownership
+// ============================================================================
+
+/// Proposes a new creator for the group. The transfer completes once they accept; proposing
+/// again replaces the pending candidate.
+pub fn propose_ownership_transfer(
+    env: Env,
+    id: BytesN<32>,
+    caller: Address,
+    new_creator: Address,
+) -> Result<(), Error> {
+    caller.require_auth();
+
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let details = get_autoshare(env.clone(), id.clone())?;
+    if details.creator != caller {
+        return Err(Error::Unauthorized);
+    }
+    if new_creator == caller {
+        return Err(Error::InvalidInput);
+    }
+
+    let key = DataKey::PendingOwner(id.clone());
+    env.storage().persistent().set(&key, &new_creator);
+    bump_persistent(&env, &key);
+
+    OwnershipTransferProposed {
+        id,
+        creator: caller,
+        new_creator,
+    }
+    .publish(&env);
+    Ok(())
+}
+
+/// Completes a pending ownership transfer. Must be called by the proposed creator.
+pub fn accept_ownership_transfer(
+    env: Env,
+    id: BytesN<32>,
+    new_creator: Address,
+) -> Result<(), Error> {
+    new_creator.require_auth();
+
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let pending_key = DataKey::PendingOwner(id.clone());
+    let pending: Address = env
+        .storage()
+        .persistent()
+        .get(&pending_key)
+        .ok_or(Error::NotFound)?;
+    if pending != new_creator {
+        return Err(Error::Unauthorized);
+    }
+
+    let key = DataKey::AutoShare(id.clone());
+    let mut details: AutoShareDetails = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::NotFound)?;
+    let old_creator = details.creator.clone();
+    details.creator = new_creator.clone();
+    env.storage().persistent().set(&key, &details);
+    bump_persistent(&env, &key);
+    env.storage().persistent().remove(&pending_key);
+    remove_group_from_creator_index(&env, &old_creator, &id);
+    add_group_to_creator_index(&env, &new_creator, &id);
+
+    // The creator implicitly holds every permission, so drop any manager entry they had
+    let managers_key = DataKey::GroupManagers(id.clone());
+    let mut managers = get_managers(env.clone(), id.clone());
+    for (idx, manager) in managers.iter().enumerate() {
+        if manager.address == new_creator {
+            managers.remove(idx as u32);
+            if managers.is_empty() {
+                env.storage().persistent().remove(&managers_key);
+            } else {
+                env.storage().persistent().set(&managers_key, &managers);
+            }
+            break;
+        }
+    }
+
+    OwnershipTransferred {
+        id,
+        old_creator,
+        new_creator,
+    }
+    .publish(&env);
+    Ok(())
+}
+
+/// Withdraws a pending ownership transfer. Only the current creator can cancel it.
+pub fn cancel_ownership_transfer(env: Env, id: BytesN<32>, caller: Address) -> Result<(), Error> {
+    caller.require_auth();
+
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let details = get_autoshare(env.clone(), id.clone())?;
+    if details.creator != caller {
+        return Err(Error::Unauthorized);
+    }
+
+    let key = DataKey::PendingOwner(id);
+    if !env.storage().persistent().has(&key) {
+        return Err(Error::NotFound);
+    }
+    env.storage().persistent().remove(&key);
+    Ok(())
+}
+
+pub fn get_pending_owner(env: Env, id: BytesN<32>) -> Option<Address> {
+    let key = DataKey::PendingOwner(id);
+    let result: Option<Address> = env.storage().persistent().get(&key);
+    if result.is_some() {
+        bump_persistent(&env, &key);
+    }
+    result
+}
+
+// ============================================================================
+// Admin Management
+// ============================================================================
+
+pub fn initialize_admin(env: Env, admin: Address) {
+    admin.require_auth();
+    let admin_key = DataKey::Admin;
+
+    // Only set if not already initialized
+    if !env.storage().persistent().has(&admin_key) {
+        env.storage().persistent().set(&admin_key, &admin);
+        bump_persistent(&env, &admin_key);
+
+        // Initialize default usage fee (10 tokens per usage)
+        let usage_fee_key = DataKey::UsageFee;
+        env.storage().persistent().set(&usage_fee_key, &10u32);
+        bump_persistent(&env, &usage_fee_key);
+
+        // Initialize empty supported tokens list
+        let tokens_key = DataKey::SupportedTokens;
+        let empty_tokens: Vec<Address> = Vec::new(&env);
+        env.storage().persistent().set(&tokens_key, &empty_tokens);
+        bump_persistent(&env, &tokens_key);
+    } else {
+        bump_persistent(&env, &admin_key);
+    }
+}
+
+fn require_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+    let admin_key = DataKey::Admin;
+    let admin: Address = env
+        .storage()
+        .persistent()
+        .get(&admin_key)
+        .ok_or(Error::Unauthorized)?;
+    bump_persistent(env, &admin_key);
+
+    if admin != *caller {
+        return Err(Error::Unauthorized);
+    }
+
+    Ok(())
+}
+
+pub fn get_admin(env: Env) -> Result<Address, Error> {
+    let admin_key = DataKey::Admin;
+    let result: Option<Address> = env.storage().persistent().get(&admin_key);
+    if result.is_some() {
+        bump_persistent(&env, &admin_key);
+    }
+    result.ok_or(Error::NotFound)
+}
+
+pub fn transfer_admin(env: Env, current_admin: Address, new_admin: Address) -> Result<(), Error> {
+    current_admin.require_auth();
+    require_admin(&env, &current_admin)?;
+
+    let admin_key = DataKey::Admin;
+    env.storage().persistent().set(&admin_key, &new_admin);
+    bump_persistent(&env, &admin_key);
+    AdminTransferred {
+        old_admin: current_admin,
+        new_admin,
+    }
+    .publish(&env);
+    Ok(())
+}
+
+// ============================================================================
+// Pause Management
+// ============================================================================
+
+pub fn pause(env: Env, admin: Address) -> Result<(), Error> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    let pause_key = DataKey::IsPaused;
+    let is_paused: bool = env.storage().persistent().get(&pause_key).unwrap_or(false);
+    bump_persistent(&env, &pause_key);
+
+    if is_paused {
+        return Err(Error::AlreadyPaused);
+    }
+
+    env.storage().persistent().set(&pause_key, &true);
+    bump_persistent(&env, &pause_key);
+    ContractPaused {}.publish(&env);
+    Ok(())
+}
+
+pub fn unpause(env: Env, admin: Address) -> Result<(), Error> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    let pause_key = DataKey::IsPaused;
+    let is_paused: bool = env.storage().persistent().get(&pause_key).unwrap_or(false);
+    bump_persistent(&env, &pause_key);
+
+    if !is_paused {
+        return Err(Error::NotPaused);
+    }
+
+    env.storage().persistent().set(&pause_key, &false);
+    bump_persistent(&env, &pause_key);
+    ContractUnpaused {}.publish(&env);
+    Ok(())
+}
+
+pub fn get_paused_status(env: &Env) -> bool {
+    let pause_key = DataKey::IsPaused;
+    let is_paused: bool = env.storage().persistent().get(&pause_key).unwrap_or(false);
+    if is_paused {
+        bump_persistent(env, &pause_key);
+    }
+    is_paused
+}
+
+// ============================================================================
+// Supported Tokens Management
+// ============================================================================
+
+pub fn add_supported_token(env: Env, token: Address, admin: Address) -> Result<(), Error> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    let tokens_key = DataKey::SupportedTokens;
+    let mut tokens: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&tokens_key)
+        .unwrap_or(Vec::new(&env));
+    if !tokens.is_empty() {
+        bump_persistent(&env, &tokens_key);
+    }
+
+    // Check if token is already supported
+    for existing_token in tokens.iter() {
+        if existing_token == token {
+            return Err(Error::AlreadyExists);
+        }
+    }
+
+    tokens.push_back(token);
+    env.storage().persistent().set(&tokens_key, &tokens);
+    bump_persistent(&env, &tokens_key);
+    Ok(())
+}
+
+pub fn remove_supported_token(env: Env, token: Address, admin: Address) -> Result<(), Error> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    let tokens_key = DataKey::SupportedTokens;
+    let tokens: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&tokens_key)
+        .unwrap_or(Vec::new(&env));
+    if !tokens.is_empty() {
+        bump_persistent(&env, &tokens_key);
+    }
+
+    let mut new_tokens: Vec<Address> = Vec::new(&env);
+    let mut found = false;
+
+    for existing_token in tokens.iter() {
+        if existing_token != token {
+            new_tokens.push_back(existing_token);
+        } else {
+            found = true;
+        }
+    }
+
+    if !found {
+        return Err(Error::NotFound);
+    }
+
+    env.storage().persistent().set(&tokens_key, &new_tokens);
+    bump_persistent(&env, &tokens_key);
+    Ok(())
+}
+
+pub fn get_supported_tokens(env: Env) -> Vec<Address> {
+    let tokens_key = DataKey::SupportedTokens;
+    let result: Option<Vec<Address>> = env.storage().persistent().get(&tokens_key);
+    if result.is_some() {
+        bump_persistent(&env, &tokens_key);
+    }
+    result.unwrap_or(Vec::new(&env))
+}
+
+pub fn is_token_supported(env: Env, token: Address) -> bool {
+    let tokens = get_supported_tokens(env);
+    for supported_token in tokens.iter() {
+        if supported_token == token {
+            return true;
+        }
+    }
+    false
+}
+
+// ============================================================================
+// Payment Configuration
+// ============================================================================
+
+pub fn set_usage_fee(env: Env, fee: u32, admin: Address) -> Result<(), Error> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+    if fee == 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let fee_key = DataKey::UsageFee;
+    env.storage().persistent().set(&fee_key, &fee);
+    bump_persistent(&env, &fee_key);
+    Ok(())
+}
+
+pub fn get_usage_fee(env: Env) -> u32 {
+    let fee_key = DataKey::UsageFee;
+    let result: Option<u32> = env.storage().persistent().get(&fee_key);
+    if result.is_some() {
+        bump_persistent(&env, &fee_key);
+    }
+    result.unwrap_or(10u32)
+}
+
+// ============================================================================
+// Subscription Management
+// ============================================================================
+
+pub fn topup_subscription(
+    env: Env,
+    id: BytesN<32>,
+    additional_usages: u32,
+    payment_token: Address,
+    payer: Address,
+) -> Result<(), Error> {
+    payer.require_auth();
+
+    // Check if contract is paused
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
+
+    // Validate usage count
+    if additional_usages == 0 {
+        return Err(Error::InvalidUsageCount);
+    }
+
+    // Verify group exists
+    let key = DataKey::AutoShare(id.clone());
+    let mut details: AutoShareDetails = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::NotFound)?;
+    bump_persistent(&env, &key);
+
+    // Verify token is supported
+    if !is_token_supported(env.clone(), payment_token.clone()) {
+        return Err(Error::UnsupportedToken);
+    }
+
+    // Calculate cost
+    let usage_fee = get_usage_fee(env.clone());
+    let total_cost = (additional_usages as i128) * (usage_fee as i128);
+
+    // Transfer tokens from payer to contract
+    let token_client = token::Client::new(&env, &payment_token);
+    token_client.transfer(&payer, env.current_contract_address(), &total_cost);
+
+    // Update usage counts
+    details.usage_count += additional_usages;
+    details.total_usages_paid += additional_usages;
+
+    // Save updated details
+    env.storage().persistent().set(&key, &details);
+    bump_persistent(&env, &key);
+
+    // Record payment history
+    record_payment(env, payer, id, additional_usages, total_cost, payment_token);
+
+    Ok(())
+}
+
+// ============================================================================
+// Payment History
+// ============================================================================
+
+fn record_payment(
+    env: Env,
+    user: Address,
+    group_id: BytesN<32>,
+    usages_purchased: u32,
+    amount_paid: i128,
+    token: Address,
+) {
+    let timestamp = env.ledger().timestamp();
+
+    let payment = PaymentHistory {
+        user: user.clone(),
+        group_id: group_id.clone(),
+        usages_purchased,
+        amount_paid,
+        timestamp,
+    };
+
+    append_history(&env, HistoryLog::UserPayments(user), payment.clone());
+    append_history(&env, HistoryLog::GroupPayments(group_id), payment);
+    add_to_reports(&env, amount_paid, |interval, period| {
+        ReportKey::FeeRevenue(token.clone(), interval, period)
+    });
+    add_token_stat(
+        &env,
+        StatsKey::FeesCollected(token.clone()),
+        &token,
+        amount_paid,
+    );
+}
+
+pub fn get_user_payment_history(
+    env: Env,
+    user: Address,
+    cursor: u32,
+    limit: u32,
+) -> Vec<PaymentHistory> {
+    read_history(&env, HistoryLog::UserPayments(user), cursor, limit)
+}
+
+pub fn get_group_payment_history(
+    env: Env,
+    id: BytesN<32>,
+    cursor: u32,
+    limit: u32,
+) -> Vec<PaymentHistory> {
+    read_history(&env, HistoryLog::GroupPayments(id), cursor, limit)
+}
+
+// ============================================================================
+// Distribution History
+// ============================================================================
+
+fn record_distribution(
+    env: Env,
+    group_id: BytesN<32>,
+    sender: Address,
+    total_amount: i128,
+    token: Address,
+    member_amounts: Vec<MemberAmount>,
+    distribution_number: u32,
+) {
+    let timestamp = env.ledger().timestamp();
+
+    let distribution = DistributionHistory {
+        group_id: group_id.clone(),
+        sender: sender.clone(),
+        total_amount,
+        token: token.clone(),
+        member_amounts: member_amounts.clone(),
+        timestamp,
+        distribution_number,
+        split_version: get_current_split_version(&env, &group_id),
+    };
+
+    append_history(
+        &env,
+        HistoryLog::GroupDistributions(group_id.clone()),
+        distribution,
+    );
+
+    for member_amount in member_amounts.iter() {
+        let record = DistributionRecord {
+            group_id: group_id.clone(),
+            amount: member_amount.amount,
+            token: token.clone(),
+            timestamp,
+        };
+        append_history(
+            &env,
+            HistoryLog::MemberDistributions(member_amount.address),
+            record,
+        );
+    }
+}
+
+pub fn get_group_distributions(
+    env: Env,
+    id: BytesN<32>,
+    cursor: u32,
+    limit: u32,
+) -> Vec<DistributionHistory> {
+    read_history(&env, HistoryLog::GroupDistributions(id), cursor, limit)
+}
+
+pub fn get_member_distributions(
+    env: Env,
+    member: Address,
+    cursor: u32,
+    limit: u32,
+) -> Vec<DistributionRecord> {
+    read_history(&env, HistoryLog::MemberDistributions(member), cursor, limit)
+}
+
+// ============================================================================
+// Protocol Statistics
+// ============================================================================
+
+pub fn get_protocol_stats(env: Env) -> ProtocolStats {
+    let mut distributed_volume: Vec<TokenAmount> = Vec::new(&env);
+    let mut fees_collected: Vec<TokenAmount> = Vec::new(&env);
+    let mut amount_raised: Vec<TokenAmount> = Vec::new(&env);
+    for token in get_stat_tokens(&env).iter() {
+        for (key, totals) in [
+            (
+                StatsKey::DistributedVolume(token.clone()),
+                &mut distributed_volume,
+            ),
+            (StatsKey::FeesCollected(token.clone()), &mut fees_collected),
+            (StatsKey::AmountRaised(token.clone()), &mut amount_raised),
+        ] {
+            let amount = read_stat(&env, &key);
+            if amount > 0 {
+                totals.push_back(TokenAmount {
+                    token: token.clone(),
+                    amount,
+                });
+            }
+        }
+    }
+
+    ProtocolStats {
+        total_groups: registry_len(&env),
+        active_groups: read_stat(&env, &StatsKey::ActiveGroups) as u32,
+        total_members: read_stat(&env, &StatsKey::TotalMembers) as u32,
+        total_distributions: read_stat(&env, &StatsKey::TotalDistributions) as u32,
+        distributed_volume,
+        fees_collected,
+        total_contributions: read_stat(&env, &StatsKey::TotalContributions) as u32,
+        amount_raised,
+    }
+}
+
+/// Returns a group's distribution, contribution and usage aggregates.
+pub fn get_group_stats(env: Env, id: BytesN<32>) -> Result<GroupStatsView, Error> {
+    let details = get_autoshare(env.clone(), id.clone())?;
+    let contributions = get_group_contribution_stats(&env, &id);
+
+    let mut distributed: Vec<TokenAmount> = Vec::new(&env);
+    let mut raised: Vec<TokenAmount> = Vec::new(&env);
+    for token in get_group_tokens(&env, &id).iter() {
+        let amount = read_stat(&env, &StatsKey::GroupDistributed(id.clone(), token.clone()));
+        if amount > 0 {
+            distributed.push_back(TokenAmount {
+                token: token.clone(),
+                amount,
+            });
+        }
+        let amount = read_stat(&env, &StatsKey::GroupRaised(id.clone(), token.clone()));
+        if amount > 0 {
+            raised.push_back(TokenAmount { token, amount });
+        }
+    }
+
+    let last_activity_key = StatsKey::GroupLastActivity(id.clone());
+    let last_activity: u64 = env
+        .storage()
+        .persistent()
+        .get(&last_activity_key)
+        .unwrap_or(0);
+    if last_activity > 0 {
+        bump_persistent(&env, &last_activity_key);
+    }
+
+    Ok(GroupStatsView {
+        distribution_count: read_stat(&env, &StatsKey::GroupDistributionCount(id.clone())) as u32,
+        distributed,
+        unique_senders: read_stat(&env, &StatsKey::GroupSenderCount(id)) as u32,
+        contribution_count: contributions.contribution_count,
+        raised,
+        last_activity,
+        usages_consumed: details.total_usages_paid - details.usage_count,
+    })
+}
+
+fn get_group_contribution_stats(env: &Env, id: &BytesN<32>) -> GroupStats {
+    let key = DataKey::GroupStats(id.clone());
+    let stats: Option<GroupStats> = env.storage().persistent().get(&key);
+    if stats.is_some() {
+        bump_persistent(env, &key);
+    }
+    stats.unwrap_or(GroupStats {
+        total_raised: 0,
+        contribution_count: 0,
+    })
+}
+
+fn record_group_distribution_stats(
+    env: &Env,
+    id: &BytesN<32>,
+    sender: &Address,
+    token: &Address,
+    amount: i128,
+) {
+    add_to_stat(env, StatsKey::GroupDistributionCount(id.clone()), 1);
+    add_to_stat(
+        env,
+        StatsKey::GroupDistributed(id.clone(), token.clone()),
+        amount,
+    );
+
+    let sender_key = StatsKey::GroupSender(id.clone(), sender.clone());
+    if !env.storage().persistent().has(&sender_key) {
+        env.storage().persistent().set(&sender_key, &true);
+        add_to_stat(env, StatsKey::GroupSenderCount(id.clone()), 1);
+    }
+    bump_persistent(env, &sender_key);
+    touch_group_activity(env, id);
+}
+
+fn touch_group_activity(env: &Env, id: &BytesN<32>) {
+    let key = StatsKey::GroupLastActivity(id.clone());
+    env.storage()
+        .persistent()
+        .set(&key, &env.ledger().timestamp());
+    bump_persistent(env, &key);
+}
+
+fn read_stat(env: &Env, key: &StatsKey) -> i128 {
+    let value: i128 = env.storage().persistent().get(key).unwrap_or(0);
+    if value != 0 {
+        bump_persistent(env, key);
+    }
+    value
+}
+
+fn add_to_stat(env: &Env, key: StatsKey, delta: i128) {
+    let value = read_stat(env, &key);
+    env.storage().persistent().set(&key, &(value + delta));
+    bump_persistent(env, &key);
+}
+
+/// Applies a change of one group to ActiveGroups or TotalMembers. Groups that predate the
+/// counters are skipped until `backfill_protocol_stats` has counted them.
+fn add_group_stat(env: &Env, id: &BytesN<32>, key: StatsKey, delta: i128) {
+    if is_group_counted(env, id) {
+        add_to_stat(env, key, delta);
+    }
+}
+
+fn is_group_counted(env: &Env, id: &BytesN<32>) -> bool {
+    let key = StatsKey::GroupCounted(id.clone());
+    let counted = env.storage().persistent().has(&key);
+    if counted {
+        bump_persistent(env, &key);
+    }
+    counted
+}
+
+fn mark_group_counted(env: &Env, id: &BytesN<32>) {
+    let key = StatsKey::GroupCounted(id.clone());
+    env.storage().persistent().set(&key, &true);
+    bump_persistent(env, &key);
+}
+
+/// Adds up to `limit` registered groups, starting at registry position `start_index`, to the
+/// ActiveGroups and TotalMembers counters. Groups that are already counted are skipped, so
+/// batches can be re-run safely. Returns how many groups were counted. Admin only.
+pub fn backfill_protocol_stats(
+    env: Env,
+    admin: Address,
+    start_index: u32,
+    limit: u32,
+) -> Result<u32, Error> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    let end = start_index.saturating_add(limit).min(registry_len(&env));
+    let mut counted = 0;
+    for i in start_index..end {
+        let Some(id) = registry_id_at(&env, i) else {
+            continue;
+        };
+        if is_group_counted(&env, &id) {
+            continue;
+        }
+        if let Ok(details) = get_autoshare(env.clone(), id.clone()) {
+            mark_group_counted(&env, &id);
+            if details.is_active {
+                add_to_stat(&env, StatsKey::ActiveGroups, 1);
+            }
+            add_to_stat(&env, StatsKey::TotalMembers, details.members.len() as i128);
+            counted += 1;
+        }
+    }
+    Ok(counted)
+}
+
+/// Adds to a per-token total, remembering the token for `get_protocol_stats`.
+fn add_token_stat(env: &Env, key: StatsKey, token: &Address, amount: i128) {
+    let mut tokens = get_stat_tokens(env);
+    if !tokens.contains(token) {
+        tokens.push_back(token.clone());
+        env.storage().persistent().set(&StatsKey::Tokens, &tokens);
+    }
+    add_to_stat(env, key, amount);
+}
+
+fn get_stat_tokens(env: &Env) -> Vec<Address> {
+    let tokens: Option<Vec<Address>> = env.storage().persistent().get(&StatsKey::Tokens);
+    if tokens.is_some() {
+        bump_persistent(env, &StatsKey::Tokens);
+    }
+    tokens.unwrap_or(Vec::new(env))
+}
+
+// ============================================================================
+// Reports
+// ============================================================================
+
+/// Returns the non-empty buckets of a group's distributed volume in `token` between the bucket
+/// numbers `from` and `to`, inclusive.
+pub fn get_group_volume_report(
+    env: Env,
+    id: BytesN<32>,
+    token: Address,
+    interval: ReportInterval,
+    from: u32,
+    to: u32,
+) -> Result<Vec<ReportBucket>, Error> {
+    read_report(&env, interval, from, to, |period| {
+        ReportKey::GroupVolume(id.clone(), token.clone(), interval, period)
+    })
+}
+
+/// Returns the non-empty buckets of a member's earnings in `token` across all groups.
+pub fn get_member_earnings_report(
+    env: Env,
+    member: Address,
+    token: Address,
+    interval: ReportInterval,
+    from: u32,
+    to: u32,
+) -> Result<Vec<ReportBucket>, Error> {
+    let mut month: Option<(u32, Map<u32, i128>)> = None;
+    read_buckets(&env, interval, from, to, |period| {
+        let month_period = match interval {
+            ReportInterval::Daily => {
+                report_period(ReportInterval::Monthly, period as u64 * SECONDS_PER_DAY)
+            }
+            ReportInterval::Monthly => period,
+        };
+        // Consecutive days mostly fall in the month read last
+        if month
+            .as_ref()
+            .is_none_or(|(cached, _)| *cached != month_period)
+        {
+            let key = ReportKey::MemberEarnings(member.clone(), token.clone(), month_period);
+            let days: Map<u32, i128> = env
+                .storage()
+                .persistent()
+                .get(&key)
+                .unwrap_or(Map::new(&env));
+            if !days.is_empty() {
+                bump_persistent(&env, &key);
+            }
+            month = Some((month_period, days));
+        }
+        let (_, days) = month.as_ref().unwrap();
+        match interval {
+            ReportInterval::Daily => days.get(period),
+            ReportInterval::Monthly if days.is_empty() => None,
+            ReportInterval::Monthly => Some(days.values().iter().sum()),
+        }
+    })
+}
+
+/// Returns the non-empty buckets of usage fees collected in `token`.
+pub fn get_fee_revenue_report(
+    env: Env,
+    token: Address,
+    interval: ReportInterval,
+    from: u32,
+    to: u32,
+) -> Result<Vec<ReportBucket>, Error> {
+    read_report(&env, interval, from, to, |period| {
+        ReportKey::FeeRevenue(token.clone(), interval, period)
+    })
+}
+
+/// Adds `amount` to the current daily and monthly buckets of a report.
+fn add_to_reports<F>(env: &Env, amount: i128, key: F)
+where
+    F: Fn(ReportInterval, u32) -> ReportKey,
+{
+    let timestamp = env.ledger().timestamp();
+    for interval in [ReportInterval::Daily, ReportInterval::Monthly] {
+        let key = key(interval, report_period(interval, timestamp));
+        let total: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(total + amount));
+        bump_persistent(env, &key);
+    }
+}
+
+/// Adds a member's payout to the day it falls on, in the entry holding that month.
+fn add_to_member_report(env: &Env, member: &Address, token: &Address, amount: i128) {
+    let timestamp = env.ledger().timestamp();
+    let day = report_period(ReportInterval::Daily, timestamp);
+    let key = ReportKey::MemberEarnings(
+        member.clone(),
+        token.clone(),
+        report_period(ReportInterval::Monthly, timestamp),
+    );
+    let mut days: Map<u32, i128> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Map::new(env));
+    days.set(day, days.get(day).unwrap_or(0) + amount);
+    env.storage().persistent().set(&key, &days);
+    bump_persistent(env, &key);
+}
+
+fn read_report<F>(
+    env: &Env,
+    interval: ReportInterval,
+    from: u32,
+    to: u32,
+    key: F,
+) -> Result<Vec<ReportBucket>, Error>
+where
+    F: Fn(u32) -> ReportKey,
+{
+    read_buckets(env, interval, from, to, |period| {
+        let bucket_key = key(period);
+        let amount = env.storage().persistent().get::<_, i128>(&bucket_key);
+        if amount.is_some() {
+            bump_persistent(env, &bucket_key);
+        }
+        amount
+    })
+}
+
+/// Collects the non-empty buckets from `from` to `to`, inclusive, with `amount` looking up each.
+fn read_buckets<F>(
+    env: &Env,
+    interval: ReportInterval,
+    from: u32,
+    to: u32,
+    mut amount: F,
+) -> Result<Vec<ReportBucket>, Error>
+where
+    F: FnMut(u32) -> Option<i128>,
+{
+    check_report_range(interval, from, to)?;
+
+    let mut buckets: Vec<ReportBucket> = Vec::new(env);
+    let mut period = from;
+    let mut visited = 0;
+    while period <= to {
+        visited += 1;
+        if visited > MAX_REPORT_BUCKETS {
+            return Err(Error::InvalidInput);
+        }
+        if let Some(amount) = amount(period) {
+            buckets.push_back(ReportBucket { period, amount });
+        }
+        period = match interval {
+            ReportInterval::Daily => period + 1,
+            ReportInterval::Monthly if period % 100 == 12 => (period / 100 + 1) * 100 + 1,
+            ReportInterval::Monthly => period + 1,
+        };
+    }
+    Ok(buckets)
+}
+
+fn check_report_range(interval: ReportInterval, from: u32, to: u32) -> Result<(), Error> {
+    if from > to
+        || (interval == ReportInterval::Monthly && !(is_valid_month(from) && is_valid_month(to)))
+    {
+        return Err(Error::InvalidInput);
+    }
+    Ok(())
+}
+
+fn is_valid_month(period: u32) -> bool {
+    (1..=12).contains(&(period % 100))
+}
+
+/// Returns the bucket number a timestamp falls in: days since the epoch, or `YYYYMM` (UTC).
+fn report_period(interval: ReportInterval, timestamp: u64) -> u32 {
+    let days = timestamp / SECONDS_PER_DAY;
+    match interval {
+        ReportInterval::Daily => days as u32,
+        ReportInterval::Monthly => {
+            // Civil-from-days conversion on the proleptic Gregorian calendar
+            let z = days + 719_468;
+            let era = z / 146_097;
+            let day_of_era = z - era * 146_097;
+            let year_of_era =
+                (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+            let day_of_year =
+                day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+            let shifted_month = (5 * day_of_year + 2) / 153;
+            let month = if shifted_month < 10 {
+                shifted_month + 3
+            } else {
+                shifted_month - 9
+            };
+            let year = year_of_era + era * 400 + u64::from(month <= 2);
+            (year * 100 + month) as u32
+        }
+    }
+}
+
+// ============================================================================
+// Paged History Storage
+// ============================================================================
+
+/// Returns how many entries a history holds, counting a legacy single-entry history that has not
+/// been moved into pages yet.
+fn history_len(env: &Env, log: &HistoryLog) -> u32 {
+    let count_key = HistoryKey::Count(log.clone());
+    if let Some(count) = env.storage().persistent().get::<_, u32>(&count_key) {
+        bump_persistent(env, &count_key);
+        return count;
+    }
+    read_legacy_history(env, log).map_or(0, |legacy| legacy.len())
+}
+
+fn legacy_history_key(log: &HistoryLog) -> DataKey {
+    match log {
+        HistoryLog::UserPayments(user) => DataKey::UserPaymentHistory(user.clone()),
+        HistoryLog::GroupPayments(id) => DataKey::GroupPaymentHistory(id.clone()),
+        HistoryLog::GroupDistributions(id) => DataKey::GroupDistributionHistory(id.clone()),
+        HistoryLog::MemberDistributions(member) => DataKey::MemberDistributions(member.clone()),
+        HistoryLog::GroupContributions(id) => DataKey::GroupContributions(id.clone()),
+    }
+}
+
+fn read_legacy_history(env: &Env, log: &HistoryLog) -> Option<Vec<Val>> {
+    let legacy_key = legacy_history_key(log);
+    let legacy: Option<Vec<Val>> = env.storage().persistent().get(&legacy_key);
+    if legacy.is_some() {
+        bump_persistent(env, &legacy_key);
+    }
+    legacy
+}
+
+/// Cuts page `page_number` out of a legacy history, upgrading old distribution entries.
+fn legacy_page(env: &Env, log: &HistoryLog, legacy: &Vec<Val>, page_number: u32) -> Vec<Val> {
+    let page_size = history_page_size(log);
+    let start = (page_number * page_size).min(legacy.len());
+    let end = (start + page_size).min(legacy.len());
+    let page = legacy.slice(start..end);
+    match log {
+        HistoryLog::GroupDistributions(_) => upgrade_legacy_distributions(env, &page),
+        _ => page,
+    }
+}
+
+/// Returns a page of a history, falling back to the legacy history for pages not moved yet.
+fn read_history_page<T>(env: &Env, log: &HistoryLog, page_number: u32) -> Vec<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let page_key = HistoryKey::Page(log.clone(), page_number);
+    if let Some(page) = env.storage().persistent().get::<_, Vec<T>>(&page_key) {
+        bump_persistent(env, &page_key);
+        return page;
+    }
+    match read_legacy_history(env, log) {
+        Some(legacy) => {
+            let page: Val = legacy_page(env, log, &legacy, page_number).into_val(env);
+            Vec::try_from_val(env, &page).unwrap()
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Moves up to `limit` pages of a legacy single-entry history into paged storage, continuing
+/// where the previous call stopped, and drops the legacy entry once every page is moved. Returns
+/// whether the history is fully migrated. Admin only.
+pub fn migrate_history(
+    env: Env,
+    admin: Address,
+    log: HistoryLog,
+    limit: u32,
+) -> Result<bool, Error> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    let Some(legacy) = read_legacy_history(&env, &log) else {
+        return Ok(true);
+    };
+
+    let progress_key = HistoryKey::Migrated(log.clone());
+    let mut page_number: u32 = env.storage().persistent().get(&progress_key).unwrap_or(0);
+    let pages = legacy.len().div_ceil(history_page_size(&log));
+    let end = page_number.saturating_add(limit).min(pages);
+    while page_number < end {
+        let page_key = HistoryKey::Page(log.clone(), page_number);
+        // A page appended to since the legacy history was last read already holds its entries
+        if !env.storage().persistent().has(&page_key) {
+            env.storage()
+                .persistent()
+                .set(&page_key, &legacy_page(&env, &log, &legacy, page_number));
+            bump_persistent(&env, &page_key);
+        }
+        page_number += 1;
+    }
+
+    if page_number < pages {
+        env.storage().persistent().set(&progress_key, &page_number);
+        bump_persistent(&env, &progress_key);
+        return Ok(false);
+    }
+
+    let count_key = HistoryKey::Count(log.clone());
+    if !env.storage().persistent().has(&count_key) {
+        env.storage().persistent().set(&count_key, &legacy.len());
+        bump_persistent(&env, &count_key);
+    }
+    env.storage().persistent().remove(&legacy_history_key(&log));
+    env.storage().persistent().remove(&progress_key);
+    Ok(true)
+}
+
+/// Rewrites distribution entries recorded before split versions existed into the current
+/// layout, with `split_version` 0.
+fn upgrade_legacy_distributions(env: &Env, entries: &Vec<Val>) -> Vec<Val> {
+    let split_version = Symbol::new(env, "split_version");
+    let mut upgraded: Vec<Val> = Vec::new(env);
+    for entry in entries.iter() {
+        let fields = Map::<Symbol, Val>::try_from_val(env, &entry).unwrap();
+        if fields.contains_key(split_version.clone()) {
+            upgraded.push_back(entry);
+            continue;
+        }
+        let legacy = LegacyDistributionHistory::try_from_val(env, &entry).unwrap();
+        let distribution = DistributionHistory {
+            group_id: legacy.group_id,
+            sender: legacy.sender,
+            total_amount: legacy.total_amount,
+            token: legacy.token,
+            member_amounts: legacy.member_amounts,
+            timestamp: legacy.timestamp,
+            distribution_number: legacy.distribution_number,
+            split_version: 0,
+        };
+        upgraded.push_back(distribution.into_val(env));
+    }
+    upgraded
+}
+
+/// Appends an entry to the last page of a history, starting a new page when it is full.
+fn append_history<T>(env: &Env, log: HistoryLog, entry: T)
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let count = history_len(env, &log);
+    let page_number = count / history_page_size(&log);
+    let page_key = HistoryKey::Page(log.clone(), page_number);
+    let mut page: Vec<T> = read_history_page(env, &log, page_number);
+    page.push_back(entry);
+    env.storage().persistent().set(&page_key, &page);
+    bump_persistent(env, &page_key);
+
+    let count_key = HistoryKey::Count(log);
+    env.storage().persistent().set(&count_key, &(count + 1));
+    bump_persistent(env, &count_key);
+}
+
+/// Distribution histories get a page rewritten for the group and for every paid member on each
+/// distribution, so their pages are kept smaller to bound that cost for large groups.
+fn history_page_size(log: &HistoryLog) -> u32 {
+    match log {
+        HistoryLog::GroupDistributions(_) | HistoryLog::MemberDistributions(_) => {
+            DISTRIBUTION_PAGE_SIZE
+        }
+        _ => HISTORY_PAGE_SIZE,
+    }
+}
+
+/// Returns every entry of a history, oldest first.
+fn read_full_history<T>(env: &Env, log: &HistoryLog) -> Vec<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let pages = history_len(env, log).div_ceil(history_page_size(log));
+    let mut entries: Vec<T> = Vec::new(env);
+    for page_number in 0..pages {
+        entries.append(&read_history_page(env, log, page_number));
+    }
+    entries
+}
+
+/// Returns up to `limit` entries of a history, oldest first, starting at entry `cursor`.
+fn read_history<T>(env: &Env, log: HistoryLog, cursor: u32, limit: u32) -> Vec<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let count = history_len(env, &log);
+    let page_size = history_page_size(&log);
+    let end = cursor
+        .saturating_add(limit.min(MAX_HISTORY_LIMIT))
+        .min(count);
+    let mut entries: Vec<T> = Vec::new(env);
+    let mut index = cursor;
+    while index < end {
+        let page_number = index / page_size;
+        let page: Vec<T> = read_history_page(env, &log, page_number);
+
+        let page_start = page_number * page_size;
+        let page_end = (end - page_start).min(page.len());
+        for offset in (index - page_start)..page_end {
+            entries.push_back(page.get(offset).unwrap());
+        }
+        index = page_start + page_size;
+    }
+    entries
+}
+
+// ============================================================================
+// Usage Tracking
+// ============================================================================
+
+pub fn get_remaining_usages(env: Env, id: BytesN<32>) -> Result<u32, Error> {
+    let key = DataKey::AutoShare(id);
+    let details: AutoShareDetails = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::NotFound)?;
+    bump_persistent(&env, &key);
+    Ok(details.usage_count)
+}
+
+pub fn get_total_usages_paid(env: Env, id: BytesN<32>) -> Result<u32, Error> {
+    let key = DataKey::AutoShare(id);
+    let details: AutoShareDetails = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::NotFound)?;
+    bump_persistent(&env, &key);
+    Ok(details.total_usages_paid)
+}
+
+#[cfg(test)]
+#[allow(dead_code)]
+pub fn reduce_usage(env: Env, id: BytesN<32>) -> Result<(), Error> {
+    let key = DataKey::AutoShare(id);
+    let mut details: AutoShareDetails = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::NotFound)?;
+    bump_persistent(&env, &key);
+
+    if details.usage_count == 0 {
+        return Err(Error::NoUsagesRemaining);
+    }
+
+    details.usage_count -= 1;
+    env.storage().persistent().set(&key, &details);
+    bump_persistent(&env, &key);
+    Ok(())
+}
+
+// ============================================================================
+// Group Activation Management
+// ============================================================================
+
+pub fn update_members(
+    env: Env,
+    id: BytesN<32>,
+    caller: Address,
+    new_members: Vec<GroupMember>,
+) -> Result<(), Error> {
+    caller.require_auth();
+
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let key = DataKey::AutoShare(id.clone());
+    let details: AutoShareDetails = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::NotFound)?;
+    bump_persistent(&env, &key);
+
+    if !has_permission(&env, &details, &caller, Permission::ChangeSplits) {
+        return Err(Error::Unauthorized);
+    }
+
+    if !details.is_active {
+        return Err(Error::GroupInactive);
+    }
+
+    if is_governed(&env, &id) {
+        return Err(Error::GovernanceRequired);
+    }
+
+    // Addresses joining the group have to consent, like accepting an invite
+    for member in new_members.iter() {
+        let joining = !details
+            .members
+            .iter()
+            .any(|existing| existing.address == member.address);
+        if joining && member.address != caller {
+            member.address.require_auth();
+        }
+    }
+
+    if get_notice_period(env.clone(), id.clone()) > 0
+        && reduces_any_share(&details.members, &new_members)
+    {
+        if new_members.len() > MAX_MEMBERS {
+            return Err(Error::MaxMembersExceeded);
+        }
+        validate_members(&new_members)?;
+        return queue_split_change(&env, id, ProposalAction::UpdateMembers(new_members), caller);
+    }
+
+    replace_group_members(&env, id, details, new_members, caller)
+}
+
+pub fn deactivate_group(env: Env, id: BytesN<32>, caller: Address) -> Result<(), Error> {
+    caller.require_auth();
+
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let key = DataKey::AutoShare(id.clone());
+    let mut details: AutoShareDetails = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::NotFound)?;
+    bump_persistent(&env, &key);
+
+    if !has_permission(&env, &details, &caller, Permission::Activation) {
+        return Err(Error::Unauthorized);
+    }
+
+    if !details.is_active {
+        return Err(Error::GroupAlreadyInactive);
+    }
+
+    details.is_active = false;
+    add_group_stat(&env, &id, StatsKey::ActiveGroups, -1);
+    env.storage().persistent().set(&key, &details);
+    bump_persistent(&env, &key);
+
+    GroupDeactivated {
+        id: id.clone(),
+        creator: caller,
+    }
+    .publish(&env);
+    Ok(())
+}
+
+pub fn activate_group(env: Env, id: BytesN<32>, caller: Address) -> Result<(), Error> {
+    caller.require_auth();
+
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let key = DataKey::AutoShare(id.clone());
+    let mut details: AutoShareDetails = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::NotFound)?;
+    bump_persistent(&env, &key);
+
+    if !has_permission(&env, &details, &caller, Permission::Activation) {
+        return Err(Error::Unauthorized);
+    }
+
+    if details.is_active {
+        return Err(Error::GroupAlreadyActive);
+    }
+
+    details.is_active = true;
+    add_group_stat(&env, &id, StatsKey::ActiveGroups, 1);
+    env.storage().persistent().set(&key, &details);
+    bump_persistent(&env, &key);
+
+    GroupActivated {
+        id: id.clone(),
+        creator: caller,
+    }
+    .publish(&env);
+    Ok(())
+}
+
+pub fn update_group_name(
+    env: Env,
+    id: BytesN<32>,
+    caller: Address,
+    new_name: String,
+) -> Result<(), Error> {
+    caller.require_auth();
+
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let key = DataKey::AutoShare(id.clone());
+    let mut details: AutoShareDetails = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::NotFound)?;
+
+    if !has_permission(&env, &details, &caller, Permission::Rename) {
+        return Err(Error::Unauthorized);
+    }
+
+    if !details.is_active {
+        return Err(Error::GroupInactive);
+    }
+
+    if !is_valid_name(&new_name) {
+        return Err(Error::EmptyName);
+    }
+
+    details.name = new_name;
+    env.storage().persistent().set(&key, &details);
+
+    GroupNameUpdated {
+        id: id.clone(),
+        updater: caller,
+    }
+    .publish(&env);
+    Ok(())
+}
+
+pub fn is_group_active(env: Env, id: BytesN<32>) -> Result<bool, Error> {
+    let key = DataKey::AutoShare(id);
+    let details: AutoShareDetails = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::NotFound)?;
+    bump_persistent(&env, &key);
+    Ok(details.is_active)
+}
+
+// ============================================================================
+// Group Deletion
+// ============================================================================
+
+/// Permanently deletes a group from the contract.
+/// Requirements:
+/// 1. Caller must be the group creator or admin
+/// 2. Group must be deactivated
+/// 3. Group must have 0 remaining usages (or they are forfeited)
+/// 4. Removes group from the group registry
+/// 5. Removes AutoShare(id) entry
+/// 6. Archives payment history before deletion (keeps it for audit trail)
+/// 7. Emits GroupDeleted event
+pub fn delete_group(env: Env, id: BytesN<32>, caller: Address) -> Result<(), Error> {
+    caller.require_auth();
+
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
+
+    // Step 1: Verify group exists
+    let key = DataKey::AutoShare(id.clone());
+    let details: AutoShareDetails = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::NotFound)?;
+    // We don't bump here if we are removing it, but we might return early if deactivated check fails
+    // However, the requirement says bump on every read.
+    bump_persistent(&env, &key);
+
+    // Step 2: Verify caller is creator or admin
+    let admin_result = get_admin(env.clone());
+    let is_admin = admin_result.is_ok() && admin_result.unwrap() == caller;
+    let is_creator = details.creator == caller;
+
+    if !is_creator && !is_admin {
+        return Err(Error::Unauthorized);
+    }
+
+    // Step 3: Check group is already deactivated
+    if details.is_active {
+        return Err(Error::GroupNotDeactivated);
+    }
+
+    // Step 4: Check group has 0 remaining usages (or warn about forfeiture)
+    // We allow deletion even with remaining usages, but this is a design choice
+    // In production, you might want to enforce zero usages or handle refunds
+    if details.usage_count > 0 {
+        // Option 1: Strict enforcement - uncomment to require zero usages
+        // return Err(Error::GroupHasRemainingUsages);
+
+        // Option 2: Allow deletion with forfeiture (current implementation)
+        // The remaining usages are simply forfeited
+    }
+
+    // Step 5: Remove the group from the group registry, the creator's index and the member total,
+    // and retire its id
+    unregister_group(&env, &id);
+    let deleted_key = RegistryKey::Deleted(id.clone());
+    env.storage().persistent().set(&deleted_key, &true);
+    bump_persistent(&env, &deleted_key);
+    remove_group_from_creator_index(&env, &details.creator, &id);
+    add_group_stat(
+        &env,
+        &id,
+        StatsKey::TotalMembers,
+        -(details.members.len() as i128),
+    );
+    env.storage()
+        .persistent()
+        .remove(&StatsKey::GroupCounted(id.clone()));
+
+    // Step 6: Remove the AutoShare(id) entry and refund any unused matching pool
+    env.storage().persistent().remove(&key);
+    release_matching_pool_funds(&env, &id);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::MatchingPool(id.clone()));
+
+    // Step 7: Archive payment history (we keep it for audit trail)
+    // Payment history is intentionally NOT deleted to maintain financial records
+    // This is a best practice for compliance and auditing purposes
+    // The entries remain in:
+    // - HistoryLog::UserPayments(Address)
+    // - HistoryLog::GroupPayments(BytesN<32>)
+
+    // Step 8: Remove group from all members' MemberGroups index
+    for member in details.members.iter() {
+        let member_groups_key = DataKey::MemberGroups(member.address.clone());
+        let member_groups: Vec<BytesN<32>> = env
+            .storage()
+            .persistent()
+            .get(&member_groups_key)
+            .unwrap_or(Vec::new(&env));
+
+        let mut updated_member_groups: Vec<BytesN<32>> = Vec::new(&env);
+        let mut group_removed = false;
+        for group_id in member_groups.iter() {
+            if group_id != id {
+                updated_member_groups.push_back(group_id);
+            } else {
+                group_removed = true;
+            }
+        }
+        if group_removed {
+            env.storage()
+                .persistent()
+                .set(&member_groups_key, &updated_member_groups);
+            bump_persistent(&env, &member_groups_key);
+        }
+    }
+
+    // Step 9: Emit deletion event
+    GroupDeleted {
+        deleter: caller,
+        id: id.clone(),
+    }
+    .publish(&env);
+
+    Ok(())
+}
+
+// ============================================================================
+// Contract Balance & Withdrawal
+// ============================================================================
+
+pub fn get_contract_balance(env: Env, token: Address) -> i128 {
+    let client = token::TokenClient::new(&env, &token);
+    client.balance(&env.current_contract_address())
+}
+
+pub fn withdraw(
+    env: Env,
+    admin: Address,
+    token: Address,
+    amount: i128,
+    recipient: Address,
+) -> Result<(), Error> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let contract_balance = get_contract_balance(env.clone(), token.clone());
+    if contract_balance < amount {
+        return Err(Error::InsufficientContractBalance);
+    }
+
+    let client = token::TokenClient::new(&env, &token);
+    client.transfer(&env.current_contract_address(), &recipient, &amount);
+
+    Withdrawal {
+        token,
+        amount,
+        recipient,
+    }
+    .publish(&env);
+    Ok(())
+}
+
+#[allow(clippy::needless_borrows_for_generic_args)]
+pub fn distribute(
+    env: Env,
+    id: BytesN<32>,
+    token: Address,
+    amount: i128,
+    sender: Address,
+) -> Result<(), Error> {
+    sender.require_auth();
+
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
+
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    if !is_token_supported(env.clone(), token.clone()) {
+        return Err(Error::UnsupportedToken);
+    }
+
+    // Splits queued behind a notice period switch over on the first payout after it ends
+    apply_due_split_change(&env, &id)?;
+
+    let key = DataKey::AutoShare(id.clone());
+    let mut details: AutoShareDetails = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::NotFound)?;
+    bump_persistent(&env, &key);
+
+    if !details.is_active {
+        return Err(Error::GroupInactive);
+    }
+
+    if details.usage_count == 0 {
+        return Err(Error::NoUsagesRemaining);
+    }
+
+    validate_members(&details.members)?;
+
+    let client = token::TokenClient::new(&env, &token);
+    client.transfer(&sender, &env.current_contract_address(), &amount);
+    let member_amounts = perform_distribution(&env, &id, &token, amount, &details.members);
+    let distribution_number = details.total_usages_paid - details.usage_count;
+    record_distribution(
+        env.clone(),
+        id.clone(),
+        sender.clone(),
+        amount,
+        token.clone(),
+        member_amounts.clone(),
+        distribution_number,
+    );
+    add_to_stat(&env, StatsKey::TotalDistributions, 1);
+    record_group_distribution_stats(&env, &id, &sender, &token, amount);
+    // Emit new distribution event for fund flow tracking
+    emit_distribution(&env, &id, &sender, &token, amount, member_amounts.len());
+
+    details.usage_count -= 1;
+    env.storage().persistent().set(&key, &details);
+    bump_persistent(&env, &key);
+
+    Ok(())
+}
+
+fn perform_distribution(
+    env: &Env,
+    id: &BytesN<32>,
+    token: &Address,
+    amount: i128,
+    members: &Vec<GroupMember>,
+) -> Vec<MemberAmount> {
+    let client = token::TokenClient::new(env, token);
+    add_group_token(env, id, token);
+    add_to_reports(env, amount, |interval, period| {
+        ReportKey::GroupVolume(id.clone(), token.clone(), interval, period)
+    });
+    add_token_stat(
+        env,
+        StatsKey::DistributedVolume(token.clone()),
+        token,
+        amount,
+    );
+    let (weights, total_weight) = vested_weights(env, id, members);
+    let mut distributed: i128 = 0;
+    let members_len = weights.len() as usize;
+    let mut member_amounts: Vec<MemberAmount> = Vec::new(env);
+    for (idx, member) in weights.iter().enumerate() {
+        let share = if idx + 1 < members_len {
+            (amount * member.amount) / total_weight
+        } else {
+            amount - distributed
+        };
+        if share > 0 {
+            client.transfer(&env.current_contract_address(), &member.address, &share);
+            distributed += share;
+            member_amounts.push_back(MemberAmount {
+                address: member.address.clone(),
+                amount: share,
+            });
+
+            // Update the member's running totals for this token
+            add_earnings(
+                env,
+                &EarningsKey::Group(member.address.clone(), id.clone(), token.clone()),
+                share,
+            );
+            add_earnings(
+                env,
+                &EarningsKey::Lifetime(member.address.clone(), token.clone()),
+                share,
+            );
+            add_to_member_report(env, &member.address, token, share);
+        }
+    }
+    member_amounts
+}
+
+/// Returns each recipient's weight for a distribution along with the total weight. Without
+/// vesting the weights are the members' percentages; unvested parts of a share are moved to the
+/// creator or spread over the members whose shares are fully vested.
+fn vested_weights(
+    env: &Env,
+    id: &BytesN<32>,
+    members: &Vec<GroupMember>,
+) -> (Vec<MemberAmount>, i128) {
+    let now = env.ledger().timestamp();
+    let mut weights: Vec<MemberAmount> = Vec::new(env);
+    let mut to_creator: i128 = 0;
+    let mut to_vested: i128 = 0;
+    let mut vested_total: i128 = 0;
+    let mut fully_vested: Vec<bool> = Vec::new(env);
+    // Most groups have no schedules, so skip looking one up for every member
+    let count_key = DataKey::VestingCount(id.clone());
+    let has_vesting = env
+        .storage()
+        .persistent()
+        .get::<_, u32>(&count_key)
+        .is_some_and(|count| count > 0);
+    if has_vesting {
+        bump_persistent(env, &count_key);
+    }
+
+    for member in members.iter() {
+        let full = member.percentage as i128 * VESTING_SCALE;
+        let schedule = if has_vesting {
+            get_vesting_schedule(env.clone(), id.clone(), member.address.clone())
+        } else {
+            None
+        };
+        let vested = match schedule {
+            Some(schedule) => {
+                let vested = full * vested_fraction(&schedule, now) / VESTING_SCALE;
+                match schedule.redirect {
+                    UnvestedRedirect::Creator => to_creator += full - vested,
+                    UnvestedRedirect::VestedMembers => to_vested += full - vested,
+                }
+                vested
+            }
+            None => full,
+        };
+        fully_vested.push_back(vested == full);
+        if vested == full {
+            vested_total += vested;
+        }
+        weights.push_back(MemberAmount {
+            address: member.address,
+            amount: vested,
+        });
+    }
+
+    let total_weight = members.iter().map(|m| m.percentage as i128).sum::<i128>() * VESTING_SCALE;
+    if to_vested > 0 && vested_total > 0 {
+        let mut spread: i128 = 0;
+        for idx in 0..weights.len() {
+            if !fully_vested.get(idx).unwrap() {
+                continue;
+            }
+            let mut weight = weights.get(idx).unwrap();
+            let extra = to_vested * weight.amount / vested_total;
+            spread += extra;
+            weight.amount += extra;
+            weights.set(idx, weight);
+        }
+        // Rounding leftovers of the spread follow the creator redirect
+        to_creator += to_vested - spread;
+    } else {
+        to_creator += to_vested;
+    }
+
+    if to_creator > 0 {
+        if let Ok(details) = get_autoshare(env.clone(), id.clone()) {
+            let mut merged = false;
+            for idx in 0..weights.len() {
+                let mut weight = weights.get(idx).unwrap();
+                if weight.address == details.creator {
+                    weight.amount += to_creator;
+                    weights.set(idx, weight);
+                    merged = true;
+                    break;
+                }
+            }
+            if !merged {
+                weights.push_back(MemberAmount {
+                    address: details.creator,
+                    amount: to_creator,
+                });
+            }
+        }
+    }
+    (weights, total_weight)
+}
+
+/// Vested part of a share in basis points: nothing before the cliff, then linear from `start`
+/// until fully vested at `end`.
+fn vested_fraction(schedule: &VestingSchedule, now: u64) -> i128 {
+    if now < schedule.cliff {
+        0
+    } else if now >= schedule.end {
+        VESTING_SCALE
+    } else {
+        VESTING_SCALE * (now - schedule.start) as i128 / (schedule.end - schedule.start) as i128
+    }
+}
+
+/// Attaches a vesting schedule to a member's share. Set by the creator or a split manager, and
+/// since it holds back the member's payouts the member must authorize it too.
+pub fn set_vesting_schedule(
+    env: Env,
+    id: BytesN<32>,
+    caller: Address,
+    member: Address,
+    schedule: VestingSchedule,
+) -> Result<(), Error> {
+    caller.require_auth();
+    member.require_auth();
+
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let details = get_autoshare(env.clone(), id.clone())?;
+    if !has_permission(&env, &details, &caller, Permission::ChangeSplits) {
+        return Err(Error::Unauthorized);
+    }
+    if member_weight(&details.members, &member) == 0 {
+        return Err(Error::MemberNotFound);
+    }
+    if schedule.start >= schedule.end
+        || schedule.cliff < schedule.start
+        || schedule.cliff > schedule.end
+    {
+        return Err(Error::InvalidInput);
+    }
+
+    let key = DataKey::MemberVesting(id.clone(), member.clone());
+    if !env.storage().persistent().has(&key) {
+        let count_key = DataKey::VestingCount(id.clone());
+        let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        env.storage().persistent().set(&count_key, &(count + 1));
+        bump_persistent(&env, &count_key);
+    }
+    env.storage().persistent().set(&key, &schedule);
+    bump_persistent(&env, &key);
+
+    VestingScheduleUpdated {
+        id,
+        member,
+        schedule: Some(schedule),
+    }
+    .publish(&env);
+    Ok(())
+}
+
+/// Removes a member's vesting schedule so their full share applies. Creator or split manager only.
+pub fn remove_vesting_schedule(
+    env: Env,
+    id: BytesN<32>,
+    caller: Address,
+    member: Address,
+) -> Result<(), Error> {
+    caller.require_auth();
+
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let details = get_autoshare(env.clone(), id.clone())?;
+    if !has_permission(&env, &details, &caller, Permission::ChangeSplits) {
+        return Err(Error::Unauthorized);
+    }
+
+    if !remove_vesting(&env, &id, &member) {
+        return Err(Error::NotFound);
+    }
+
+    VestingScheduleUpdated {
+        id,
+        member,
+        schedule: None,
+    }
+    .publish(&env);
+    Ok(())
+}
+
+/// Deletes a member's vesting schedule, returning whether there was one.
+fn remove_vesting(env: &Env, id: &BytesN<32>, member: &Address) -> bool {
+    let key = DataKey::MemberVesting(id.clone(), member.clone());
+    if !env.storage().persistent().has(&key) {
+        return false;
+    }
+    env.storage().persistent().remove(&key);
+
+    let count_key = DataKey::VestingCount(id.clone());
+    let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&count_key, &count.saturating_sub(1));
+    bump_persistent(env, &count_key);
+    true
+}
+
+pub fn get_vesting_schedule(env: Env, id: BytesN<32>, member: Address) -> Option<VestingSchedule> {
+    let key = DataKey::MemberVesting(id, member);
+    let result: Option<VestingSchedule> = env.storage().persistent().get(&key);
+    if result.is_some() {
+        bump_persistent(&env, &key);
+    }
+    result
+}
+
+/// Returns a member's earnings from a group that pays out in a single token, including any legacy
+/// earnings recorded before per-token tracking. Amounts in different tokens cannot be added up, so
+/// groups that have paid out in several tokens fail with `MultipleTokens`; use
+/// `get_member_earnings_by_token` for those.
+pub fn get_member_earnings(env: Env, member: Address, group_id: BytesN<32>) -> Result<i128, Error> {
+    let tokens = get_group_tokens(&env, &group_id);
+    if tokens.len() > 1 {
+        return Err(Error::MultipleTokens);
+    }
+    let mut earnings = read_earnings(
+        &env,
+        &DataKey::MemberGroupEarnings(member.clone(), group_id.clone()),
+    );
+    if let Some(token) = tokens.first() {
+        earnings += read_earnings(&env, &EarningsKey::Group(member, group_id, token));
+    }
+    Ok(earnings)
+}
+
+pub fn get_member_earnings_by_token(
+    env: Env,
+    member: Address,
+    group_id: BytesN<32>,
+    token: Address,
+) -> i128 {
+    read_earnings(&env, &EarningsKey::Group(member, group_id, token))
+}
+
+pub fn get_member_lifetime_earnings(env: Env, member: Address, token: Address) -> i128 {
+    read_earnings(&env, &EarningsKey::Lifetime(member, token))
+}
+
+/// Moves a member's legacy earnings sum for a group, which predates per-token tracking, into the
+/// totals for `token`. Admin only, since only off-chain records tell which token it was paid in.
+pub fn migrate_member_earnings(
+    env: Env,
+    admin: Address,
+    member: Address,
+    group_id: BytesN<32>,
+    token: Address,
+) -> Result<i128, Error> {
+    admin.require_auth();
+    require_admin(&env, &admin)?;
+
+    let legacy_key = DataKey::MemberGroupEarnings(member.clone(), group_id.clone());
+    if !env.storage().persistent().has(&legacy_key) {
+        return Err(Error::NotFound);
+    }
+    let earnings = take_earnings(&env, &legacy_key);
+    add_group_token(&env, &group_id, &token);
+    add_earnings(
+        &env,
+        &EarningsKey::Group(member.clone(), group_id, token.clone()),
+        earnings,
+    );
+    add_earnings(&env, &EarningsKey::Lifetime(member, token), earnings);
+    Ok(earnings)
+}
+
+fn read_earnings<K>(env: &Env, key: &K) -> i128
+where
+    K: IntoVal<Env, Val>,
+{
+    let earnings: i128 = env.storage().persistent().get(key).unwrap_or(0);
+    if earnings != 0 {
+        bump_persistent(env, key);
+    }
+    earnings
+}
+
+fn add_earnings<K>(env: &Env, key: &K, amount: i128)
+where
+    K: IntoVal<Env, Val>,
+{
+    let earnings: i128 = env.storage().persistent().get(key).unwrap_or(0);
+    env.storage().persistent().set(key, &(earnings + amount));
+    bump_persistent(env, key);
+}
+
+fn take_earnings<K>(env: &Env, key: &K) -> i128
+where
+    K: IntoVal<Env, Val>,
+{
+    let earnings: i128 = env.storage().persistent().get(key).unwrap_or(0);
+    env.storage().persistent().remove(key);
+    earnings
+}
+
+pub fn get_fundraising_status(env: Env, id: BytesN<32>) -> FundraisingConfig {
+    if let Some(mut config) = read_fundraising(&env, &id) {
+        // A campaign past its deadline is reported as inactive even though nobody has closed it
+        config.is_active = is_fundraising_open(&env, &config);
+        config
+    } else {
+        FundraisingConfig {
+            target_amount: 0,
+            total_raised: 0,
+            is_active: false,
+            deadline: 0,
+            is_cancelled: false,
+            overfunding_policy: OverfundingPolicy::CloseAtTarget,
+            hard_cap: 0,
+            campaign_id: 0,
+        }
+    }
+}
+
+/// Reads a group's campaign config, upgrading one stored in the legacy layout.
+fn read_fundraising(env: &Env, id: &BytesN<32>) -> Option<FundraisingConfig> {
+    let key = DataKey::GroupFundraising(id.clone());
+    let fields: Map<Symbol, Val> = env.storage().persistent().get(&key)?;
+    bump_persistent(env, &key);
+
+    if fields.contains_key(Symbol::new(env, "campaign_id")) {
+        return Some(FundraisingConfig::try_from_val(env, &fields.to_val()).unwrap());
+    }
+    let legacy = LegacyFundraisingConfig::try_from_val(env, &fields.to_val()).unwrap();
+    Some(FundraisingConfig {
+        target_amount: legacy.target_amount,
+        total_raised: legacy.total_raised,
+        is_active: legacy.is_active,
+        deadline: 0,
+        is_cancelled: false,
+        overfunding_policy: OverfundingPolicy::CloseAtTarget,
+        hard_cap: 0,
+        campaign_id: 1,
+    })
+}
+
+/// Returns true if the campaign accepts contributions: active and not past its deadline.
+fn is_fundraising_open(env: &Env, config: &FundraisingConfig) -> bool {
+    config.is_active && (config.deadline == 0 || env.ledger().timestamp() <= config.deadline)
+}
+
+/// Returns true once the campaign has raised everything its overfunding policy lets it accept.
+fn is_fundraising_complete(config: &FundraisingConfig) -> bool {
+    match config.overfunding_policy {
+        OverfundingPolicy::AllowUpToCap => config.total_raised >= config.hard_cap,
+        _ => config.total_raised >= config.target_amount,
+    }
+}
+
+/// Returns how much of `amount` the campaign accepts under its overfunding policy.
+fn accepted_contribution(config: &FundraisingConfig, amount: i128) -> Result<i128, Error> {
+    let remaining = config.target_amount - config.total_raised;
+    match config.overfunding_policy {
+        OverfundingPolicy::CloseAtTarget => Ok(amount),
+        OverfundingPolicy::AllowUpToCap => Ok(amount.min(config.hard_cap - config.total_raised)),
+        OverfundingPolicy::RefundExcess => Ok(amount.min(remaining)),
+        OverfundingPolicy::Reject => {
+            if amount > remaining {
+                return Err(Error::ContributionExceedsTarget);
+            }
+            Ok(amount)
+        }
+    }
+}
+
+pub fn get_group_contributions(env: Env, id: BytesN<32>) -> Vec<FundraisingContribution> {
+    read_full_history(&env, &HistoryLog::GroupContributions(id))
+}
+
+pub fn get_user_contributions(env: Env, user: Address) -> Vec<FundraisingContribution> {
+    let key = DataKey::UserContributions(user);
+    let result: Option<Vec<FundraisingContribution>> = env.storage().persistent().get(&key);
+    if result.is_some() {
+        bump_persistent(&env, &key);
+    }
+    result.unwrap_or(Vec::new(&env))
+}
+
+fn validate_members(members: &Vec<GroupMember>) -> Result<(), Error> {
+    if members.is_empty() {
+        return Err(Error::EmptyMembers);
+    }
+    let env = members.env();
+    let mut total_percentage: u32 = 0;
+    let mut seen_addresses = Vec::new(env);
+
+    for member in members.iter() {
+        if member.percentage == 0 {
+            return Err(Error::InvalidInput);
+        }
+        total_percentage += member.percentage;
+        for seen in seen_addresses.iter() {
+            if seen == member.address {
+                return Err(Error::DuplicateMember);
+            }
+        }
+        seen_addresses.push_back(member.address.clone());
+    }
+
+    if total_percentage != 100 {
+        return Err(Error::InvalidTotalPercentage);
+    }
+    Ok(())
+}
+
+pub fn start_fundraising(
+    env: Env,
+    id: BytesN<32>,
+    caller: Address,
+    target_amount: i128,
+) -> Result<(), Error> {
+    caller.require_auth();
+
+    // Check if contract is paused
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
+
+    // Verify group exists
+    let key = DataKey::AutoShare(id.clone());
+    let details: AutoShareDetails = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::NotFound)?;
+    bump_persistent(&env, &key);
+
+    // Verify caller is the creator or a fundraising manager
+    if !has_permission(&env, &details, &caller, Permission::Fundraising) {
+        return Err(Error::Unauthorized);
+    }
+
+    // Verify group is active
+    if !details.is_active {
+        return Err(Error::GroupInactive);
+    }
+
+    // Check no active fundraiser already exists for this group
+    let mut campaign_id = 1;
+    if let Some(config) = read_fundraising(&env, &id) {
+        if is_fundraising_open(&env, &config) {
+            return Err(Error::FundraisingAlreadyActive);
+        }
+        campaign_id = config.campaign_id + 1;
+    }
+
+    // Validate target_amount > 0
+    if target_amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    // Refund what is left of a pool sponsoring the previous campaign, e.g. one that expired
+    release_matching_pool_funds(&env, &id);
+
+    // Store a new FundraisingConfig
+    let fundraising_config = FundraisingConfig {
+        target_amount,
+        total_raised: 0,
+        is_active: true,
+        deadline: 0,
+        is_cancelled: false,
+        overfunding_policy: OverfundingPolicy::CloseAtTarget,
+        hard_cap: 0,
+        campaign_id,
+    };
+
+    save_fundraising(&env, &id, &fundraising_config);
+
+    // Emit a FundraisingStarted event
+    FundraisingStarted {
+        group_id: id,
+        target_amount,
+    }
+    .publish(&env);
+
+    Ok(())
+}
+
+/// Loads a group for a change that needs `permission`, held by the creator or a manager.
+fn load_group_with_permission(
+    env: &Env,
+    id: &BytesN<32>,
+    caller: &Address,
+    permission: Permission,
+) -> Result<AutoShareDetails, Error> {
+    if get_paused_status(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let key = DataKey::AutoShare(id.clone());
+    let details: AutoShareDetails = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::NotFound)?;
+    bump_persistent(env, &key);
+
+    if !has_permission(env, &details, caller, permission) {
+        return Err(Error::Unauthorized);
+    }
+    Ok(details)
+}
+
+/// Loads the running campaign of a group for a lifecycle change by its creator.
+fn load_open_fundraising(
+    env: &Env,
+    id: &BytesN<32>,
+    caller: &Address,
+) -> Result<FundraisingConfig, Error> {
+    load_group_with_permission(env, id, caller, Permission::Fundraising)?;
+
+    let config = read_fundraising(env, id).ok_or(Error::FundraisingNotActive)?;
+    if !is_fundraising_open(env, &config) {
+        return Err(Error::FundraisingNotActive);
+    }
+    Ok(config)
+}
+
+fn save_fundraising(env: &Env, id: &BytesN<32>, config: &FundraisingConfig) {
+    let fundraising_key = DataKey::GroupFundraising(id.clone());
+    env.storage().persistent().set(&fundraising_key, config);
+    bump_persistent(env, &fundraising_key);
+}
+
+/// Ends a running campaign before its target is reached. Funds raised so far stay with members.
+pub fn close_fundraising(env: Env, id: BytesN<32>, caller: Address) -> Result<(), Error> {
+    caller.require_auth();
+    let mut config = load_open_fundraising(&env, &id, &caller)?;
+
+    config.is_active = false;
+    save_fundraising(&env, &id, &config);
+    release_matching_pool_funds(&env, &id);
+
+    FundraisingClosed {
+        group_id: id,
+        total_raised: config.total_raised,
+    }
+    .publish(&env);
+    Ok(())
+}
+
+/// Cancels a running campaign. Contributions are distributed on receipt, so nothing is refunded;
+/// the campaign is only marked as cancelled.
+pub fn cancel_fundraising(env: Env, id: BytesN<32>, caller: Address) -> Result<(), Error> {
+    caller.require_auth();
+    let mut config = load_open_fundraising(&env, &id, &caller)?;
+
+    config.is_active = false;
+    config.is_cancelled = true;
+    save_fundraising(&env, &id, &config);
+    release_matching_pool_funds(&env, &id);
+
+    FundraisingCancelled {
+        group_id: id,
+        total_raised: config.total_raised,
+    }
+    .publish(&env);
+    Ok(())
+}
+
+/// Sets a deadline on a running campaign or pushes the existing one further out.
+pub fn extend_fundraising_deadline(
+    env: Env,
+    id: BytesN<32>,
+    caller: Address,
+    new_deadline: u64,
+) -> Result<(), Error> {
+    caller.require_auth();
+    let mut config = load_open_fundraising(&env, &id, &caller)?;
+
+    if new_deadline <= env.ledger().timestamp() {
+        return Err(Error::InvalidDeadline);
+    }
+    if config.deadline != 0 && new_deadline <= config.deadline {
+        return Err(Error::InvalidDeadline);
+    }
+
+    config.deadline = new_deadline;
+    save_fundraising(&env, &id, &config);
+
+    FundraisingDeadlineExtended {
+        group_id: id,
+        deadline: new_deadline,
+    }
+    .publish(&env);
+    Ok(())
+}
+
+/// Raises or lowers the target of a running campaign. The target cannot go below the amount
+/// already raised; setting it exactly to that amount completes the campaign.
+pub fn update_fundraising_target(
+    env: Env,
+    id: BytesN<32>,
+    caller: Address,
+    new_target: i128,
+) -> Result<(), Error> {
+    caller.require_auth();
+    let mut config = load_open_fundraising(&env, &id, &caller)?;
+
+    if new_target <= 0 || new_target < config.total_raised {
+        return Err(Error::InvalidAmount);
+    }
+
+    if config.overfunding_policy == OverfundingPolicy::AllowUpToCap && new_target > config.hard_cap
+    {
+        return Err(Error::InvalidAmount);
+    }
+
+    let old_target = config.target_amount;
+    config.target_amount = new_target;
+    if is_fundraising_complete(&config) {
+        config.is_active = false;
+    }
+    save_fundraising(&env, &id, &config);
+    if !config.is_active {
+        release_matching_pool_funds(&env, &id);
+    }
+
+    FundraisingTargetUpdated {
+        group_id: id,
+        old_target,
+        new_target,
+    }
+    .publish(&env);
+    Ok(())
+}
+
+/// Sets how a running campaign handles contributions that go past its target.
+/// `hard_cap` is only used by `AllowUpToCap` and must be at least the target.
+pub fn set_overfunding_policy(
+    env: Env,
+    id: BytesN<32>,
+    caller: Address,
+    policy: OverfundingPolicy,
+    hard_cap: i128,
+) -> Result<(), Error> {
+    caller.require_auth();
+    let mut config = load_open_fundraising(&env, &id, &caller)?;
+
+    if policy == OverfundingPolicy::AllowUpToCap {
+        if hard_cap < config.target_amount || hard_cap <= config.total_raised {
+            return Err(Error::InvalidAmount);
+        }
+        config.hard_cap = hard_cap;
+    } else {
+        // Campaigns already past their target can only keep running under a hard cap
+        if config.total_raised >= config.target_amount {
+            return Err(Error::InvalidAmount);
+        }
+        config.hard_cap = 0;
+    }
+    config.overfunding_policy = policy;
+    save_fundraising(&env, &id, &config);
+    Ok(())
+}
+
+/// Sets the contribution limits applied to the group's campaigns. Zero disables a limit.
+pub fn set_contribution_rules(
+    env: Env,
+    id: BytesN<32>,
+    caller: Address,
+    rules: ContributionRules,
+) -> Result<(), Error> {
+    caller.require_auth();
+    load_group_with_permission(&env, &id, &caller, Permission::Fundraising)?;
+
+    if rules.min_contribution < 0 || rules.max_contribution < 0 || rules.contributor_cap < 0 {
+        return Err(Error::InvalidAmount);
+    }
+    if rules.max_contribution > 0 && rules.min_contribution > rules.max_contribution {
+        return Err(Error::InvalidAmount);
+    }
+    if rules.contributor_cap > 0 && rules.min_contribution > rules.contributor_cap {
+        return Err(Error::InvalidAmount);
+    }
+
+    let key = DataKey::ContributionRules(id);
+    env.storage().persistent().set(&key, &rules);
+    bump_persistent(&env, &key);
+    Ok(())
+}
+
+pub fn get_contribution_rules(env: Env, id: BytesN<32>) -> ContributionRules {
+    let key = DataKey::ContributionRules(id);
+    let result: Option<ContributionRules> = env.storage().persistent().get(&key);
+    if result.is_some() {
+        bump_persistent(&env, &key);
+    }
+    result.unwrap_or(ContributionRules {
+        min_contribution: 0,
+        max_contribution: 0,
+        contributor_cap: 0,
+        allowlist_enabled: false,
+    })
+}
+
+pub fn add_to_allowlist(
+    env: Env,
+    id: BytesN<32>,
+    caller: Address,
+    contributor: Address,
+) -> Result<(), Error> {
+    caller.require_auth();
+    load_group_with_permission(&env, &id, &caller, Permission::Fundraising)?;
+
+    let key = DataKey::FundraisingAllowlist(id);
+    let mut allowlist: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(&env));
+
+    if allowlist.contains(&contributor) {
+        return Err(Error::AlreadyExists);
+    }
+    if allowlist.len() >= MAX_ALLOWLIST_SIZE {
+        return Err(Error::AllowlistFull);
+    }
+
+    allowlist.push_back(contributor);
+    env.storage().persistent().set(&key, &allowlist);
+    bump_persistent(&env, &key);
+    Ok(())
+}
+
+pub fn remove_from_allowlist(
+    env: Env,
+    id: BytesN<32>,
+    caller: Address,
+    contributor: Address,
+) -> Result<(), Error> {
+    caller.require_auth();
+    load_group_with_permission(&env, &id, &caller, Permission::Fundraising)?;
+
+    let key = DataKey::FundraisingAllowlist(id);
+    let mut allowlist: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(&env));
+
+    let index = allowlist
+        .first_index_of(&contributor)
+        .ok_or(Error::NotFound)?;
+    allowlist.remove(index);
+    env.storage().persistent().set(&key, &allowlist);
+    bump_persistent(&env, &key);
+    Ok(())
+}
+
+pub fn get_allowlist(env: Env, id: BytesN<32>) -> Vec<Address> {
+    let key = DataKey::FundraisingAllowlist(id);
+    let result: Option<Vec<Address>> = env.storage().persistent().get(&key);
+    if result.is_some() {
+        bump_persistent(&env, &key);
+    }
+    result.unwrap_or(Vec::new(&env))
+}
+
+/// Returns how much a contributor has given to the group's current campaign.
+pub fn get_campaign_contribution(env: Env, id: BytesN<32>, contributor: Address) -> i128 {
+    let campaign_id = get_fundraising_status(env.clone(), id.clone()).campaign_id;
+    let key = DataKey::CampaignContributorTotal(id, campaign_id, contributor);
+    let total: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    if total > 0 {
+        bump_persistent(&env, &key);
+    }
+    total
+}
+
+/// Checks a contribution against the group's contribution rules and returns the contributor's
+/// new total for the campaign.
+fn check_contribution_rules(
+    env: &Env,
+    id: &BytesN<32>,
+    config: &FundraisingConfig,
+    contributor: &Address,
+    requested: i128,
+    accepted: i128,
+) -> Result<i128, Error> {
+    let rules = get_contribution_rules(env.clone(), id.clone());
+
+    if rules.allowlist_enabled && !get_allowlist(env.clone(), id.clone()).contains(contributor) {
+        return Err(Error::ContributorNotAllowed);
+    }
+    if requested < rules.min_contribution {
+        return Err(Error::ContributionBelowMinimum);
+    }
+    if rules.max_contribution > 0 && requested > rules.max_contribution {
+        return Err(Error::ContributionLimitExceeded);
+    }
+
+    let total_key =
+        DataKey::CampaignContributorTotal(id.clone(), config.campaign_id, contributor.clone());
+    let previous: i128 = env.storage().persistent().get(&total_key).unwrap_or(0);
+    let new_total = previous + accepted;
+    if rules.contributor_cap > 0 && new_total > rules.contributor_cap {
+        return Err(Error::ContributionLimitExceeded);
+    }
+    Ok(new_total)
+}
+
+/// Where the tokens of a contribution are pulled from.
+enum ContributionSource {
+    /// The contributor signs a direct transfer to the contract.
+    Direct,
+    /// The contract spends an allowance the contributor granted to it.
+    Allowance,
+}
+
+/// Contributes to a running campaign and returns the amount actually accepted.
+/// Any part of `amount` rejected by the overfunding policy is never taken from the contributor.
+pub fn contribute(
+    env: Env,
+    id: BytesN<32>,
+    token: Address,
+    amount: i128,
+    contributor: Address,
+) -> Result<i128, Error> {
+    contributor.require_auth();
+    apply_contribution(
+        &env,
+        &id,
+        &token,
+        amount,
+        &contributor,
+        ContributionSource::Direct,
+    )
+}
+
+/// Validates, collects, distributes and records a contribution. Shared by every path that
+/// feeds a campaign so they all obey the same campaign rules.
+fn apply_contribution(
+    env: &Env,
+    id: &BytesN<32>,
+    token: &Address,
+    amount: i128,
+    contributor: &Address,
+    source: ContributionSource,
+) -> Result<i128, Error> {
+    if get_paused_status(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    if !is_token_supported(env.clone(), token.clone()) {
+        return Err(Error::UnsupportedToken);
+    }
+
+    // Verify group exists and is active
+    let group_key = DataKey::AutoShare(id.clone());
+    let group_details: AutoShareDetails = env
+        .storage()
+        .persistent()
+        .get(&group_key)
+        .ok_or(Error::NotFound)?;
+    bump_persistent(env, &group_key);
+
+    if !group_details.is_active {
+        return Err(Error::GroupInactive);
+    }
+
+    // Verify fundraising is active
+    let mut fundraising_config = read_fundraising(env, id).ok_or(Error::FundraisingNotActive)?;
+
+    if !fundraising_config.is_active {
+        return Err(Error::FundraisingNotActive);
+    }
+    if !is_fundraising_open(env, &fundraising_config) {
+        return Err(Error::FundraisingExpired);
+    }
+
+    // Only the part allowed by the overfunding policy is taken; the excess stays with the contributor
+    let requested = amount;
+    let amount = accepted_contribution(&fundraising_config, requested)?;
+    let contributor_total =
+        check_contribution_rules(env, id, &fundraising_config, contributor, requested, amount)?;
+
+    // Transfer amount from contributor to the contract
+    let token_client = token::Client::new(env, token);
+    match source {
+        ContributionSource::Direct => {
+            token_client.transfer(contributor, env.current_contract_address(), &amount);
+        }
+        ContributionSource::Allowance => {
+            let contract = env.current_contract_address();
+            token_client.transfer_from(&contract, contributor, &contract, &amount);
+        }
+    }
+
+    let total_key = DataKey::CampaignContributorTotal(
+        id.clone(),
+        fundraising_config.campaign_id,
+        contributor.clone(),
+    );
+    env.storage()
+        .persistent()
+        .set(&total_key, &contributor_total);
+    bump_persistent(env, &total_key);
+
+    record_accepted_contribution(
+        env,
+        id,
+        token,
+        amount,
+        contributor,
+        &mut fundraising_config,
+        &group_details.members,
+    );
+
+    // Count the contribution towards the quadratic funding round the group is in, if any
+    tally_round_contribution(env, id, token, amount, contributor);
+
+    // Sponsors match the contribution from their pool while it lasts
+    apply_matching(
+        env,
+        id,
+        token,
+        amount,
+        contributor,
+        &mut fundraising_config,
+        &group_details.members,
+    );
+
+    if is_fundraising_complete(&fundraising_config) {
+        fundraising_config.is_active = false;
+    }
+    save_fundraising(env, id, &fundraising_config);
+    if !fundraising_config.is_active {
+        release_matching_pool_funds(env, id);
+    }
+
+    Ok(amount)
+}
+
+/// Distributes an accepted contribution held by the contract and records it everywhere
+/// contributions are tracked. The caller saves the updated campaign.
+fn record_accepted_contribution(
+    env: &Env,
+    id: &BytesN<32>,
+    token: &Address,
+    amount: i128,
+    contributor: &Address,
+    fundraising_config: &mut FundraisingConfig,
+    members: &Vec<GroupMember>,
+) {
+    // Distribute funds to group members
+    perform_distribution(env, id, token, amount, members);
+
+    // Update fundraising total
+    fundraising_config.total_raised += amount;
+
+    // Record contribution
+    let contribution = FundraisingContribution {
+        group_id: id.clone(),
+        contributor: contributor.clone(),
+        token: token.clone(),
+        amount,
+        timestamp: env.ledger().timestamp(),
+    };
+
+    append_history(
+        env,
+        HistoryLog::GroupContributions(id.clone()),
+        contribution.clone(),
+    );
+
+    let user_contributions_key = DataKey::UserContributions(contributor.clone());
+    let mut user_contributions: Vec<FundraisingContribution> = env
+        .storage()
+        .persistent()
+        .get(&user_contributions_key)
+        .unwrap_or(Vec::new(env));
+    user_contributions.push_back(contribution);
+    env.storage()
+        .persistent()
+        .set(&user_contributions_key, &user_contributions);
+    bump_persistent(env, &user_contributions_key);
+
+    // Update group stats
+    let stats_key = DataKey::GroupStats(id.clone());
+    let mut stats = get_group_contribution_stats(env, id);
+    stats.total_raised += amount;
+    stats.contribution_count += 1;
+    env.storage().persistent().set(&stats_key, &stats);
+    bump_persistent(env, &stats_key);
+    add_to_stat(env, StatsKey::TotalContributions, 1);
+    add_token_stat(env, StatsKey::AmountRaised(token.clone()), token, amount);
+    add_to_stat(
+        env,
+        StatsKey::GroupRaised(id.clone(), token.clone()),
+        amount,
+    );
+    touch_group_activity(env, id);
+    // Emit new contribution event for fundraising tracking
+    emit_contribution(env, id, contributor, token, amount);
 }
 
 // ============================================================================
-// Group Deletion
+// Matching Pools
 // ============================================================================
 
-/// Permanently deletes a group from the contract.
-/// Requirements:
-/// 1. Caller must be the group creator or admin
-/// 2. Group must be deactivated
-/// 3. Group must have 0 remaining usages (or they are forfeited)
-/// 4. Removes group from AllGroups list
-/// 5. Removes AutoShare(id) entry
-/// 6. Archives payment history before deletion (keeps it for audit trail)
-/// 7. Emits GroupDeleted event
-pub fn delete_group(env: Env, id: BytesN<32>, caller: Address) -> Result<(), Error> {
-    caller.require_auth();
+/// Deposits a sponsor's matching pool into the group's running campaign.
+/// Each later contribution in `token` is matched at `match_ratio` percent (100 = 1:1),
+/// at most `match_cap` per contribution when non-zero, until the pool runs out.
+pub fn add_matching_pool(
+    env: Env,
+    id: BytesN<32>,
+    sponsor: Address,
+    token: Address,
+    amount: i128,
+    match_ratio: u32,
+    match_cap: i128,
+) -> Result<(), Error> {
+    sponsor.require_auth();
 
     if get_paused_status(&env) {
         return Err(Error::ContractPaused);
     }
 
-    // Step 1: Verify group exists
-    let key = DataKey::AutoShare(id.clone());
-    let details: AutoShareDetails = env
-        .storage()
-        .persistent()
-        .get(&key)
-        .ok_or(Error::NotFound)?;
-    // We don't bump here if we are removing it, but we might return early if deactivated check fails
-    // However, the requirement says bump on every read.
-    bump_persistent(&env, &key);
-
-    // Step 2: Verify caller is creator or admin
-    let admin_result = get_admin(env.clone());
-    let is_admin = admin_result.is_ok() && admin_result.unwrap() == caller;
-    let is_creator = details.creator == caller;
-
-    if !is_creator && !is_admin {
-        return Err(Error::Unauthorized);
+    if amount <= 0 || match_cap < 0 {
+        return Err(Error::InvalidAmount);
     }
-
-    // Step 3: Check group is already deactivated
-    if details.is_active {
-        return Err(Error::GroupNotDeactivated);
+    if match_ratio == 0 {
+        return Err(Error::InvalidInput);
     }
 
-    // Step 4: Check group has 0 remaining usages (or warn about forfeiture)
-    // We allow deletion even with remaining usages, but this is a design choice
-    // In production, you might want to enforce zero usages or handle refunds
-    if details.usage_count > 0 {
-        // Option 1: Strict enforcement - uncomment to require zero usages
-        // return Err(Error::GroupHasRemainingUsages);
+    if !is_token_supported(env.clone(), token.clone()) {
+        return Err(Error::UnsupportedToken);
+    }
 
-        // Option 2: Allow deletion with forfeiture (current implementation)
-        // The remaining usages are simply forfeited
+    let group_key = DataKey::AutoShare(id.clone());
+    if !env.storage().persistent().has(&group_key) {
+        return Err(Error::NotFound);
     }
+    bump_persistent(&env, &group_key);
 
-    // Step 5: Remove the group from AllGroups list
-    let all_groups_key = DataKey::AllGroups;
-    let group_ids: Vec<BytesN<32>> = env
-        .storage()
-        .persistent()
-        .get(&all_groups_key)
-        .unwrap_or(Vec::new(&env));
-    if !group_ids.is_empty() {
-        bump_persistent(&env, &all_groups_key);
+    let config = get_fundraising_status(env.clone(), id.clone());
+    if !config.is_active {
+        return Err(Error::FundraisingNotActive);
     }
 
-    let mut new_group_ids: Vec<BytesN<32>> = Vec::new(&env);
-    for group_id in group_ids.iter() {
-        if group_id != id {
-            new_group_ids.push_back(group_id);
+    let pool_key = DataKey::MatchingPool(id.clone());
+    let existing: Option<MatchingPool> = env.storage().persistent().get(&pool_key);
+    if let Some(pool) = existing {
+        if pool.remaining > 0 && pool.campaign_id == config.campaign_id {
+            return Err(Error::AlreadyExists);
         }
+        // A pool left over from an earlier campaign goes back to its sponsor first
+        release_matching_pool_funds(&env, &id);
     }
-    env.storage()
-        .persistent()
-        .set(&all_groups_key, &new_group_ids);
-    bump_persistent(&env, &all_groups_key);
-
-    // Step 6: Remove the AutoShare(id) entry
-    env.storage().persistent().remove(&key);
-
-    // Step 7: Archive payment history (we keep it for audit trail)
-    // Payment history is intentionally NOT deleted to maintain financial records
-    // This is a best practice for compliance and auditing purposes
-    // The entries remain in:
-    // - DataKey::UserPaymentHistory(Address)
-    // - DataKey::GroupPaymentHistory(BytesN<32>)
 
-    // Step 8: Remove group from all members' MemberGroups index
-    for member in details.members.iter() {
-        let member_groups_key = DataKey::MemberGroups(member.address.clone());
-        let member_groups: Vec<BytesN<32>> = env
-            .storage()
-            .persistent()
-            .get(&member_groups_key)
-            .unwrap_or(Vec::new(&env));
+    let token_client = token::Client::new(&env, &token);
+    token_client.transfer(&sponsor, env.current_contract_address(), &amount);
 
-        let mut updated_member_groups: Vec<BytesN<32>> = Vec::new(&env);
-        let mut group_removed = false;
-        for group_id in member_groups.iter() {
-            if group_id != id {
-                updated_member_groups.push_back(group_id);
-            } else {
-                group_removed = true;
-            }
-        }
-        if group_removed {
-            env.storage()
-                .persistent()
-                .set(&member_groups_key, &updated_member_groups);
-            bump_persistent(&env, &member_groups_key);
-        }
-    }
+    let pool = MatchingPool {
+        sponsor: sponsor.clone(),
+        token,
+        match_ratio,
+        match_cap,
+        deposited: amount,
+        remaining: amount,
+        campaign_id: config.campaign_id,
+    };
+    env.storage().persistent().set(&pool_key, &pool);
+    bump_persistent(&env, &pool_key);
 
-    // Step 9: Emit deletion event
-    GroupDeleted {
-        deleter: caller,
-        id: id.clone(),
+    MatchingPoolFunded {
+        group_id: id,
+        sponsor,
+        amount,
     }
     .publish(&env);
-
     Ok(())
 }
 
-// ============================================================================
-// Contract Balance & Withdrawal
-// ============================================================================
+pub fn get_matching_pool(env: Env, id: BytesN<32>) -> Result<MatchingPool, Error> {
+    let key = DataKey::MatchingPool(id);
+    let result: Option<MatchingPool> = env.storage().persistent().get(&key);
+    if result.is_some() {
+        bump_persistent(&env, &key);
+    }
+    result.ok_or(Error::NotFound)
+}
 
-pub fn get_contract_balance(env: Env, token: Address) -> i128 {
-    let client = token::TokenClient::new(&env, &token);
-    client.balance(&env.current_contract_address())
+/// Returns the unused part of a matching pool to its sponsor once the campaign has ended,
+/// e.g. after its deadline passed. Anyone can call.
+pub fn release_matching_pool(env: Env, id: BytesN<32>) -> Result<i128, Error> {
+    let pool = get_matching_pool(env.clone(), id.clone())?;
+
+    let config = get_fundraising_status(env.clone(), id.clone());
+    if config.is_active && config.campaign_id == pool.campaign_id {
+        return Err(Error::FundraisingAlreadyActive);
+    }
+    Ok(release_matching_pool_funds(&env, &id))
 }
 
-pub fn withdraw(
-    env: Env,
-    admin: Address,
-    token: Address,
+/// Matches a contribution from the group's pool and records the match as a contribution
+/// from the sponsor. Matches never push the campaign past what its overfunding policy accepts.
+fn apply_matching(
+    env: &Env,
+    id: &BytesN<32>,
+    token: &Address,
     amount: i128,
-    recipient: Address,
-) -> Result<(), Error> {
-    admin.require_auth();
-    require_admin(&env, &admin)?;
-
-    if amount <= 0 {
-        return Err(Error::InvalidAmount);
+    contributor: &Address,
+    fundraising_config: &mut FundraisingConfig,
+    members: &Vec<GroupMember>,
+) {
+    let pool_key = DataKey::MatchingPool(id.clone());
+    let pool: Option<MatchingPool> = env.storage().persistent().get(&pool_key);
+    let mut pool = match pool {
+        Some(pool) => pool,
+        None => return,
+    };
+    if pool.campaign_id != fundraising_config.campaign_id
+        || pool.token != *token
+        || pool.sponsor == *contributor
+        || pool.remaining == 0
+        || is_fundraising_complete(fundraising_config)
+    {
+        return;
     }
 
-    let contract_balance = get_contract_balance(env.clone(), token.clone());
-    if contract_balance < amount {
-        return Err(Error::InsufficientContractBalance);
+    let mut matched = amount * (pool.match_ratio as i128) / 100;
+    if pool.match_cap > 0 {
+        matched = matched.min(pool.match_cap);
+    }
+    matched = matched.min(pool.remaining);
+    matched = match fundraising_config.overfunding_policy {
+        OverfundingPolicy::CloseAtTarget => matched,
+        OverfundingPolicy::AllowUpToCap => {
+            matched.min(fundraising_config.hard_cap - fundraising_config.total_raised)
+        }
+        OverfundingPolicy::RefundExcess | OverfundingPolicy::Reject => {
+            matched.min(fundraising_config.target_amount - fundraising_config.total_raised)
+        }
+    };
+    if matched <= 0 {
+        return;
     }
 
-    let client = token::TokenClient::new(&env, &token);
-    client.transfer(&env.current_contract_address(), &recipient, &amount);
+    pool.remaining -= matched;
+    env.storage().persistent().set(&pool_key, &pool);
+    bump_persistent(env, &pool_key);
 
-    Withdrawal {
+    record_accepted_contribution(
+        env,
+        id,
         token,
+        matched,
+        &pool.sponsor,
+        fundraising_config,
+        members,
+    );
+}
+
+/// Sends whatever is left in the group's matching pool back to the sponsor.
+fn release_matching_pool_funds(env: &Env, id: &BytesN<32>) -> i128 {
+    let pool_key = DataKey::MatchingPool(id.clone());
+    let pool: Option<MatchingPool> = env.storage().persistent().get(&pool_key);
+    let mut pool = match pool {
+        Some(pool) => pool,
+        None => return 0,
+    };
+    bump_persistent(env, &pool_key);
+
+    let amount = pool.remaining;
+    if amount == 0 {
+        return 0;
+    }
+
+    let token_client = token::Client::new(env, &pool.token);
+    token_client.transfer(&env.current_contract_address(), &pool.sponsor, &amount);
+
+    pool.remaining = 0;
+    env.storage().persistent().set(&pool_key, &pool);
+    bump_persistent(env, &pool_key);
+
+    MatchingPoolReleased {
+        group_id: id.clone(),
+        sponsor: pool.sponsor,
         amount,
-        recipient,
     }
-    .publish(&env);
-    Ok(())
+    .publish(env);
+    amount
 }
 
-#[allow(clippy::needless_borrows_for_generic_args)]
-pub fn distribute(
+// ============================================================================
+// Recurring Pledges
+// ============================================================================
+
+/// Registers a recurring pledge to a group's campaigns and returns its id.
+/// The pledger must separately approve the contract to spend `amount` per payment of `token`;
+/// the first payment is due immediately.
+pub fn create_pledge(
     env: Env,
     id: BytesN<32>,
+    pledger: Address,
     token: Address,
     amount: i128,
-    sender: Address,
-) -> Result<(), Error> {
-    sender.require_auth();
+    interval: u64,
+    max_payments: u32,
+) -> Result<u32, Error> {
+    pledger.require_auth();
 
     if get_paused_status(&env) {
         return Err(Error::ContractPaused);
@@ -1395,362 +5738,533 @@ pub fn distribute(
     if amount <= 0 {
         return Err(Error::InvalidAmount);
     }
+    if interval == 0 || max_payments == 0 {
+        return Err(Error::InvalidInput);
+    }
 
     if !is_token_supported(env.clone(), token.clone()) {
         return Err(Error::UnsupportedToken);
     }
+    let allowance =
+        token::TokenClient::new(&env, &token).allowance(&pledger, &env.current_contract_address());
+    if allowance < amount {
+        return Err(Error::InsufficientAllowance);
+    }
 
-    let key = DataKey::AutoShare(id.clone());
-    let mut details: AutoShareDetails = env
+    let group_key = DataKey::AutoShare(id.clone());
+    if !env.storage().persistent().has(&group_key) {
+        return Err(Error::NotFound);
+    }
+    bump_persistent(&env, &group_key);
+
+    let group_pledges_key = DataKey::GroupPledges(id.clone());
+    let mut group_pledges: Vec<u32> = env
         .storage()
         .persistent()
-        .get(&key)
-        .ok_or(Error::NotFound)?;
-    bump_persistent(&env, &key);
-
-    if !details.is_active {
-        return Err(Error::GroupInactive);
+        .get(&group_pledges_key)
+        .unwrap_or(Vec::new(&env));
+    if group_pledges.len() >= MAX_GROUP_PLEDGES {
+        return Err(Error::MaxPledgesExceeded);
     }
 
-    if details.usage_count == 0 {
-        return Err(Error::NoUsagesRemaining);
+    let count_key = DataKey::PledgeCount;
+    let pledge_id: u32 = env.storage().persistent().get(&count_key).unwrap_or(0) + 1;
+    env.storage().persistent().set(&count_key, &pledge_id);
+    bump_persistent(&env, &count_key);
+
+    let pledge = Pledge {
+        pledge_id,
+        group_id: id.clone(),
+        pledger: pledger.clone(),
+        token,
+        amount,
+        interval,
+        max_payments,
+        payments_made: 0,
+        next_payment_at: env.ledger().timestamp(),
+        is_active: true,
+    };
+    let pledge_key = DataKey::Pledge(pledge_id);
+    env.storage().persistent().set(&pledge_key, &pledge);
+    bump_persistent(&env, &pledge_key);
+
+    group_pledges.push_back(pledge_id);
+    env.storage()
+        .persistent()
+        .set(&group_pledges_key, &group_pledges);
+    bump_persistent(&env, &group_pledges_key);
+
+    PledgeCreated {
+        group_id: id,
+        pledger,
+        pledge_id,
     }
+    .publish(&env);
+    Ok(pledge_id)
+}
 
-    validate_members(&details.members)?;
+/// Collects the next due payment of a pledge through the same path as `contribute`.
+/// Anyone can call this; the tokens are pulled from the pledger's allowance. At most one payment
+/// is collected per call: periods missed entirely are skipped, and the next payment falls due at
+/// the first slot after the current time.
+pub fn collect_pledge(env: Env, pledge_id: u32) -> Result<i128, Error> {
+    let pledge_key = DataKey::Pledge(pledge_id);
+    let mut pledge: Pledge = env
+        .storage()
+        .persistent()
+        .get(&pledge_key)
+        .ok_or(Error::NotFound)?;
+    bump_persistent(&env, &pledge_key);
 
-    let client = token::TokenClient::new(&env, &token);
-    client.transfer(&sender, &env.current_contract_address(), &amount);
-    let member_amounts = perform_distribution(&env, &id, &token, amount, &details.members);
-    let distribution_number = details.total_usages_paid - details.usage_count;
-    record_distribution(
-        env.clone(),
-        id.clone(),
-        sender.clone(),
-        amount,
-        token.clone(),
-        member_amounts.clone(),
-        distribution_number,
-    );
-    // Emit new distribution event for fund flow tracking
-    emit_distribution(
-        &env,
-        &id,
-        &sender,
-        &token,
-        amount,
-        member_amounts.len() as u32,
-    );
+    if !pledge.is_active {
+        return Err(Error::PledgeNotActive);
+    }
+    if env.ledger().timestamp() < pledge.next_payment_at {
+        return Err(Error::PledgeNotDue);
+    }
 
-    details.usage_count -= 1;
-    env.storage().persistent().set(&key, &details);
-    bump_persistent(&env, &key);
+    let accepted = apply_contribution(
+        &env,
+        &pledge.group_id,
+        &pledge.token,
+        pledge.amount,
+        &pledge.pledger,
+        ContributionSource::Allowance,
+    )?;
+
+    pledge.payments_made += 1;
+    let missed = (env.ledger().timestamp() - pledge.next_payment_at) / pledge.interval;
+    pledge.next_payment_at = pledge
+        .next_payment_at
+        .saturating_add((missed + 1).saturating_mul(pledge.interval));
+    if pledge.payments_made >= pledge.max_payments {
+        pledge.is_active = false;
+        remove_group_pledge(&env, &pledge.group_id, pledge_id);
+    }
+    env.storage().persistent().set(&pledge_key, &pledge);
+    bump_persistent(&env, &pledge_key);
 
-    Ok(())
+    Ok(accepted)
 }
 
-fn perform_distribution(
-    env: &Env,
-    id: &BytesN<32>,
-    token: &Address,
-    amount: i128,
-    members: &Vec<GroupMember>,
-) -> Vec<MemberAmount> {
-    let client = token::TokenClient::new(env, token);
-    let mut distributed: i128 = 0;
-    let members_len = members.len() as usize;
-    let mut member_amounts: Vec<MemberAmount> = Vec::new(env);
-    for (idx, member) in members.iter().enumerate() {
-        let share = if idx + 1 < members_len {
-            (amount * (member.percentage as i128)) / 100
-        } else {
-            amount - distributed
-        };
-        if share > 0 {
-            client.transfer(&env.current_contract_address(), &member.address, &share);
-            distributed += share;
-            member_amounts.push_back(MemberAmount {
-                address: member.address.clone(),
-                amount: share,
-            });
+/// Stops a pledge. Only the pledger can cancel.
+pub fn cancel_pledge(env: Env, pledge_id: u32, pledger: Address) -> Result<(), Error> {
+    pledger.require_auth();
+
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let pledge_key = DataKey::Pledge(pledge_id);
+    let mut pledge: Pledge = env
+        .storage()
+        .persistent()
+        .get(&pledge_key)
+        .ok_or(Error::NotFound)?;
+    bump_persistent(&env, &pledge_key);
 
-            // Update running total for member group earnings
-            let earnings_key = DataKey::MemberGroupEarnings(member.address.clone(), id.clone());
-            let current_earnings: i128 = env.storage().persistent().get(&earnings_key).unwrap_or(0);
-            env.storage()
-                .persistent()
-                .set(&earnings_key, &(current_earnings + share));
-            bump_persistent(env, &earnings_key);
-        }
+    if pledge.pledger != pledger {
+        return Err(Error::Unauthorized);
+    }
+    if !pledge.is_active {
+        return Err(Error::PledgeNotActive);
     }
-    member_amounts
-}
 
-pub fn get_member_earnings(env: Env, member: Address, group_id: BytesN<32>) -> i128 {
-    let key = DataKey::MemberGroupEarnings(member, group_id);
-    let earnings: i128 = env.storage().persistent().get(&key).unwrap_or(0);
-    if earnings > 0 {
-        bump_persistent(&env, &key);
+    pledge.is_active = false;
+    env.storage().persistent().set(&pledge_key, &pledge);
+    bump_persistent(&env, &pledge_key);
+    remove_group_pledge(&env, &pledge.group_id, pledge_id);
+
+    PledgeCancelled {
+        group_id: pledge.group_id,
+        pledge_id,
     }
-    earnings
+    .publish(&env);
+    Ok(())
 }
 
-pub fn get_fundraising_status(env: Env, id: BytesN<32>) -> FundraisingConfig {
-    let key = DataKey::GroupFundraising(id);
-    let result: Option<FundraisingConfig> = env.storage().persistent().get(&key);
-    if let Some(config) = result {
-        bump_persistent(&env, &key);
-        config
-    } else {
-        FundraisingConfig {
-            target_amount: 0,
-            total_raised: 0,
-            is_active: false,
-        }
+/// Drops an ended pledge from its group's index, freeing its slot.
+fn remove_group_pledge(env: &Env, id: &BytesN<32>, pledge_id: u32) {
+    let key = DataKey::GroupPledges(id.clone());
+    let mut pledge_ids: Vec<u32> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    if let Some(index) = pledge_ids.first_index_of(pledge_id) {
+        pledge_ids.remove(index);
+        env.storage().persistent().set(&key, &pledge_ids);
+        bump_persistent(env, &key);
     }
 }
 
-pub fn get_group_contributions(env: Env, id: BytesN<32>) -> Vec<FundraisingContribution> {
-    let key = DataKey::GroupContributions(id);
-    let result: Option<Vec<FundraisingContribution>> = env.storage().persistent().get(&key);
+pub fn get_pledge(env: Env, pledge_id: u32) -> Result<Pledge, Error> {
+    let key = DataKey::Pledge(pledge_id);
+    let result: Option<Pledge> = env.storage().persistent().get(&key);
     if result.is_some() {
         bump_persistent(&env, &key);
     }
-    result.unwrap_or(Vec::new(&env))
+    result.ok_or(Error::NotFound)
 }
 
-pub fn get_user_contributions(env: Env, user: Address) -> Vec<FundraisingContribution> {
-    let key = DataKey::UserContributions(user);
-    let result: Option<Vec<FundraisingContribution>> = env.storage().persistent().get(&key);
-    if result.is_some() {
+pub fn get_group_pledges(env: Env, id: BytesN<32>) -> Vec<Pledge> {
+    let key = DataKey::GroupPledges(id);
+    let pledge_ids: Vec<u32> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(&env));
+    if !pledge_ids.is_empty() {
         bump_persistent(&env, &key);
     }
-    result.unwrap_or(Vec::new(&env))
+
+    let mut result: Vec<Pledge> = Vec::new(&env);
+    for pledge_id in pledge_ids.iter() {
+        if let Ok(pledge) = get_pledge(env.clone(), pledge_id) {
+            result.push_back(pledge);
+        }
+    }
+    result
 }
 
-fn validate_members(members: &Vec<GroupMember>) -> Result<(), Error> {
-    if members.is_empty() {
-        return Err(Error::EmptyMembers);
+// ============================================================================
+// Quadratic Funding Rounds
+// ============================================================================
+
+/// Opens a quadratic funding round. The sponsor's `matching_pool` is escrowed and, once the
+/// round ends, shared between participating groups by the quadratic funding formula.
+pub fn create_funding_round(
+    env: Env,
+    sponsor: Address,
+    token: Address,
+    matching_pool: i128,
+    start_time: u64,
+    end_time: u64,
+) -> Result<u32, Error> {
+    sponsor.require_auth();
+
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
     }
-    let env = members.env();
-    let mut total_percentage: u32 = 0;
-    let mut seen_addresses = Vec::new(env);
 
-    for member in members.iter() {
-        if member.percentage == 0 {
-            return Err(Error::InvalidInput);
-        }
-        total_percentage += member.percentage;
-        for seen in seen_addresses.iter() {
-            if seen == member.address {
-                return Err(Error::DuplicateMember);
-            }
-        }
-        seen_addresses.push_back(member.address.clone());
+    if matching_pool <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    if end_time <= start_time || end_time <= env.ledger().timestamp() {
+        return Err(Error::InvalidDeadline);
     }
 
-    if total_percentage != 100 {
-        return Err(Error::InvalidTotalPercentage);
+    if !is_token_supported(env.clone(), token.clone()) {
+        return Err(Error::UnsupportedToken);
     }
-    Ok(())
+
+    let token_client = token::Client::new(&env, &token);
+    token_client.transfer(&sponsor, env.current_contract_address(), &matching_pool);
+
+    let count_key = DataKey::RoundCount;
+    let round_id: u32 = env.storage().persistent().get(&count_key).unwrap_or(0) + 1;
+    env.storage().persistent().set(&count_key, &round_id);
+    bump_persistent(&env, &count_key);
+
+    let round = FundingRound {
+        round_id,
+        sponsor: sponsor.clone(),
+        token,
+        matching_pool,
+        start_time,
+        end_time,
+        groups: Vec::new(&env),
+        is_finalized: false,
+    };
+    let round_key = DataKey::FundingRound(round_id);
+    env.storage().persistent().set(&round_key, &round);
+    bump_persistent(&env, &round_key);
+
+    FundingRoundCreated {
+        sponsor,
+        round_id,
+        matching_pool,
+    }
+    .publish(&env);
+    Ok(round_id)
 }
 
-pub fn start_fundraising(
+/// Opts a group into a funding round. Only the group creator can join, before the round ends,
+/// and a group can only take part in one unfinished round at a time.
+pub fn join_funding_round(
     env: Env,
+    round_id: u32,
     id: BytesN<32>,
     caller: Address,
-    target_amount: i128,
 ) -> Result<(), Error> {
     caller.require_auth();
+    load_group_with_permission(&env, &id, &caller, Permission::Fundraising)?;
 
-    // Check if contract is paused
-    if get_paused_status(&env) {
-        return Err(Error::ContractPaused);
-    }
-
-    // Verify group exists
-    let key = DataKey::AutoShare(id.clone());
-    let details: AutoShareDetails = env
+    let round_key = DataKey::FundingRound(round_id);
+    let mut round: FundingRound = env
         .storage()
         .persistent()
-        .get(&key)
+        .get(&round_key)
         .ok_or(Error::NotFound)?;
-    bump_persistent(&env, &key);
-
-    // Verify caller is the group creator
-    if details.creator != caller {
-        return Err(Error::Unauthorized);
-    }
+    bump_persistent(&env, &round_key);
 
-    // Verify group is active
-    if !details.is_active {
-        return Err(Error::GroupInactive);
+    if round.is_finalized || env.ledger().timestamp() > round.end_time {
+        return Err(Error::RoundClosed);
     }
 
-    // Check no active fundraiser already exists for this group
-    let fundraising_key = DataKey::GroupFundraising(id.clone());
-    let existing_fundraising: Option<FundraisingConfig> =
-        env.storage().persistent().get(&fundraising_key);
-
-    if let Some(config) = existing_fundraising {
-        if config.is_active {
-            return Err(Error::FundraisingAlreadyActive);
+    let group_round_key = DataKey::GroupRound(id.clone());
+    let current_round: Option<u32> = env.storage().persistent().get(&group_round_key);
+    if let Some(current_round_id) = current_round {
+        if let Ok(current) = get_funding_round(env.clone(), current_round_id) {
+            if !current.is_finalized {
+                return Err(Error::AlreadyExists);
+            }
         }
-        bump_persistent(&env, &fundraising_key);
     }
 
-    // Validate target_amount > 0
-    if target_amount <= 0 {
-        return Err(Error::InvalidAmount);
+    if round.groups.len() >= MAX_ROUND_GROUPS {
+        return Err(Error::MaxRoundGroupsExceeded);
     }
 
-    // Store a new FundraisingConfig
-    let fundraising_config = FundraisingConfig {
-        target_amount,
-        total_raised: 0,
-        is_active: true,
-    };
+    round.groups.push_back(id.clone());
+    env.storage().persistent().set(&round_key, &round);
+    bump_persistent(&env, &round_key);
 
-    env.storage()
-        .persistent()
-        .set(&fundraising_key, &fundraising_config);
-    bump_persistent(&env, &fundraising_key);
+    env.storage().persistent().set(&group_round_key, &round_id);
+    bump_persistent(&env, &group_round_key);
 
-    // Emit a FundraisingStarted event
-    FundraisingStarted {
+    FundingRoundJoined {
         group_id: id,
-        target_amount,
+        round_id,
     }
     .publish(&env);
-
     Ok(())
 }
 
-pub fn contribute(
-    env: Env,
-    id: BytesN<32>,
-    token: Address,
+/// Adds a contribution to the group's tally in its funding round when it falls inside the
+/// round window and is made in the round token.
+fn tally_round_contribution(
+    env: &Env,
+    id: &BytesN<32>,
+    token: &Address,
     amount: i128,
-    contributor: Address,
-) -> Result<(), Error> {
-    contributor.require_auth();
+    contributor: &Address,
+) {
+    let group_round_key = DataKey::GroupRound(id.clone());
+    let round_id: u32 = match env.storage().persistent().get(&group_round_key) {
+        Some(round_id) => round_id,
+        None => return,
+    };
+    let round: FundingRound = match env
+        .storage()
+        .persistent()
+        .get(&DataKey::FundingRound(round_id))
+    {
+        Some(round) => round,
+        None => return,
+    };
+    let now = env.ledger().timestamp();
+    if round.is_finalized || round.token != *token || now < round.start_time || now > round.end_time
+    {
+        return;
+    }
 
-    if get_paused_status(&env) {
-        return Err(Error::ContractPaused);
+    let contribution_key = DataKey::RoundContribution(round_id, id.clone(), contributor.clone());
+    let previous: i128 = env
+        .storage()
+        .persistent()
+        .get(&contribution_key)
+        .unwrap_or(0);
+    let updated = previous + amount;
+    env.storage().persistent().set(&contribution_key, &updated);
+    bump_persistent(env, &contribution_key);
+
+    let mut tally = get_round_group_tally(env.clone(), round_id, id.clone());
+    tally.sum_sqrt += isqrt(updated) - isqrt(previous);
+    tally.total_contributed += amount;
+    if previous == 0 {
+        tally.contributor_count += 1;
     }
+    let tally_key = DataKey::RoundGroupTally(round_id, id.clone());
+    env.storage().persistent().set(&tally_key, &tally);
+    bump_persistent(env, &tally_key);
+}
 
-    if amount <= 0 {
-        return Err(Error::InvalidAmount);
+/// Integer square root, rounded down.
+fn isqrt(value: i128) -> i128 {
+    if value < 2 {
+        return value.max(0);
+    }
+    let mut x = value;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
     }
+    x
+}
 
-    if !is_token_supported(env.clone(), token.clone()) {
-        return Err(Error::UnsupportedToken);
+/// Allocates the matching pool across the round's groups. A group's weight is
+/// `(sum of sqrt(contribution))^2 - sum of contributions`. Groups that are inactive, deleted or
+/// have an invalid split get no weight, so their share stays in the pool for the others.
+/// Rounding dust goes back to the sponsor. Shares are paid by `claim_round_match`, one group
+/// per call. Anyone can call once the round has ended.
+pub fn finalize_funding_round(env: Env, round_id: u32) -> Result<i128, Error> {
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
     }
 
-    // Verify group exists and is active
-    let group_key = DataKey::AutoShare(id.clone());
-    let group_details: AutoShareDetails = env
+    let round_key = DataKey::FundingRound(round_id);
+    let mut round: FundingRound = env
         .storage()
         .persistent()
-        .get(&group_key)
+        .get(&round_key)
         .ok_or(Error::NotFound)?;
-    bump_persistent(&env, &group_key);
+    bump_persistent(&env, &round_key);
 
-    if !group_details.is_active {
-        return Err(Error::GroupInactive);
+    if round.is_finalized {
+        return Err(Error::RoundClosed);
+    }
+    if env.ledger().timestamp() <= round.end_time {
+        return Err(Error::RoundNotEnded);
     }
 
-    // Verify fundraising is active
-    let fundraising_key = DataKey::GroupFundraising(id.clone());
-    let mut fundraising_config: FundraisingConfig = env
-        .storage()
-        .persistent()
-        .get(&fundraising_key)
-        .ok_or(Error::FundraisingNotActive)?;
+    let mut weights: Vec<i128> = Vec::new(&env);
+    let mut total_weight: i128 = 0;
+    for id in round.groups.iter() {
+        let eligible = get_autoshare(env.clone(), id.clone())
+            .is_ok_and(|details| details.is_active && validate_members(&details.members).is_ok());
+        let weight = if eligible {
+            let tally = get_round_group_tally(env.clone(), round_id, id);
+            (tally.sum_sqrt * tally.sum_sqrt - tally.total_contributed).max(0)
+        } else {
+            0
+        };
+        weights.push_back(weight);
+        total_weight += weight;
+    }
 
-    if !fundraising_config.is_active {
-        return Err(Error::FundraisingNotActive);
+    let mut total_matched: i128 = 0;
+    if total_weight > 0 {
+        for (idx, id) in round.groups.iter().enumerate() {
+            let weight = weights.get(idx as u32).unwrap_or(0);
+            let share = round.matching_pool * weight / total_weight;
+            if share <= 0 {
+                continue;
+            }
+
+            let mut tally = get_round_group_tally(env.clone(), round_id, id.clone());
+            tally.matched_amount = share;
+            let tally_key = DataKey::RoundGroupTally(round_id, id);
+            env.storage().persistent().set(&tally_key, &tally);
+            bump_persistent(&env, &tally_key);
+
+            total_matched += share;
+        }
     }
-    bump_persistent(&env, &fundraising_key);
 
-    // Transfer amount from contributor to the contract
-    let token_client = token::Client::new(&env, &token);
-    token_client.transfer(&contributor, env.current_contract_address(), &amount);
+    let unallocated = round.matching_pool - total_matched;
+    if unallocated > 0 {
+        let token_client = token::Client::new(&env, &round.token);
+        token_client.transfer(
+            &env.current_contract_address(),
+            &round.sponsor,
+            &unallocated,
+        );
+    }
 
-    // Distribute funds to group members
-    perform_distribution(&env, &id, &token, amount, &group_details.members);
+    round.is_finalized = true;
+    env.storage().persistent().set(&round_key, &round);
+    bump_persistent(&env, &round_key);
 
-    // Update fundraising total
-    fundraising_config.total_raised += amount;
-    if fundraising_config.total_raised >= fundraising_config.target_amount {
-        fundraising_config.is_active = false;
+    FundingRoundFinalized {
+        round_id,
+        total_matched,
     }
-    env.storage()
-        .persistent()
-        .set(&fundraising_key, &fundraising_config);
-    bump_persistent(&env, &fundraising_key);
+    .publish(&env);
+    Ok(total_matched)
+}
 
-    // Record contribution
-    let contribution = FundraisingContribution {
-        group_id: id.clone(),
-        contributor: contributor.clone(),
-        token: token.clone(),
-        amount,
-        timestamp: env.ledger().timestamp(),
-    };
+/// Pays a group's matched share of a finalized round through its split. If the group has been
+/// deactivated, deleted or left with an invalid split since finalization, the share goes back to
+/// the sponsor instead. Returns the amount paid to the group. Anyone can call.
+pub fn claim_round_match(env: Env, round_id: u32, id: BytesN<32>) -> Result<i128, Error> {
+    if get_paused_status(&env) {
+        return Err(Error::ContractPaused);
+    }
 
-    let group_contributions_key = DataKey::GroupContributions(id.clone());
-    let mut group_contributions: Vec<FundraisingContribution> = env
-        .storage()
-        .persistent()
-        .get(&group_contributions_key)
-        .unwrap_or(Vec::new(&env));
-    group_contributions.push_back(contribution.clone());
-    env.storage()
-        .persistent()
-        .set(&group_contributions_key, &group_contributions);
-    bump_persistent(&env, &group_contributions_key);
+    let round = get_funding_round(env.clone(), round_id)?;
+    if !round.is_finalized {
+        return Err(Error::RoundNotEnded);
+    }
 
-    let user_contributions_key = DataKey::UserContributions(contributor.clone());
-    let mut user_contributions: Vec<FundraisingContribution> = env
-        .storage()
-        .persistent()
-        .get(&user_contributions_key)
-        .unwrap_or(Vec::new(&env));
-    user_contributions.push_back(contribution);
-    env.storage()
-        .persistent()
-        .set(&user_contributions_key, &user_contributions);
-    bump_persistent(&env, &user_contributions_key);
+    let mut tally = get_round_group_tally(env.clone(), round_id, id.clone());
+    if tally.matched_amount <= 0 {
+        return Err(Error::NotFound);
+    }
+    if tally.claimed {
+        return Err(Error::AlreadyClaimed);
+    }
 
-    // Update group stats
-    let stats_key = DataKey::GroupStats(id.clone());
-    let mut stats: GroupStats = env
-        .storage()
-        .persistent()
-        .get(&stats_key)
-        .unwrap_or(GroupStats {
-            total_raised: 0,
-            contribution_count: 0,
-        });
-    stats.total_raised += amount;
-    stats.contribution_count += 1;
-    env.storage().persistent().set(&stats_key, &stats);
-    bump_persistent(&env, &stats_key);
-    // Emit new contribution event for fundraising tracking
-    emit_contribution(&env, &id, &contributor, &token, amount);
+    tally.claimed = true;
+    let tally_key = DataKey::RoundGroupTally(round_id, id.clone());
+    env.storage().persistent().set(&tally_key, &tally);
+    bump_persistent(&env, &tally_key);
+
+    let share = tally.matched_amount;
+    let details = get_autoshare(env.clone(), id.clone())
+        .ok()
+        .filter(|details| details.is_active && validate_members(&details.members).is_ok());
+    match details {
+        Some(details) => {
+            perform_distribution(&env, &id, &round.token, share, &details.members);
+            emit_distribution(
+                &env,
+                &id,
+                &round.sponsor,
+                &round.token,
+                share,
+                details.members.len(),
+            );
+            Ok(share)
+        }
+        None => {
+            let token_client = token::Client::new(&env, &round.token);
+            token_client.transfer(&env.current_contract_address(), &round.sponsor, &share);
+            Ok(0)
+        }
+    }
+}
 
-    Ok(())
+pub fn get_funding_round(env: Env, round_id: u32) -> Result<FundingRound, Error> {
+    let key = DataKey::FundingRound(round_id);
+    let result: Option<FundingRound> = env.storage().persistent().get(&key);
+    if result.is_some() {
+        bump_persistent(&env, &key);
+    }
+    result.ok_or(Error::NotFound)
+}
+
+pub fn get_round_group_tally(env: Env, round_id: u32, id: BytesN<32>) -> RoundGroupTally {
+    let key = DataKey::RoundGroupTally(round_id, id);
+    let result: Option<RoundGroupTally> = env.storage().persistent().get(&key);
+    if result.is_some() {
+        bump_persistent(&env, &key);
+    }
+    result.unwrap_or(RoundGroupTally {
+        sum_sqrt: 0,
+        total_contributed: 0,
+        contributor_count: 0,
+        matched_amount: 0,
+        claimed: false,
+    })
 }
 
 /// Returns the fundraising progress as a percentage (0-100).
 /// Returns 0 if no fundraising campaign exists.
 pub fn get_fundraising_progress(env: Env, id: BytesN<32>) -> u32 {
-    let key = DataKey::GroupFundraising(id);
-    let config: Option<FundraisingConfig> = env.storage().persistent().get(&key);
-
-    if let Some(fundraising) = config {
+    if let Some(fundraising) = read_fundraising(&env, &id) {
         if fundraising.target_amount > 0 {
-            bump_persistent(&env, &key);
             let progress = (fundraising.total_raised * 100) / fundraising.target_amount;
             // Cap at 100%
             if progress > 100 {
@@ -1768,11 +6282,7 @@ pub fn get_fundraising_progress(env: Env, id: BytesN<32>) -> u32 {
 
 /// Checks if a fundraising campaign has reached its target.
 pub fn is_fundraising_target_reached(env: Env, id: BytesN<32>) -> bool {
-    let key = DataKey::GroupFundraising(id);
-    let config: Option<FundraisingConfig> = env.storage().persistent().get(&key);
-
-    if let Some(fundraising) = config {
-        bump_persistent(&env, &key);
+    if let Some(fundraising) = read_fundraising(&env, &id) {
         fundraising.total_raised >= fundraising.target_amount
     } else {
         false
@@ -1803,18 +6313,8 @@ pub fn get_user_total_contributions(env: Env, user: Address) -> i128 {
 
 /// Returns the number of unique contributors to a group's fundraising campaign.
 pub fn get_contributor_count(env: Env, id: BytesN<32>) -> u32 {
-    let key = DataKey::GroupContributions(id);
-    let contributions: Vec<FundraisingContribution> = env
-        .storage()
-        .persistent()
-        .get(&key)
-        .unwrap_or(Vec::new(&env));
-
-    if contributions.is_empty() {
-        return 0;
-    }
-
-    bump_persistent(&env, &key);
+    let contributions: Vec<FundraisingContribution> =
+        read_full_history(&env, &HistoryLog::GroupContributions(id));
 
     // Count unique contributors
     let mut unique_contributors: Vec<Address> = Vec::new(&env);
@@ -1836,11 +6336,7 @@ pub fn get_contributor_count(env: Env, id: BytesN<32>) -> u32 {
 /// Returns the remaining amount needed to reach the fundraising target.
 /// Returns 0 if target is already reached or no fundraising exists.
 pub fn get_fundraising_remaining(env: Env, id: BytesN<32>) -> i128 {
-    let key = DataKey::GroupFundraising(id);
-    let config: Option<FundraisingConfig> = env.storage().persistent().get(&key);
-
-    if let Some(fundraising) = config {
-        bump_persistent(&env, &key);
+    if let Some(fundraising) = read_fundraising(&env, &id) {
         let remaining = fundraising.target_amount - fundraising.total_raised;
         if remaining > 0 {
             remaining
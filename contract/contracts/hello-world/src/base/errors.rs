@@ -31,4 +31,26 @@ pub enum Error {
     MaxMembersExceeded = 25,
     FundraisingAlreadyActive = 32,
     FundraisingNotActive = 33,
+    FundraisingExpired = 34,
+    InvalidDeadline = 35,
+    ContributionExceedsTarget = 36,
+    ContributionBelowMinimum = 37,
+    ContributionLimitExceeded = 38,
+    ContributorNotAllowed = 39,
+    PledgeNotActive = 40,
+    PledgeNotDue = 41,
+    RoundClosed = 42,
+    RoundNotEnded = 43,
+    InviteExpired = 44,
+    GovernanceRequired = 45,
+    ProposalNotActive = 46,
+    VotingNotEnded = 47,
+    NoticePeriodActive = 48,
+    AllowlistFull = 49,
+    MaxRoundGroupsExceeded = 50,
+    MaxManagersExceeded = 51,
+    MultipleTokens = 52,
+    InsufficientAllowance = 53,
+    MaxPledgesExceeded = 54,
+    AlreadyClaimed = 55,
 }
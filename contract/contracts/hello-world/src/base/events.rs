@@ -33,6 +33,7 @@ pub fn emit_contribution(
     }
     .publish(env);
 }
+use crate::base::types::{ManagerScope, VestingSchedule};
 use soroban_sdk::{contractevent, Address, BytesN};
 
 #[contractevent(data_format = "single-value")]
@@ -129,6 +130,39 @@ pub struct FundraisingStarted {
     pub target_amount: i128,
 }
 
+#[contractevent(data_format = "single-value")]
+#[derive(Clone)]
+pub struct FundraisingClosed {
+    #[topic]
+    pub group_id: BytesN<32>,
+    pub total_raised: i128,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone)]
+pub struct FundraisingCancelled {
+    #[topic]
+    pub group_id: BytesN<32>,
+    pub total_raised: i128,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone)]
+pub struct FundraisingDeadlineExtended {
+    #[topic]
+    pub group_id: BytesN<32>,
+    pub deadline: u64,
+}
+
+#[contractevent]
+#[derive(Clone)]
+pub struct FundraisingTargetUpdated {
+    #[topic]
+    pub group_id: BytesN<32>,
+    pub old_target: i128,
+    pub new_target: i128,
+}
+
 #[contractevent]
 #[derive(Clone)]
 pub struct Contribution {
@@ -140,3 +174,225 @@ pub struct Contribution {
     pub token: Address,
     pub amount: i128,
 }
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone)]
+pub struct PledgeCreated {
+    #[topic]
+    pub group_id: BytesN<32>,
+    #[topic]
+    pub pledger: Address,
+    pub pledge_id: u32,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone)]
+pub struct PledgeCancelled {
+    #[topic]
+    pub group_id: BytesN<32>,
+    pub pledge_id: u32,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone)]
+pub struct MatchingPoolFunded {
+    #[topic]
+    pub group_id: BytesN<32>,
+    #[topic]
+    pub sponsor: Address,
+    pub amount: i128,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone)]
+pub struct MatchingPoolReleased {
+    #[topic]
+    pub group_id: BytesN<32>,
+    #[topic]
+    pub sponsor: Address,
+    pub amount: i128,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone)]
+pub struct FundingRoundCreated {
+    #[topic]
+    pub sponsor: Address,
+    #[topic]
+    pub round_id: u32,
+    pub matching_pool: i128,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone)]
+pub struct FundingRoundJoined {
+    #[topic]
+    pub group_id: BytesN<32>,
+    pub round_id: u32,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone)]
+pub struct FundingRoundFinalized {
+    #[topic]
+    pub round_id: u32,
+    pub total_matched: i128,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone)]
+pub struct MemberLeft {
+    #[topic]
+    pub member: Address,
+    pub id: BytesN<32>,
+}
+
+#[contractevent]
+#[derive(Clone)]
+pub struct PayoutAddressUpdated {
+    #[topic]
+    pub id: BytesN<32>,
+    #[topic]
+    pub old_address: Address,
+    pub new_address: Address,
+}
+
+#[contractevent]
+#[derive(Clone)]
+pub struct MemberInvited {
+    #[topic]
+    pub id: BytesN<32>,
+    #[topic]
+    pub invitee: Address,
+    pub percentage: u32,
+    pub expires_at: u64,
+}
+
+#[contractevent]
+#[derive(Clone)]
+pub struct InviteAccepted {
+    #[topic]
+    pub id: BytesN<32>,
+    #[topic]
+    pub member: Address,
+    pub percentage: u32,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone)]
+pub struct InviteCancelled {
+    #[topic]
+    pub id: BytesN<32>,
+    pub invitee: Address,
+}
+
+#[contractevent]
+#[derive(Clone)]
+pub struct ManagerUpdated {
+    #[topic]
+    pub id: BytesN<32>,
+    #[topic]
+    pub manager: Address,
+    pub scope: ManagerScope,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone)]
+pub struct ManagerRemoved {
+    #[topic]
+    pub id: BytesN<32>,
+    pub manager: Address,
+}
+
+#[contractevent]
+#[derive(Clone)]
+pub struct OwnershipTransferProposed {
+    #[topic]
+    pub id: BytesN<32>,
+    #[topic]
+    pub creator: Address,
+    pub new_creator: Address,
+}
+
+#[contractevent]
+#[derive(Clone)]
+pub struct OwnershipTransferred {
+    #[topic]
+    pub id: BytesN<32>,
+    #[topic]
+    pub old_creator: Address,
+    pub new_creator: Address,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone)]
+pub struct ProposalCreated {
+    #[topic]
+    pub id: BytesN<32>,
+    pub proposal_id: u32,
+}
+
+#[contractevent]
+#[derive(Clone)]
+pub struct VoteCast {
+    #[topic]
+    pub proposal_id: u32,
+    #[topic]
+    pub voter: Address,
+    pub approve: bool,
+    pub weight: u32,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone)]
+pub struct ProposalResolved {
+    #[topic]
+    pub proposal_id: u32,
+    pub executed: bool,
+}
+
+#[contractevent]
+#[derive(Clone)]
+pub struct SplitChangeQueued {
+    #[topic]
+    pub id: BytesN<32>,
+    #[topic]
+    pub updater: Address,
+    pub effective_at: u64,
+}
+
+#[contractevent(data_format = "single-value")]
+#[derive(Clone)]
+pub struct SplitChangeCancelled {
+    #[topic]
+    pub id: BytesN<32>,
+    pub caller: Address,
+}
+
+/// A queued split change that no longer fit the group when it fell due and was discarded.
+#[contractevent(data_format = "single-value")]
+#[derive(Clone)]
+pub struct SplitChangeDropped {
+    #[topic]
+    pub id: BytesN<32>,
+    pub updater: Address,
+}
+
+#[contractevent]
+#[derive(Clone)]
+pub struct VestingScheduleUpdated {
+    #[topic]
+    pub id: BytesN<32>,
+    #[topic]
+    pub member: Address,
+    pub schedule: Option<VestingSchedule>,
+}
+
+#[contractevent]
+#[derive(Clone)]
+pub struct GroupTtlExtended {
+    #[topic]
+    pub id: BytesN<32>,
+    pub live_until_ledger: u32,
+    pub entries: u32,
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, String, Val, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -19,6 +19,74 @@ pub struct GroupMember {
     pub percentage: u32,
 }
 
+/// Group actions the creator has delegated to a manager.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ManagerScope {
+    pub manage_members: bool,
+    pub change_splits: bool,
+    pub rename: bool,
+    pub fundraising: bool,
+    pub activation: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupManager {
+    pub address: Address,
+    pub scope: ManagerScope,
+}
+
+/// How other members' shares are adjusted when a member is added or removed.
+/// `Exact` keeps them unchanged, `ProRata` scales them proportionally, and `Member` moves the
+/// difference onto a single named member.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RebalanceStrategy {
+    Exact,
+    ProRata,
+    Member(Address),
+}
+
+/// Where the unvested part of a member's share goes while their schedule runs.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UnvestedRedirect {
+    Creator,
+    VestedMembers,
+}
+
+/// Vesting of a member's share: nothing is paid before `cliff`, the share then counts as vested
+/// linearly from `start` and fully from `end`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    pub start: u64,
+    pub cliff: u64,
+    pub end: u64,
+    pub redirect: UnvestedRedirect,
+}
+
+/// A snapshot of a group's members, recorded every time the member set changes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SplitVersion {
+    pub version: u32,
+    pub members: Vec<GroupMember>,
+    pub created_at: u64,
+    pub author: Address,
+}
+
+/// A pending offer to join a group. The invitee only becomes a member once they accept.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberInvite {
+    pub invitee: Address,
+    pub percentage: u32,
+    pub invited_by: Address,
+    pub expires_at: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PaymentHistory {
@@ -46,6 +114,9 @@ pub struct DistributionHistory {
     pub member_amounts: Vec<MemberAmount>,
     pub timestamp: u64,
     pub distribution_number: u32,
+    /// Split version the payout followed; 0 for a group's original members and for
+    /// distributions recorded before split versions existed.
+    pub split_version: u32,
 }
 
 #[contracttype]
@@ -66,12 +137,195 @@ pub struct GroupPage {
     pub limit: u32,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenAmount {
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// A member's position in one group, as part of `MemberOverview`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberGroupOverview {
+    pub group_id: BytesN<32>,
+    pub name: String,
+    pub percentage: u32,
+    pub is_active: bool,
+    pub earnings: Vec<TokenAmount>,
+    /// Earnings recorded before per-token tracking, whose token is unknown until an admin runs
+    /// `migrate_member_earnings`.
+    pub unattributed_earnings: i128,
+}
+
+/// Everything a member dashboard needs, read from a single ledger.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberOverview {
+    pub groups: Vec<MemberGroupOverview>,
+    /// The member's latest payouts, oldest first.
+    pub recent_distributions: Vec<DistributionRecord>,
+}
+
+/// Aggregates for one group, returned by `get_group_stats`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupStatsView {
+    pub distribution_count: u32,
+    pub distributed: Vec<TokenAmount>,
+    /// Number of distinct addresses that have called `distribute` for the group.
+    pub unique_senders: u32,
+    pub contribution_count: u32,
+    pub raised: Vec<TokenAmount>,
+    /// Timestamp of the latest distribution or contribution, 0 if there was none.
+    pub last_activity: u64,
+    pub usages_consumed: u32,
+}
+
+/// Deployment-wide totals. Counters other than `total_groups` start counting from the upgrade
+/// that introduced them.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolStats {
+    pub total_groups: u32,
+    pub active_groups: u32,
+    /// Sum of member counts over all groups; an address in two groups counts twice.
+    pub total_members: u32,
+    pub total_distributions: u32,
+    /// Everything paid out to members, by distributions and campaign contributions alike.
+    pub distributed_volume: Vec<TokenAmount>,
+    pub fees_collected: Vec<TokenAmount>,
+    pub total_contributions: u32,
+    pub amount_raised: Vec<TokenAmount>,
+}
+
+/// Position in the walk over a group's storage entries. Pass it back unchanged.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupTtlCursor {
+    pub section: u32,
+    pub item: u32,
+    pub offset: u32,
+}
+
+/// An `extend_group_ttl` walk that is still in progress.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct GroupTtlProgress {
+    pub cursor: GroupTtlCursor,
+    /// Live-until ledger of the entries extended by the walk's first call.
+    pub live_until_ledger: u32,
+}
+
+/// One page of the storage entries kept for a group, and how far they were last deliberately
+/// extended. Contracts cannot read TTLs, so exact live-until ledgers must be looked up off-chain
+/// (for example with RPC `getLedgerEntries`) using the listed keys.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct GroupTtlReport {
+    /// Ledger the last completed `extend_group_ttl` walk extended entries to, 0 if there was none.
+    /// Not a live-until ledger: entries created after that walk only carry the TTL they were
+    /// written with.
+    pub extended_until: u32,
+    pub current_ledger: u32,
+    /// Persistent storage keys of entries belonging to the group. A key may appear on more
+    /// than one page.
+    pub keys: Vec<Val>,
+    pub next_cursor: GroupTtlCursor,
+    /// Whether this page ends the walk.
+    pub complete: bool,
+}
+
+/// Granularity of on-chain report buckets. Daily buckets are numbered by days since the Unix
+/// epoch, monthly buckets as `YYYYMM` in UTC.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReportInterval {
+    Daily,
+    Monthly,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReportBucket {
+    pub period: u32,
+    pub amount: i128,
+}
+
+/// Conditions for `query_groups`. Unset fields match every group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupFilter {
+    pub is_active: Option<bool>,
+    pub creator: Option<Address>,
+    pub member: Option<Address>,
+    /// Only groups with a fundraising campaign open for contributions.
+    pub fundraising_active: bool,
+    /// Only groups with fewer remaining usages than this.
+    pub usages_below: Option<u32>,
+    /// Only groups that have paid out in this token. Payouts made before the token index existed
+    /// count once `backfill_group_tokens` has processed the group's history.
+    pub token: Option<Address>,
+}
+
+/// Continuation point for `query_groups`. Pass it back unchanged.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupCursor {
+    /// Number of list positions not yet examined.
+    pub remaining: u32,
+    /// Last group examined, used to notice when a deletion shifted the list.
+    pub last_id: Option<BytesN<32>>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupQueryPage {
+    pub groups: Vec<AutoShareDetails>,
+    /// Cursor to pass to the next query. Its `remaining` is 0 once every group has been examined.
+    pub next_cursor: GroupCursor,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FundraisingConfig {
     pub target_amount: i128,
     pub total_raised: i128,
     pub is_active: bool,
+    /// Ledger timestamp after which contributions are rejected. 0 means no deadline.
+    pub deadline: u64,
+    pub is_cancelled: bool,
+    pub overfunding_policy: OverfundingPolicy,
+    /// Upper bound on `total_raised` under `OverfundingPolicy::AllowUpToCap`; 0 otherwise.
+    pub hard_cap: i128,
+    /// Sequence number of the campaign, incremented by every `start_fundraising`.
+    pub campaign_id: u32,
+}
+
+/// Optional limits checked on every contribution. A zero amount disables that limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributionRules {
+    pub min_contribution: i128,
+    pub max_contribution: i128,
+    /// Maximum a single contributor may give over the lifetime of one campaign.
+    pub contributor_cap: i128,
+    /// When set, only addresses on the group's allowlist may contribute.
+    pub allowlist_enabled: bool,
+}
+
+/// How a campaign treats contributions that would take it past its target.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OverfundingPolicy {
+    /// Accept the whole contribution and close the campaign once the target is reached.
+    CloseAtTarget,
+    /// Keep accepting past the target until `hard_cap`; anything above the cap is refunded.
+    AllowUpToCap,
+    /// Accept only what is still needed to reach the target and refund the rest.
+    RefundExcess,
+    /// Reject any contribution that would exceed the target.
+    Reject,
 }
 
 #[contracttype]
@@ -90,3 +344,129 @@ pub struct GroupStats {
     pub total_raised: i128,
     pub contribution_count: u32,
 }
+
+/// A recurring contribution to a group's campaigns, collected from a token allowance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pledge {
+    pub pledge_id: u32,
+    pub group_id: BytesN<32>,
+    pub pledger: Address,
+    pub token: Address,
+    pub amount: i128,
+    /// Seconds between two payments.
+    pub interval: u64,
+    pub max_payments: u32,
+    pub payments_made: u32,
+    /// Ledger timestamp from which the next payment can be collected.
+    pub next_payment_at: u64,
+    pub is_active: bool,
+}
+
+/// A sponsor's pool that matches contributions to one campaign of a group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchingPool {
+    pub sponsor: Address,
+    pub token: Address,
+    /// Matched amount as a percentage of each contribution (100 = 1:1).
+    pub match_ratio: u32,
+    /// Maximum matched for a single contribution. 0 means no cap.
+    pub match_cap: i128,
+    pub deposited: i128,
+    pub remaining: i128,
+    /// Campaign the pool was deposited into.
+    pub campaign_id: u32,
+}
+
+/// A quadratic funding round whose matching pool is shared between participating groups.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundingRound {
+    pub round_id: u32,
+    pub sponsor: Address,
+    pub token: Address,
+    pub matching_pool: i128,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub groups: Vec<BytesN<32>>,
+    pub is_finalized: bool,
+}
+
+/// Running totals of a group's contributions within a funding round.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundGroupTally {
+    /// Sum over unique contributors of the square root of their total contribution.
+    pub sum_sqrt: i128,
+    pub total_contributed: i128,
+    pub contributor_count: u32,
+    /// Share of the matching pool allocated to the group when the round was finalized.
+    pub matched_amount: i128,
+    /// Whether the allocated share has been paid out or returned to the sponsor.
+    pub claimed: bool,
+}
+
+/// Voting rules for a group in governance mode. `quorum` is the share of the group's total
+/// percentage that must vote, `threshold` the share of cast votes that must approve.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceConfig {
+    pub enabled: bool,
+    pub quorum: u32,
+    pub threshold: u32,
+    pub voting_period: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProposalAction {
+    UpdateMembers(Vec<GroupMember>),
+    AddMember(Address, u32, RebalanceStrategy),
+    RemoveMember(Address, RebalanceStrategy),
+    SetGovernance(GovernanceConfig),
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProposalStatus {
+    Open,
+    Executed,
+    Rejected,
+    /// Approved, but the change could no longer be applied to the group.
+    Failed,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proposal {
+    pub proposal_id: u32,
+    pub group_id: BytesN<32>,
+    pub proposer: Address,
+    pub action: ProposalAction,
+    pub votes_for: u32,
+    pub votes_against: u32,
+    pub created_at: u64,
+    pub ends_at: u64,
+    pub status: ProposalStatus,
+}
+
+/// Notice period for split reductions. `previous` stays in force until `changed_at + previous`
+/// so that shortening the period does not cut an existing notice short.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NoticePeriod {
+    pub period: u64,
+    pub previous: u64,
+    pub changed_at: u64,
+}
+
+/// A member change waiting out the group's notice period.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingSplitChange {
+    pub action: ProposalAction,
+    pub updater: Address,
+    pub queued_at: u64,
+    pub effective_at: u64,
+}
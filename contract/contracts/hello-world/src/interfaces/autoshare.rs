@@ -1,8 +1,14 @@
 use soroban_sdk::{Address, BytesN, Env, String, Vec};
 
+use crate::autoshare_logic::HistoryLog;
+
 use crate::base::types::{
-    AutoShareDetails, DistributionHistory, DistributionRecord, FundraisingConfig,
-    FundraisingContribution, GroupMember, PaymentHistory,
+    AutoShareDetails, ContributionRules, DistributionHistory, DistributionRecord, FundingRound,
+    FundraisingConfig, FundraisingContribution, GovernanceConfig, GroupCursor, GroupFilter,
+    GroupManager, GroupMember, GroupQueryPage, GroupStatsView, GroupTtlCursor, GroupTtlReport,
+    ManagerScope, MatchingPool, MemberInvite, MemberOverview, OverfundingPolicy, PaymentHistory,
+    PendingSplitChange, Pledge, Proposal, ProposalAction, ProtocolStats, RebalanceStrategy,
+    ReportBucket, ReportInterval, RoundGroupTally, SplitVersion, VestingSchedule,
 };
 
 /// AutoShareTrait defines the interface for the AutoShare contract.
@@ -53,7 +59,9 @@ pub trait AutoShareTrait {
     );
 
     /// Update members of an existing AutoShare plan.
-    /// Only creator can update. Validates percentages.
+    /// Only the creator or a split manager can update. Validates percentages.
+    /// Addresses that are not yet members must authorize joining.
+    /// If a notice period is set, changes that lower any member's share are queued instead.
     fn update_members(env: Env, id: BytesN<32>, caller: Address, new_members: Vec<GroupMember>);
 
     /// Retrieves an existing AutoShare plan.
@@ -65,6 +73,42 @@ pub trait AutoShareTrait {
     /// Retrieves all AutoShare groups created by a specific address.
     fn get_groups_by_creator(env: Env, creator: Address) -> Vec<AutoShareDetails>;
 
+    /// Returns up to `limit` (max 50) groups matching `filter`, newest first, continuing from
+    /// `cursor` (`None` to start). Pass the returned `next_cursor` back to fetch more until it
+    /// has no groups `remaining`. Groups are never skipped when others are deleted mid-walk.
+    fn query_groups(
+        env: Env,
+        filter: GroupFilter,
+        cursor: Option<GroupCursor>,
+        limit: u32,
+    ) -> GroupQueryPage;
+
+    /// Indexes up to `limit` existing groups by creator, starting at registry position
+    /// `start_index`, and returns how many were indexed. Admin only.
+    fn backfill_creator_index(env: Env, admin: Address, start_index: u32, limit: u32) -> u32;
+
+    /// Counts up to `limit` existing groups, starting at registry position `start_index`, in the
+    /// active group and member totals of `get_protocol_stats`. Returns how many were counted.
+    /// Admin only.
+    fn backfill_protocol_stats(env: Env, admin: Address, start_index: u32, limit: u32) -> u32;
+
+    /// Moves up to `limit` pages of a history stored in the legacy single-entry layout into
+    /// paged storage. Returns true once the history is fully migrated. Admin only.
+    fn migrate_history(env: Env, admin: Address, log: HistoryLog, limit: u32) -> bool;
+
+    /// Indexes the payout tokens of up to `limit` entries of a group's distribution history,
+    /// starting at entry `start_index`, and returns how many were examined. Admin only.
+    fn backfill_group_tokens(
+        env: Env,
+        admin: Address,
+        id: BytesN<32>,
+        start_index: u32,
+        limit: u32,
+    ) -> u32;
+
+    /// Returns a member's groups, shares, per-token earnings and latest payouts in one call.
+    fn get_member_overview(env: Env, member: Address) -> MemberOverview;
+
     /// Returns the total number of groups.
     fn get_group_count(env: Env) -> u32;
 
@@ -75,33 +119,157 @@ pub trait AutoShareTrait {
     fn get_group_members(env: Env, id: BytesN<32>) -> Vec<GroupMember>;
 
     /// Adds a member to a group with specified percentage.
-    /// Only the group creator (caller) may add members.
+    /// Only the group creator (caller) or a member manager may add members, and the new
+    /// member must authorize joining.
+    /// `strategy` decides whose shares shrink to make room; `Exact` requires the new total to be 100.
     fn add_group_member(
         env: Env,
         id: BytesN<32>,
         caller: Address,
         address: Address,
         percentage: u32,
+        strategy: RebalanceStrategy,
+    );
+
+    /// Removes a single member from a group. Creator or member manager only; group must be active.
+    /// `strategy` decides who receives the freed share; with `Exact` the remaining percentages
+    /// may not sum to 100 and update_members must be called to set a valid split.
+    /// If a notice period is set, the removal is queued until it elapses.
+    fn remove_group_member(
+        env: Env,
+        id: BytesN<32>,
+        caller: Address,
+        member_address: Address,
+        strategy: RebalanceStrategy,
+    );
+
+    /// Attaches a vesting schedule to a member's share. Creator or split manager, with the
+    /// member's authorization.
+    fn set_vesting_schedule(
+        env: Env,
+        id: BytesN<32>,
+        caller: Address,
+        member: Address,
+        schedule: VestingSchedule,
     );
 
-    /// Removes a single member from a group. Only the creator can call; group must be active.
-    /// After removal, remaining percentages may not sum to 100; call update_members to set a valid split.
-    fn remove_group_member(env: Env, id: BytesN<32>, caller: Address, member_address: Address);
+    /// Removes a member's vesting schedule. Creator or split manager only.
+    fn remove_vesting_schedule(env: Env, id: BytesN<32>, caller: Address, member: Address);
+
+    /// Returns the vesting schedule of a member's share, if any.
+    fn get_vesting_schedule(env: Env, id: BytesN<32>, member: Address) -> Option<VestingSchedule>;
+
+    /// Returns a numbered snapshot of a group's members.
+    fn get_split_version(env: Env, id: BytesN<32>, version: u32) -> SplitVersion;
+
+    /// Returns every recorded split version of a group, oldest first.
+    fn get_split_versions(env: Env, id: BytesN<32>) -> Vec<SplitVersion>;
+
+    /// Removes the caller from a group; their share goes pro-rata to the remaining members.
+    /// Not available in governed groups or to the last member. Cancels a queued split change.
+    fn leave_group(env: Env, id: BytesN<32>, member: Address);
+
+    /// Replaces a member's payout address in a group. Both addresses must authorize.
+    /// Not available in governed groups. A queued split change follows the new address.
+    fn update_payout_address(env: Env, id: BytesN<32>, member: Address, new_address: Address);
+
+    /// Grants or replaces a manager's delegated permissions. Only the creator can call this.
+    fn set_manager(
+        env: Env,
+        id: BytesN<32>,
+        caller: Address,
+        manager: Address,
+        scope: ManagerScope,
+    );
+
+    /// Removes a manager from a group. Only the creator can call this.
+    fn remove_manager(env: Env, id: BytesN<32>, caller: Address, manager: Address);
+
+    /// Returns the managers of a group and their scopes.
+    fn get_managers(env: Env, id: BytesN<32>) -> Vec<GroupManager>;
+
+    /// Enables or configures governance mode. Only the creator can call this, and only while
+    /// governance is off.
+    fn set_governance(env: Env, id: BytesN<32>, caller: Address, config: GovernanceConfig);
+
+    /// Returns the governance settings of a group.
+    fn get_governance_config(env: Env, id: BytesN<32>) -> GovernanceConfig;
+
+    /// Opens a proposal for a member or governance change in a governed group.
+    fn propose_change(env: Env, id: BytesN<32>, proposer: Address, action: ProposalAction) -> u32;
 
-    /// Deactivates a group. Only the creator can deactivate.
+    /// Casts a vote on a proposal, weighted by the voter's percentage.
+    fn vote(env: Env, proposal_id: u32, voter: Address, approve: bool);
+
+    /// Resolves a proposal and applies it if it passed. Returns whether it was applied.
+    fn execute_proposal(env: Env, proposal_id: u32) -> bool;
+
+    /// Returns a proposal by ID.
+    fn get_proposal(env: Env, proposal_id: u32) -> Proposal;
+
+    /// Returns all proposals of a group.
+    fn get_group_proposals(env: Env, id: BytesN<32>) -> Vec<Proposal>;
+
+    /// Sets the notice period for split reductions. Only the creator can call this.
+    fn set_notice_period(env: Env, id: BytesN<32>, caller: Address, period: u64);
+
+    /// Returns the notice period currently in force for a group.
+    fn get_notice_period(env: Env, id: BytesN<32>) -> u64;
+
+    /// Applies a queued split change whose notice period has elapsed.
+    fn apply_split_change(env: Env, id: BytesN<32>);
+
+    /// Cancels a queued split change. Creator or split manager only.
+    fn cancel_split_change(env: Env, id: BytesN<32>, caller: Address);
+
+    /// Returns the split change waiting out the notice period, if any.
+    fn get_pending_split_change(env: Env, id: BytesN<32>) -> Option<PendingSplitChange>;
+
+    /// Proposes a new creator for a group. Only the current creator can call this.
+    fn propose_ownership_transfer(env: Env, id: BytesN<32>, caller: Address, new_creator: Address);
+
+    /// Accepts a pending ownership transfer. Must be called by the proposed creator.
+    fn accept_ownership_transfer(env: Env, id: BytesN<32>, new_creator: Address);
+
+    /// Cancels a pending ownership transfer. Only the current creator can call this.
+    fn cancel_ownership_transfer(env: Env, id: BytesN<32>, caller: Address);
+
+    /// Returns the proposed new creator of a group, if a transfer is pending.
+    fn get_pending_owner(env: Env, id: BytesN<32>) -> Option<Address>;
+
+    /// Invites an address to join a group. They become a member only after accepting.
+    fn invite_member(
+        env: Env,
+        id: BytesN<32>,
+        caller: Address,
+        invitee: Address,
+        percentage: u32,
+        expires_at: u64,
+    );
+
+    /// Accepts a pending invite; existing shares are scaled down to make room.
+    fn accept_invite(env: Env, id: BytesN<32>, invitee: Address);
+
+    /// Cancels a pending invite. Callable by the creator or the invitee.
+    fn cancel_invite(env: Env, id: BytesN<32>, caller: Address, invitee: Address);
+
+    /// Returns the unexpired invites of a group.
+    fn get_pending_invites(env: Env, id: BytesN<32>) -> Vec<MemberInvite>;
+
+    /// Deactivates a group. Creator or activation manager only.
     fn deactivate_group(env: Env, id: BytesN<32>, caller: Address);
 
-    /// Activates a group. Only the creator can activate.
+    /// Activates a group. Creator or activation manager only.
     fn activate_group(env: Env, id: BytesN<32>, caller: Address);
 
-    /// Updates the name of a group. Only the creator can update.
+    /// Updates the name of a group. Creator or rename manager only.
     fn update_group_name(env: Env, id: BytesN<32>, caller: Address, new_name: String);
 
     /// Returns whether a group is active.
     fn is_group_active(env: Env, id: BytesN<32>) -> bool;
 
     /// Permanently deletes a group. Only creator or admin can delete.
-    /// Group must be deactivated first and have 0 remaining usages.
+    /// Group must be deactivated first and have 0 remaining usages. The id cannot be reused.
     fn delete_group(env: Env, id: BytesN<32>, caller: Address);
 
     // ============================================================================
@@ -150,21 +318,99 @@ pub trait AutoShareTrait {
     // Payment History
     // ============================================================================
 
-    /// Returns all payment history for a user.
-    fn get_user_payment_history(env: Env, user: Address) -> Vec<PaymentHistory>;
+    /// Returns up to `limit` (max 50) of a user's payments, oldest first, starting at `cursor`.
+    fn get_user_payment_history(
+        env: Env,
+        user: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<PaymentHistory>;
 
-    /// Returns all payment history for a group.
-    fn get_group_payment_history(env: Env, id: BytesN<32>) -> Vec<PaymentHistory>;
+    /// Returns up to `limit` (max 50) of a group's payments, oldest first, starting at `cursor`.
+    fn get_group_payment_history(
+        env: Env,
+        id: BytesN<32>,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<PaymentHistory>;
 
     // ============================================================================
     // Distribution History
     // ============================================================================
 
-    /// Returns all distribution history for a group.
-    fn get_group_distributions(env: Env, id: BytesN<32>) -> Vec<DistributionHistory>;
+    /// Returns up to `limit` (max 50) of a group's distributions, oldest first, starting at
+    /// `cursor`.
+    fn get_group_distributions(
+        env: Env,
+        id: BytesN<32>,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<DistributionHistory>;
 
-    /// Returns all distribution history for a member.
-    fn get_member_distributions(env: Env, member: Address) -> Vec<DistributionRecord>;
+    /// Returns up to `limit` (max 50) of a member's payouts, oldest first, starting at `cursor`.
+    fn get_member_distributions(
+        env: Env,
+        member: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<DistributionRecord>;
+
+    /// Returns a group's distribution, contribution and usage aggregates.
+    fn get_group_stats(env: Env, id: BytesN<32>) -> GroupStatsView;
+
+    /// Extends the next `limit` storage entries of a group (at most 30) to the maximum TTL so
+    /// they do not archive while quiet, continuing where the previous call stopped. Anyone may
+    /// call this. Returns the ledger the walk extended entries to once it completes, `None`
+    /// while entries remain. Entries written after they were walked keep their own TTL.
+    fn extend_group_ttl(env: Env, id: BytesN<32>, limit: u32) -> Option<u32>;
+
+    /// Lists up to `limit` storage keys of a group's entries (at most 30) along with the
+    /// ledger the last completed `extend_group_ttl` walk extended them to. This is not a
+    /// live-until ledger: the contract cannot read TTLs, so look the listed keys up off-chain.
+    fn get_group_ttl(
+        env: Env,
+        id: BytesN<32>,
+        cursor: Option<GroupTtlCursor>,
+        limit: u32,
+    ) -> GroupTtlReport;
+
+    /// Returns deployment-wide group, member, distribution, fee and fundraising totals.
+    fn get_protocol_stats(env: Env) -> ProtocolStats;
+
+    // ============================================================================
+    // Reports
+    // ============================================================================
+
+    /// Returns a group's distributed volume in `token` per day or month, for bucket numbers
+    /// `from` to `to` inclusive (at most 100 buckets). Days count from the Unix epoch, months are
+    /// written `YYYYMM`. Empty buckets are omitted.
+    fn get_group_volume_report(
+        env: Env,
+        id: BytesN<32>,
+        token: Address,
+        interval: ReportInterval,
+        from: u32,
+        to: u32,
+    ) -> Vec<ReportBucket>;
+
+    /// Returns a member's earnings in `token` across all groups per day or month.
+    fn get_member_earnings_report(
+        env: Env,
+        member: Address,
+        token: Address,
+        interval: ReportInterval,
+        from: u32,
+        to: u32,
+    ) -> Vec<ReportBucket>;
+
+    /// Returns the usage fees collected in `token` per day or month.
+    fn get_fee_revenue_report(
+        env: Env,
+        token: Address,
+        interval: ReportInterval,
+        from: u32,
+        to: u32,
+    ) -> Vec<ReportBucket>;
 
     // ============================================================================
     // Usage Tracking
@@ -176,6 +422,27 @@ pub trait AutoShareTrait {
     /// Returns the total usages paid for a group.
     fn get_total_usages_paid(env: Env, id: BytesN<32>) -> u32;
 
+    /// Returns a member's earnings from a specific group in one token.
+    fn get_member_earnings_by_token(
+        env: Env,
+        member: Address,
+        group_id: BytesN<32>,
+        token: Address,
+    ) -> i128;
+
+    /// Returns a member's earnings from all groups in one token.
+    fn get_member_lifetime_earnings(env: Env, member: Address, token: Address) -> i128;
+
+    /// Assigns a member's pre-upgrade earnings sum for a group to `token` and returns the amount
+    /// moved. Admin only.
+    fn migrate_member_earnings(
+        env: Env,
+        admin: Address,
+        member: Address,
+        group_id: BytesN<32>,
+        token: Address,
+    ) -> i128;
+
     /// Returns the fundraising status for a group.
     fn get_fundraising_status(env: Env, id: BytesN<32>) -> FundraisingConfig;
 
@@ -188,6 +455,132 @@ pub trait AutoShareTrait {
     /// Starts a fundraising campaign for a group.
     fn start_fundraising(env: Env, id: BytesN<32>, caller: Address, target_amount: i128);
 
-    /// Contributes funds to a fundraising campaign.
-    fn contribute(env: Env, id: BytesN<32>, token: Address, amount: i128, contributor: Address);
+    /// Closes a running fundraising campaign early. Creator or fundraising manager only.
+    fn close_fundraising(env: Env, id: BytesN<32>, caller: Address);
+
+    /// Cancels a running fundraising campaign. Creator or fundraising manager only.
+    fn cancel_fundraising(env: Env, id: BytesN<32>, caller: Address);
+
+    /// Sets or extends the deadline of a running fundraising campaign.
+    fn extend_fundraising_deadline(env: Env, id: BytesN<32>, caller: Address, new_deadline: u64);
+
+    /// Raises or lowers the target of a running campaign, never below the amount already raised.
+    fn update_fundraising_target(env: Env, id: BytesN<32>, caller: Address, new_target: i128);
+
+    /// Contributes funds to a fundraising campaign and returns the amount accepted.
+    fn contribute(
+        env: Env,
+        id: BytesN<32>,
+        token: Address,
+        amount: i128,
+        contributor: Address,
+    ) -> i128;
+
+    /// Sets the overfunding policy of a running campaign. Creator or fundraising manager only.
+    fn set_overfunding_policy(
+        env: Env,
+        id: BytesN<32>,
+        caller: Address,
+        policy: OverfundingPolicy,
+        hard_cap: i128,
+    );
+
+    /// Sets the contribution limits for a group's campaigns. Creator or fundraising manager only.
+    fn set_contribution_rules(env: Env, id: BytesN<32>, caller: Address, rules: ContributionRules);
+
+    /// Returns the contribution limits for a group's campaigns.
+    fn get_contribution_rules(env: Env, id: BytesN<32>) -> ContributionRules;
+
+    /// Allows an address to contribute when the group's allowlist is enabled. Creator only.
+    fn add_to_allowlist(env: Env, id: BytesN<32>, caller: Address, contributor: Address);
+
+    /// Removes an address from the group's contributor allowlist. Creator only.
+    fn remove_from_allowlist(env: Env, id: BytesN<32>, caller: Address, contributor: Address);
+
+    /// Returns the group's contributor allowlist.
+    fn get_allowlist(env: Env, id: BytesN<32>) -> Vec<Address>;
+
+    /// Returns how much a contributor has given to the group's current campaign.
+    fn get_campaign_contribution(env: Env, id: BytesN<32>, contributor: Address) -> i128;
+
+    // ============================================================================
+    // Recurring Pledges
+    // ============================================================================
+
+    /// Registers a recurring pledge to a group and returns its id. The pledger must first approve
+    /// the contract to spend at least `amount` of the pledged token. A group holds at most 50
+    /// active pledges.
+    fn create_pledge(
+        env: Env,
+        id: BytesN<32>,
+        pledger: Address,
+        token: Address,
+        amount: i128,
+        interval: u64,
+        max_payments: u32,
+    ) -> u32;
+
+    /// Collects the next due payment of a pledge. Anyone can call. Missed periods are skipped
+    /// rather than collected back to back.
+    fn collect_pledge(env: Env, pledge_id: u32) -> i128;
+
+    /// Cancels a pledge. Only the pledger can cancel.
+    fn cancel_pledge(env: Env, pledge_id: u32, pledger: Address);
+
+    /// Returns a pledge by id.
+    fn get_pledge(env: Env, pledge_id: u32) -> Pledge;
+
+    /// Returns the active pledges of a group.
+    fn get_group_pledges(env: Env, id: BytesN<32>) -> Vec<Pledge>;
+
+    // ============================================================================
+    // Matching Pools
+    // ============================================================================
+
+    /// Deposits a sponsor's matching pool into a group's running campaign.
+    fn add_matching_pool(
+        env: Env,
+        id: BytesN<32>,
+        sponsor: Address,
+        token: Address,
+        amount: i128,
+        match_ratio: u32,
+        match_cap: i128,
+    );
+
+    /// Returns the matching pool of a group.
+    fn get_matching_pool(env: Env, id: BytesN<32>) -> MatchingPool;
+
+    /// Returns unused matching funds to the sponsor once the campaign has ended. Anyone can call.
+    fn release_matching_pool(env: Env, id: BytesN<32>) -> i128;
+
+    // ============================================================================
+    // Quadratic Funding Rounds
+    // ============================================================================
+
+    /// Opens a quadratic funding round backed by the sponsor's matching pool.
+    fn create_funding_round(
+        env: Env,
+        sponsor: Address,
+        token: Address,
+        matching_pool: i128,
+        start_time: u64,
+        end_time: u64,
+    ) -> u32;
+
+    /// Opts a group into a funding round. Creator or fundraising manager only.
+    fn join_funding_round(env: Env, round_id: u32, id: BytesN<32>, caller: Address);
+
+    /// Allocates the matching pool of an ended round across its active groups. Anyone can call.
+    fn finalize_funding_round(env: Env, round_id: u32) -> i128;
+
+    /// Pays a group's matched share of a finalized round, or returns it to the sponsor if the
+    /// group is no longer active. Anyone can call.
+    fn claim_round_match(env: Env, round_id: u32, id: BytesN<32>) -> i128;
+
+    /// Returns a funding round by id.
+    fn get_funding_round(env: Env, round_id: u32) -> FundingRound;
+
+    /// Returns a group's contribution tally within a funding round.
+    fn get_round_group_tally(env: Env, round_id: u32, id: BytesN<32>) -> RoundGroupTally;
 }
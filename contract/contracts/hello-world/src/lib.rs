@@ -66,7 +66,9 @@ impl AutoShareContract {
     }
 
     /// Update members of an existing AutoShare plan.
-    /// Requirement: Only creator can update. Validates percentages.
+    /// Requirement: Only the creator or a split manager can update. Validates percentages.
+    /// Addresses that are not yet members must authorize joining.
+    /// If a notice period is set, changes that lower any member's share are queued instead.
     pub fn update_members(
         env: Env,
         id: BytesN<32>,
@@ -97,19 +99,74 @@ impl AutoShareContract {
         autoshare_logic::get_groups_by_member(env, member)
     }
 
-    /// Returns a paginated list of groups.
+    /// Returns a member's groups, shares, per-token earnings and latest payouts in one call.
+    pub fn get_member_overview(env: Env, member: Address) -> base::types::MemberOverview {
+        autoshare_logic::get_member_overview(env, member)
+    }
+
+    /// Returns a paginated list of groups, newest first. Deprecated: offsets shift when groups
+    /// are deleted, use `query_groups` instead.
     pub fn get_groups_paginated(env: Env, start_index: u32, limit: u32) -> base::types::GroupPage {
         autoshare_logic::get_groups_paginated(env, start_index, limit)
     }
 
-    /// Returns a paginated list of groups created by a specific address.
+    /// Returns up to `limit` (max 20) groups created by a specific address, newest first,
+    /// continuing from `cursor` (`None` to start) like `query_groups`.
     pub fn get_groups_by_creator_paginated(
         env: Env,
         creator: Address,
-        offset: u32,
+        cursor: Option<base::types::GroupCursor>,
+        limit: u32,
+    ) -> base::types::GroupQueryPage {
+        autoshare_logic::get_groups_by_creator_paginated(env, creator, cursor, limit)
+    }
+
+    /// Returns up to `limit` (max 50) groups matching `filter`, newest first, continuing from
+    /// `cursor` (`None` to start). Pass the returned `next_cursor` back to fetch more until it
+    /// has no groups `remaining`. Groups are never skipped when others are deleted mid-walk.
+    pub fn query_groups(
+        env: Env,
+        filter: base::types::GroupFilter,
+        cursor: Option<base::types::GroupCursor>,
+        limit: u32,
+    ) -> base::types::GroupQueryPage {
+        autoshare_logic::query_groups(env, filter, cursor, limit)
+    }
+
+    /// Indexes up to `limit` existing groups by creator, starting at registry position
+    /// `start_index`, and returns how many were indexed. Admin only.
+    pub fn backfill_creator_index(env: Env, admin: Address, start_index: u32, limit: u32) -> u32 {
+        autoshare_logic::backfill_creator_index(env, admin, start_index, limit).unwrap()
+    }
+
+    /// Counts up to `limit` existing groups, starting at registry position `start_index`, in the
+    /// active group and member totals of `get_protocol_stats`. Returns how many were counted.
+    /// Admin only.
+    pub fn backfill_protocol_stats(env: Env, admin: Address, start_index: u32, limit: u32) -> u32 {
+        autoshare_logic::backfill_protocol_stats(env, admin, start_index, limit).unwrap()
+    }
+
+    /// Moves up to `limit` pages of a history stored in the legacy single-entry layout into
+    /// paged storage. Returns true once the history is fully migrated. Admin only.
+    pub fn migrate_history(
+        env: Env,
+        admin: Address,
+        log: autoshare_logic::HistoryLog,
+        limit: u32,
+    ) -> bool {
+        autoshare_logic::migrate_history(env, admin, log, limit).unwrap()
+    }
+
+    /// Indexes the payout tokens of up to `limit` entries of a group's distribution history,
+    /// starting at entry `start_index`, and returns how many were examined. Admin only.
+    pub fn backfill_group_tokens(
+        env: Env,
+        admin: Address,
+        id: BytesN<32>,
+        start_index: u32,
         limit: u32,
-    ) -> base::types::GroupPage {
-        autoshare_logic::get_groups_by_creator_paginated(env, creator, offset, limit)
+    ) -> u32 {
+        autoshare_logic::backfill_group_tokens(env, admin, id, start_index, limit).unwrap()
     }
 
     /// Returns the total number of groups.
@@ -127,34 +184,240 @@ impl AutoShareContract {
     }
 
     /// Adds a member to a group with specified percentage.
-    /// Only the group creator (caller) may add members.
+    /// Only the group creator (caller) or a member manager may add members, and the new
+    /// member must authorize joining.
+    /// `strategy` decides whose shares shrink to make room; `Exact` requires the new total to be 100.
     pub fn add_group_member(
         env: Env,
         id: BytesN<32>,
         caller: Address,
         address: Address,
         percentage: u32,
+        strategy: base::types::RebalanceStrategy,
+    ) {
+        autoshare_logic::add_group_member(env, id, caller, address, percentage, strategy).unwrap();
+    }
+
+    /// Removes a single member from a group. Creator or member manager only; group must be active.
+    /// `strategy` decides who receives the freed share; with `Exact` the remaining percentages
+    /// may not sum to 100 and update_members must be called to set a valid split.
+    /// If a notice period is set, the removal is queued until it elapses.
+    pub fn remove_group_member(
+        env: Env,
+        id: BytesN<32>,
+        caller: Address,
+        member_address: Address,
+        strategy: base::types::RebalanceStrategy,
+    ) {
+        autoshare_logic::remove_group_member(env, id, caller, member_address, strategy).unwrap();
+    }
+
+    /// Attaches a vesting schedule to a member's share. Creator or split manager, with the
+    /// member's authorization.
+    pub fn set_vesting_schedule(
+        env: Env,
+        id: BytesN<32>,
+        caller: Address,
+        member: Address,
+        schedule: base::types::VestingSchedule,
+    ) {
+        autoshare_logic::set_vesting_schedule(env, id, caller, member, schedule).unwrap();
+    }
+
+    /// Removes a member's vesting schedule. Creator or split manager only.
+    pub fn remove_vesting_schedule(env: Env, id: BytesN<32>, caller: Address, member: Address) {
+        autoshare_logic::remove_vesting_schedule(env, id, caller, member).unwrap();
+    }
+
+    /// Returns the vesting schedule of a member's share, if any.
+    pub fn get_vesting_schedule(
+        env: Env,
+        id: BytesN<32>,
+        member: Address,
+    ) -> Option<base::types::VestingSchedule> {
+        autoshare_logic::get_vesting_schedule(env, id, member)
+    }
+
+    /// Returns a numbered snapshot of a group's members.
+    pub fn get_split_version(env: Env, id: BytesN<32>, version: u32) -> base::types::SplitVersion {
+        autoshare_logic::get_split_version(env, id, version).unwrap()
+    }
+
+    /// Returns every recorded split version of a group, oldest first.
+    pub fn get_split_versions(env: Env, id: BytesN<32>) -> Vec<base::types::SplitVersion> {
+        autoshare_logic::get_split_versions(env, id)
+    }
+
+    /// Removes the caller from a group; their share goes pro-rata to the remaining members.
+    /// Not available in governed groups or to the last member. Cancels a queued split change.
+    pub fn leave_group(env: Env, id: BytesN<32>, member: Address) {
+        autoshare_logic::leave_group(env, id, member).unwrap();
+    }
+
+    /// Replaces a member's payout address in a group. Both addresses must authorize.
+    /// Not available in governed groups. A queued split change follows the new address.
+    pub fn update_payout_address(env: Env, id: BytesN<32>, member: Address, new_address: Address) {
+        autoshare_logic::update_payout_address(env, id, member, new_address).unwrap();
+    }
+
+    /// Grants or replaces a manager's delegated permissions. Only the creator can call this.
+    pub fn set_manager(
+        env: Env,
+        id: BytesN<32>,
+        caller: Address,
+        manager: Address,
+        scope: base::types::ManagerScope,
+    ) {
+        autoshare_logic::set_manager(env, id, caller, manager, scope).unwrap();
+    }
+
+    /// Removes a manager from a group. Only the creator can call this.
+    pub fn remove_manager(env: Env, id: BytesN<32>, caller: Address, manager: Address) {
+        autoshare_logic::remove_manager(env, id, caller, manager).unwrap();
+    }
+
+    /// Returns the managers of a group and their scopes.
+    pub fn get_managers(env: Env, id: BytesN<32>) -> Vec<base::types::GroupManager> {
+        autoshare_logic::get_managers(env, id)
+    }
+
+    /// Enables or configures governance mode. Only the creator can call this, and only while
+    /// governance is off.
+    pub fn set_governance(
+        env: Env,
+        id: BytesN<32>,
+        caller: Address,
+        config: base::types::GovernanceConfig,
     ) {
-        autoshare_logic::add_group_member(env, id, caller, address, percentage).unwrap();
+        autoshare_logic::set_governance(env, id, caller, config).unwrap();
     }
 
-    /// Removes a single member from a group. Only the creator can call; group must be active.
-    /// After removal, remaining percentages may not sum to 100; call update_members to set a valid split.
-    pub fn remove_group_member(env: Env, id: BytesN<32>, caller: Address, member_address: Address) {
-        autoshare_logic::remove_group_member(env, id, caller, member_address).unwrap();
+    /// Returns the governance settings of a group.
+    pub fn get_governance_config(env: Env, id: BytesN<32>) -> base::types::GovernanceConfig {
+        autoshare_logic::get_governance_config(env, id)
     }
 
-    /// Deactivates a group. Only the creator can deactivate.
+    /// Opens a proposal for a member or governance change in a governed group.
+    pub fn propose_change(
+        env: Env,
+        id: BytesN<32>,
+        proposer: Address,
+        action: base::types::ProposalAction,
+    ) -> u32 {
+        autoshare_logic::propose_change(env, id, proposer, action).unwrap()
+    }
+
+    /// Casts a vote on a proposal, weighted by the voter's percentage.
+    pub fn vote(env: Env, proposal_id: u32, voter: Address, approve: bool) {
+        autoshare_logic::vote(env, proposal_id, voter, approve).unwrap();
+    }
+
+    /// Resolves a proposal and applies it if it passed. Returns whether it was applied.
+    pub fn execute_proposal(env: Env, proposal_id: u32) -> bool {
+        autoshare_logic::execute_proposal(env, proposal_id).unwrap()
+    }
+
+    /// Returns a proposal by ID.
+    pub fn get_proposal(env: Env, proposal_id: u32) -> base::types::Proposal {
+        autoshare_logic::get_proposal(env, proposal_id).unwrap()
+    }
+
+    /// Returns all proposals of a group.
+    pub fn get_group_proposals(env: Env, id: BytesN<32>) -> Vec<base::types::Proposal> {
+        autoshare_logic::get_group_proposals(env, id)
+    }
+
+    /// Sets the notice period for split reductions. Only the creator can call this.
+    pub fn set_notice_period(env: Env, id: BytesN<32>, caller: Address, period: u64) {
+        autoshare_logic::set_notice_period(env, id, caller, period).unwrap();
+    }
+
+    /// Returns the notice period currently in force for a group.
+    pub fn get_notice_period(env: Env, id: BytesN<32>) -> u64 {
+        autoshare_logic::get_notice_period(env, id)
+    }
+
+    /// Applies a queued split change whose notice period has elapsed.
+    pub fn apply_split_change(env: Env, id: BytesN<32>) {
+        autoshare_logic::apply_split_change(env, id).unwrap();
+    }
+
+    /// Cancels a queued split change. Creator or split manager only.
+    pub fn cancel_split_change(env: Env, id: BytesN<32>, caller: Address) {
+        autoshare_logic::cancel_split_change(env, id, caller).unwrap();
+    }
+
+    /// Returns the split change waiting out the notice period, if any.
+    pub fn get_pending_split_change(
+        env: Env,
+        id: BytesN<32>,
+    ) -> Option<base::types::PendingSplitChange> {
+        autoshare_logic::get_pending_split_change(env, id)
+    }
+
+    /// Proposes a new creator for a group. Only the current creator can call this.
+    pub fn propose_ownership_transfer(
+        env: Env,
+        id: BytesN<32>,
+        caller: Address,
+        new_creator: Address,
+    ) {
+        autoshare_logic::propose_ownership_transfer(env, id, caller, new_creator).unwrap();
+    }
+
+    /// Accepts a pending ownership transfer. Must be called by the proposed creator.
+    pub fn accept_ownership_transfer(env: Env, id: BytesN<32>, new_creator: Address) {
+        autoshare_logic::accept_ownership_transfer(env, id, new_creator).unwrap();
+    }
+
+    /// Cancels a pending ownership transfer. Only the current creator can call this.
+    pub fn cancel_ownership_transfer(env: Env, id: BytesN<32>, caller: Address) {
+        autoshare_logic::cancel_ownership_transfer(env, id, caller).unwrap();
+    }
+
+    /// Returns the proposed new creator of a group, if a transfer is pending.
+    pub fn get_pending_owner(env: Env, id: BytesN<32>) -> Option<Address> {
+        autoshare_logic::get_pending_owner(env, id)
+    }
+
+    /// Invites an address to join a group. They become a member only after accepting.
+    pub fn invite_member(
+        env: Env,
+        id: BytesN<32>,
+        caller: Address,
+        invitee: Address,
+        percentage: u32,
+        expires_at: u64,
+    ) {
+        autoshare_logic::invite_member(env, id, caller, invitee, percentage, expires_at).unwrap();
+    }
+
+    /// Accepts a pending invite; existing shares are scaled down to make room.
+    pub fn accept_invite(env: Env, id: BytesN<32>, invitee: Address) {
+        autoshare_logic::accept_invite(env, id, invitee).unwrap();
+    }
+
+    /// Cancels a pending invite. Callable by the creator or the invitee.
+    pub fn cancel_invite(env: Env, id: BytesN<32>, caller: Address, invitee: Address) {
+        autoshare_logic::cancel_invite(env, id, caller, invitee).unwrap();
+    }
+
+    /// Returns the unexpired invites of a group.
+    pub fn get_pending_invites(env: Env, id: BytesN<32>) -> Vec<base::types::MemberInvite> {
+        autoshare_logic::get_pending_invites(env, id)
+    }
+
+    /// Deactivates a group. Creator or activation manager only.
     pub fn deactivate_group(env: Env, id: BytesN<32>, caller: Address) {
         autoshare_logic::deactivate_group(env, id, caller).unwrap();
     }
 
-    /// Activates a group. Only the creator can activate.
+    /// Activates a group. Creator or activation manager only.
     pub fn activate_group(env: Env, id: BytesN<32>, caller: Address) {
         autoshare_logic::activate_group(env, id, caller).unwrap();
     }
 
-    /// Updates the name of a group. Only the creator can update.
+    /// Updates the name of a group. Creator or rename manager only.
     pub fn update_group_name(env: Env, id: BytesN<32>, caller: Address, new_name: String) {
         autoshare_logic::update_group_name(env, id, caller, new_name).unwrap();
     }
@@ -165,7 +428,7 @@ impl AutoShareContract {
     }
 
     /// Permanently deletes a group. Only creator or admin can delete.
-    /// Group must be deactivated first and have 0 remaining usages.
+    /// Group must be deactivated first and have 0 remaining usages. The id cannot be reused.
     pub fn delete_group(env: Env, id: BytesN<32>, caller: Address) {
         autoshare_logic::delete_group(env, id, caller).unwrap();
     }
@@ -253,34 +516,120 @@ impl AutoShareContract {
     // Payment History
     // ============================================================================
 
-    /// Returns all payment history for a user.
-    pub fn get_user_payment_history(env: Env, user: Address) -> Vec<base::types::PaymentHistory> {
-        autoshare_logic::get_user_payment_history(env, user)
+    /// Returns up to `limit` (max 50) of a user's payments, oldest first, starting at `cursor`.
+    pub fn get_user_payment_history(
+        env: Env,
+        user: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<base::types::PaymentHistory> {
+        autoshare_logic::get_user_payment_history(env, user, cursor, limit)
     }
 
-    /// Returns all payment history for a group.
-    pub fn get_group_payment_history(env: Env, id: BytesN<32>) -> Vec<base::types::PaymentHistory> {
-        autoshare_logic::get_group_payment_history(env, id)
+    /// Returns up to `limit` (max 50) of a group's payments, oldest first, starting at `cursor`.
+    pub fn get_group_payment_history(
+        env: Env,
+        id: BytesN<32>,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<base::types::PaymentHistory> {
+        autoshare_logic::get_group_payment_history(env, id, cursor, limit)
     }
 
     // ============================================================================
     // Distribution History
     // ============================================================================
 
-    /// Returns all distribution history for a group.
+    /// Returns up to `limit` (max 50) of a group's distributions, oldest first, starting at
+    /// `cursor`.
     pub fn get_group_distributions(
         env: Env,
         id: BytesN<32>,
+        cursor: u32,
+        limit: u32,
     ) -> Vec<base::types::DistributionHistory> {
-        autoshare_logic::get_group_distributions(env, id)
+        autoshare_logic::get_group_distributions(env, id, cursor, limit)
     }
 
-    /// Returns all distribution history for a member.
+    /// Returns up to `limit` (max 50) of a member's payouts, oldest first, starting at `cursor`.
     pub fn get_member_distributions(
         env: Env,
         member: Address,
+        cursor: u32,
+        limit: u32,
     ) -> Vec<base::types::DistributionRecord> {
-        autoshare_logic::get_member_distributions(env, member)
+        autoshare_logic::get_member_distributions(env, member, cursor, limit)
+    }
+
+    /// Returns a group's distribution, contribution and usage aggregates.
+    pub fn get_group_stats(env: Env, id: BytesN<32>) -> base::types::GroupStatsView {
+        autoshare_logic::get_group_stats(env, id).unwrap()
+    }
+
+    /// Extends the next `limit` storage entries of a group (at most 30) to the maximum TTL so
+    /// they do not archive while quiet, continuing where the previous call stopped. Anyone may
+    /// call this. Returns the ledger the walk extended entries to once it completes, `None`
+    /// while entries remain. Entries written after they were walked keep their own TTL.
+    pub fn extend_group_ttl(env: Env, id: BytesN<32>, limit: u32) -> Option<u32> {
+        autoshare_logic::extend_group_ttl(env, id, limit).unwrap()
+    }
+
+    /// Lists up to `limit` storage keys of a group's entries (at most 30) along with the
+    /// ledger the last completed `extend_group_ttl` walk extended them to. This is not a
+    /// live-until ledger: the contract cannot read TTLs, so look the listed keys up off-chain.
+    pub fn get_group_ttl(
+        env: Env,
+        id: BytesN<32>,
+        cursor: Option<base::types::GroupTtlCursor>,
+        limit: u32,
+    ) -> base::types::GroupTtlReport {
+        autoshare_logic::get_group_ttl(env, id, cursor, limit).unwrap()
+    }
+
+    /// Returns deployment-wide group, member, distribution, fee and fundraising totals.
+    pub fn get_protocol_stats(env: Env) -> base::types::ProtocolStats {
+        autoshare_logic::get_protocol_stats(env)
+    }
+
+    // ============================================================================
+    // Reports
+    // ============================================================================
+
+    /// Returns a group's distributed volume in `token` per day or month, for bucket numbers
+    /// `from` to `to` inclusive (at most 100 buckets). Days count from the Unix epoch, months are
+    /// written `YYYYMM`. Empty buckets are omitted.
+    pub fn get_group_volume_report(
+        env: Env,
+        id: BytesN<32>,
+        token: Address,
+        interval: base::types::ReportInterval,
+        from: u32,
+        to: u32,
+    ) -> Vec<base::types::ReportBucket> {
+        autoshare_logic::get_group_volume_report(env, id, token, interval, from, to).unwrap()
+    }
+
+    /// Returns a member's earnings in `token` across all groups per day or month.
+    pub fn get_member_earnings_report(
+        env: Env,
+        member: Address,
+        token: Address,
+        interval: base::types::ReportInterval,
+        from: u32,
+        to: u32,
+    ) -> Vec<base::types::ReportBucket> {
+        autoshare_logic::get_member_earnings_report(env, member, token, interval, from, to).unwrap()
+    }
+
+    /// Returns the usage fees collected in `token` per day or month.
+    pub fn get_fee_revenue_report(
+        env: Env,
+        token: Address,
+        interval: base::types::ReportInterval,
+        from: u32,
+        to: u32,
+    ) -> Vec<base::types::ReportBucket> {
+        autoshare_logic::get_fee_revenue_report(env, token, interval, from, to).unwrap()
     }
 
     // ============================================================================
@@ -297,9 +646,37 @@ impl AutoShareContract {
         autoshare_logic::get_total_usages_paid(env, id).unwrap()
     }
 
-    /// Returns the total earnings for a member from a specific group.
+    /// Returns the total earnings for a member from a specific group. Fails for groups that have
+    /// paid out in more than one token; use `get_member_earnings_by_token` for those.
     pub fn get_member_earnings(env: Env, member: Address, group_id: BytesN<32>) -> i128 {
-        autoshare_logic::get_member_earnings(env, member, group_id)
+        autoshare_logic::get_member_earnings(env, member, group_id).unwrap()
+    }
+
+    /// Returns a member's earnings from a specific group in one token.
+    pub fn get_member_earnings_by_token(
+        env: Env,
+        member: Address,
+        group_id: BytesN<32>,
+        token: Address,
+    ) -> i128 {
+        autoshare_logic::get_member_earnings_by_token(env, member, group_id, token)
+    }
+
+    /// Returns a member's earnings from all groups in one token.
+    pub fn get_member_lifetime_earnings(env: Env, member: Address, token: Address) -> i128 {
+        autoshare_logic::get_member_lifetime_earnings(env, member, token)
+    }
+
+    /// Assigns a member's pre-upgrade earnings sum for a group to `token` and returns the amount
+    /// moved. Admin only.
+    pub fn migrate_member_earnings(
+        env: Env,
+        admin: Address,
+        member: Address,
+        group_id: BytesN<32>,
+        token: Address,
+    ) -> i128 {
+        autoshare_logic::migrate_member_earnings(env, admin, member, group_id, token).unwrap()
     }
 
     /// Returns the fundraising status for a group.
@@ -328,15 +705,197 @@ impl AutoShareContract {
         autoshare_logic::start_fundraising(env, id, caller, target_amount).unwrap();
     }
 
-    /// Contributes funds to a fundraising campaign.
+    /// Closes a running fundraising campaign early. Creator or fundraising manager only.
+    pub fn close_fundraising(env: Env, id: BytesN<32>, caller: Address) {
+        autoshare_logic::close_fundraising(env, id, caller).unwrap();
+    }
+
+    /// Cancels a running fundraising campaign. Creator or fundraising manager only.
+    pub fn cancel_fundraising(env: Env, id: BytesN<32>, caller: Address) {
+        autoshare_logic::cancel_fundraising(env, id, caller).unwrap();
+    }
+
+    /// Sets or extends the deadline of a running fundraising campaign.
+    pub fn extend_fundraising_deadline(
+        env: Env,
+        id: BytesN<32>,
+        caller: Address,
+        new_deadline: u64,
+    ) {
+        autoshare_logic::extend_fundraising_deadline(env, id, caller, new_deadline).unwrap();
+    }
+
+    /// Raises or lowers the target of a running campaign, never below the amount already raised.
+    pub fn update_fundraising_target(env: Env, id: BytesN<32>, caller: Address, new_target: i128) {
+        autoshare_logic::update_fundraising_target(env, id, caller, new_target).unwrap();
+    }
+
+    /// Contributes funds to a fundraising campaign and returns the amount accepted.
     pub fn contribute(
         env: Env,
         id: BytesN<32>,
         token: Address,
         amount: i128,
         contributor: Address,
+    ) -> i128 {
+        autoshare_logic::contribute(env, id, token, amount, contributor).unwrap()
+    }
+
+    /// Sets the overfunding policy of a running campaign. Creator or fundraising manager only.
+    pub fn set_overfunding_policy(
+        env: Env,
+        id: BytesN<32>,
+        caller: Address,
+        policy: base::types::OverfundingPolicy,
+        hard_cap: i128,
+    ) {
+        autoshare_logic::set_overfunding_policy(env, id, caller, policy, hard_cap).unwrap();
+    }
+
+    /// Sets the contribution limits for a group's campaigns. Creator or fundraising manager only.
+    pub fn set_contribution_rules(
+        env: Env,
+        id: BytesN<32>,
+        caller: Address,
+        rules: base::types::ContributionRules,
+    ) {
+        autoshare_logic::set_contribution_rules(env, id, caller, rules).unwrap();
+    }
+
+    /// Returns the contribution limits for a group's campaigns.
+    pub fn get_contribution_rules(env: Env, id: BytesN<32>) -> base::types::ContributionRules {
+        autoshare_logic::get_contribution_rules(env, id)
+    }
+
+    /// Allows an address to contribute when the group's allowlist is enabled. Creator only.
+    pub fn add_to_allowlist(env: Env, id: BytesN<32>, caller: Address, contributor: Address) {
+        autoshare_logic::add_to_allowlist(env, id, caller, contributor).unwrap();
+    }
+
+    /// Removes an address from the group's contributor allowlist. Creator only.
+    pub fn remove_from_allowlist(env: Env, id: BytesN<32>, caller: Address, contributor: Address) {
+        autoshare_logic::remove_from_allowlist(env, id, caller, contributor).unwrap();
+    }
+
+    /// Returns the group's contributor allowlist.
+    pub fn get_allowlist(env: Env, id: BytesN<32>) -> Vec<Address> {
+        autoshare_logic::get_allowlist(env, id)
+    }
+
+    /// Returns how much a contributor has given to the group's current campaign.
+    pub fn get_campaign_contribution(env: Env, id: BytesN<32>, contributor: Address) -> i128 {
+        autoshare_logic::get_campaign_contribution(env, id, contributor)
+    }
+
+    /// Registers a recurring pledge to a group and returns its id. The pledger must first approve
+    /// the contract to spend at least `amount` of the pledged token. A group holds at most 50
+    /// active pledges.
+    pub fn create_pledge(
+        env: Env,
+        id: BytesN<32>,
+        pledger: Address,
+        token: Address,
+        amount: i128,
+        interval: u64,
+        max_payments: u32,
+    ) -> u32 {
+        autoshare_logic::create_pledge(env, id, pledger, token, amount, interval, max_payments)
+            .unwrap()
+    }
+
+    /// Collects the next due payment of a pledge. Anyone can call. Missed periods are skipped
+    /// rather than collected back to back.
+    pub fn collect_pledge(env: Env, pledge_id: u32) -> i128 {
+        autoshare_logic::collect_pledge(env, pledge_id).unwrap()
+    }
+
+    /// Cancels a pledge. Only the pledger can cancel.
+    pub fn cancel_pledge(env: Env, pledge_id: u32, pledger: Address) {
+        autoshare_logic::cancel_pledge(env, pledge_id, pledger).unwrap();
+    }
+
+    /// Returns a pledge by id.
+    pub fn get_pledge(env: Env, pledge_id: u32) -> base::types::Pledge {
+        autoshare_logic::get_pledge(env, pledge_id).unwrap()
+    }
+
+    /// Returns the active pledges of a group.
+    pub fn get_group_pledges(env: Env, id: BytesN<32>) -> Vec<base::types::Pledge> {
+        autoshare_logic::get_group_pledges(env, id)
+    }
+
+    /// Deposits a sponsor's matching pool into a group's running campaign.
+    pub fn add_matching_pool(
+        env: Env,
+        id: BytesN<32>,
+        sponsor: Address,
+        token: Address,
+        amount: i128,
+        match_ratio: u32,
+        match_cap: i128,
     ) {
-        autoshare_logic::contribute(env, id, token, amount, contributor).unwrap();
+        autoshare_logic::add_matching_pool(env, id, sponsor, token, amount, match_ratio, match_cap)
+            .unwrap();
+    }
+
+    /// Returns the matching pool of a group.
+    pub fn get_matching_pool(env: Env, id: BytesN<32>) -> base::types::MatchingPool {
+        autoshare_logic::get_matching_pool(env, id).unwrap()
+    }
+
+    /// Returns unused matching funds to the sponsor once the campaign has ended. Anyone can call.
+    pub fn release_matching_pool(env: Env, id: BytesN<32>) -> i128 {
+        autoshare_logic::release_matching_pool(env, id).unwrap()
+    }
+
+    /// Opens a quadratic funding round backed by the sponsor's matching pool.
+    pub fn create_funding_round(
+        env: Env,
+        sponsor: Address,
+        token: Address,
+        matching_pool: i128,
+        start_time: u64,
+        end_time: u64,
+    ) -> u32 {
+        autoshare_logic::create_funding_round(
+            env,
+            sponsor,
+            token,
+            matching_pool,
+            start_time,
+            end_time,
+        )
+        .unwrap()
+    }
+
+    /// Opts a group into a funding round. Creator or fundraising manager only.
+    pub fn join_funding_round(env: Env, round_id: u32, id: BytesN<32>, caller: Address) {
+        autoshare_logic::join_funding_round(env, round_id, id, caller).unwrap();
+    }
+
+    /// Allocates the matching pool of an ended round across its active groups. Anyone can call.
+    pub fn finalize_funding_round(env: Env, round_id: u32) -> i128 {
+        autoshare_logic::finalize_funding_round(env, round_id).unwrap()
+    }
+
+    /// Pays a group's matched share of a finalized round, or returns it to the sponsor if the
+    /// group is no longer active. Anyone can call.
+    pub fn claim_round_match(env: Env, round_id: u32, id: BytesN<32>) -> i128 {
+        autoshare_logic::claim_round_match(env, round_id, id).unwrap()
+    }
+
+    /// Returns a funding round by id.
+    pub fn get_funding_round(env: Env, round_id: u32) -> base::types::FundingRound {
+        autoshare_logic::get_funding_round(env, round_id).unwrap()
+    }
+
+    /// Returns a group's contribution tally within a funding round.
+    pub fn get_round_group_tally(
+        env: Env,
+        round_id: u32,
+        id: BytesN<32>,
+    ) -> base::types::RoundGroupTally {
+        autoshare_logic::get_round_group_tally(env, round_id, id)
     }
 
     /// Returns the fundraising progress as a percentage (0-100).
@@ -425,3 +984,103 @@ mod max_members_test;
 #[cfg(test)]
 #[path = "tests/group_count_property_test.rs"]
 mod group_count_property_test;
+
+#[cfg(test)]
+#[path = "tests/fundraising_lifecycle_test.rs"]
+mod fundraising_lifecycle_test;
+
+#[cfg(test)]
+#[path = "tests/overfunding_test.rs"]
+mod overfunding_test;
+
+#[cfg(test)]
+#[path = "tests/contribution_rules_test.rs"]
+mod contribution_rules_test;
+
+#[cfg(test)]
+#[path = "tests/pledge_test.rs"]
+mod pledge_test;
+
+#[cfg(test)]
+#[path = "tests/matching_pool_test.rs"]
+mod matching_pool_test;
+
+#[cfg(test)]
+#[path = "tests/funding_round_test.rs"]
+mod funding_round_test;
+
+#[cfg(test)]
+#[path = "tests/member_self_service_test.rs"]
+mod member_self_service_test;
+
+#[cfg(test)]
+#[path = "tests/invitation_test.rs"]
+mod invitation_test;
+
+#[cfg(test)]
+#[path = "tests/group_manager_test.rs"]
+mod group_manager_test;
+
+#[cfg(test)]
+#[path = "tests/ownership_transfer_test.rs"]
+mod ownership_transfer_test;
+
+#[cfg(test)]
+#[path = "tests/governance_test.rs"]
+mod governance_test;
+
+#[cfg(test)]
+#[path = "tests/notice_period_test.rs"]
+mod notice_period_test;
+
+#[cfg(test)]
+#[path = "tests/split_version_test.rs"]
+mod split_version_test;
+
+#[cfg(test)]
+#[path = "tests/rebalance_test.rs"]
+mod rebalance_test;
+
+#[cfg(test)]
+#[path = "tests/vesting_test.rs"]
+mod vesting_test;
+
+#[cfg(test)]
+#[path = "tests/history_paging_test.rs"]
+mod history_paging_test;
+
+#[cfg(test)]
+#[path = "tests/group_registry_test.rs"]
+mod group_registry_test;
+
+#[cfg(test)]
+#[path = "tests/creator_index_test.rs"]
+mod creator_index_test;
+
+#[cfg(test)]
+#[path = "tests/group_query_test.rs"]
+mod group_query_test;
+
+#[cfg(test)]
+#[path = "tests/token_earnings_test.rs"]
+mod token_earnings_test;
+
+#[cfg(test)]
+#[path = "tests/report_test.rs"]
+mod report_test;
+
+#[cfg(test)]
+#[path = "tests/protocol_stats_test.rs"]
+mod protocol_stats_test;
+
+#[cfg(test)]
+#[path = "tests/group_stats_test.rs"]
+mod group_stats_test;
+
+#[cfg(test)]
+#[path = "tests/member_overview_test.rs"]
+mod member_overview_test;
+
+#[cfg(test)]
+#[path = "tests/group_ttl_test.rs"]
+mod group_ttl_test;
@@ -1,4 +1,4 @@
-use crate::base::types::GroupMember;
+use crate::base::types::{GroupMember, RebalanceStrategy};
 use crate::mock_token::{MockToken, MockTokenClient};
 use crate::test_utils::{create_test_group, setup_test_env};
 use crate::{AutoShareContract, AutoShareContractClient};
@@ -682,7 +682,7 @@ fn test_add_duplicate_member() {
     create_helper(&client, &id, &name, &creator, &members, &test_env);
 
     // Try to add the same member again - should fail
-    client.add_group_member(&id, &creator, &member1, &50);
+    client.add_group_member(&id, &creator, &member1, &50, &RebalanceStrategy::Exact);
 }
 
 #[test]
@@ -695,7 +695,7 @@ fn test_add_member_to_non_existent_group() {
     let member = Address::generate(&test_env.env);
 
     let caller = Address::generate(&test_env.env);
-    client.add_group_member(&id, &caller, &member, &50);
+    client.add_group_member(&id, &caller, &member, &50, &RebalanceStrategy::Exact);
 }
 
 #[test]
@@ -720,7 +720,7 @@ fn test_add_member_invalid_total_percentage() {
 
     // Try to add another member with 50% (total would be 150%) - should fail
     let member2 = Address::generate(&test_env.env);
-    client.add_group_member(&id, &creator, &member2, &50);
+    client.add_group_member(&id, &creator, &member2, &50, &RebalanceStrategy::Exact);
 }
 
 #[test]
@@ -889,7 +889,7 @@ fn test_remove_group_member_success() {
     create_helper(&client, &id, &name, &creator, &members, &test_env);
     assert_eq!(client.get_group_members(&id).len(), 3);
 
-    client.remove_group_member(&id, &creator, &member2);
+    client.remove_group_member(&id, &creator, &member2, &RebalanceStrategy::Exact);
 
     let after = client.get_group_members(&id);
     assert_eq!(after.len(), 2);
@@ -924,7 +924,7 @@ fn test_remove_group_member_unauthorized() {
     create_helper(&client, &id, &name, &creator, &members, &test_env);
 
     let other_user = Address::generate(&test_env.env);
-    client.remove_group_member(&id, &other_user, &member2);
+    client.remove_group_member(&id, &other_user, &member2, &RebalanceStrategy::Exact);
 }
 
 #[test]
@@ -952,7 +952,7 @@ fn test_remove_group_member_inactive() {
     create_helper(&client, &id, &name, &creator, &members, &test_env);
     client.deactivate_group(&id, &creator);
 
-    client.remove_group_member(&id, &creator, &member2);
+    client.remove_group_member(&id, &creator, &member2, &RebalanceStrategy::Exact);
 }
 
 #[test]
@@ -980,7 +980,7 @@ fn test_remove_group_member_not_found() {
     create_helper(&client, &id, &name, &creator, &members, &test_env);
 
     let not_in_group = Address::generate(&test_env.env);
-    client.remove_group_member(&id, &creator, &not_in_group);
+    client.remove_group_member(&id, &creator, &not_in_group, &RebalanceStrategy::Exact);
 }
 
 #[test]
@@ -1009,7 +1009,7 @@ fn test_remove_group_member_paused() {
     create_helper(&client, &id, &name, &creator, &members, &test_env);
     client.pause(&admin);
 
-    client.remove_group_member(&id, &creator, &member2);
+    client.remove_group_member(&id, &creator, &member2, &RebalanceStrategy::Exact);
 }
 
 #[test]
@@ -1034,7 +1034,7 @@ fn test_remove_group_member_then_update_members() {
     });
 
     create_helper(&client, &id, &name, &creator, &members, &test_env);
-    client.remove_group_member(&id, &creator, &member2);
+    client.remove_group_member(&id, &creator, &member2, &RebalanceStrategy::Exact);
 
     let after_remove = client.get_group_members(&id);
     assert_eq!(after_remove.len(), 1);
@@ -0,0 +1,147 @@
+use crate::base::types::ContributionRules;
+use crate::test_utils::{
+    create_test_group, create_test_members, fund_user_with_tokens, setup_test_env, TestEnv,
+};
+use crate::AutoShareContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN};
+
+fn setup_campaign(test_env: &TestEnv, target_amount: i128) -> (BytesN<32>, Address, Address) {
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let members = create_test_members(&test_env.env, 2);
+    let group_id = create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        &creator,
+        &members,
+        10,
+        &token,
+    );
+    client.start_fundraising(&group_id, &creator, &target_amount);
+    (group_id, creator, token)
+}
+
+fn rules(min: i128, max: i128, cap: i128, allowlist_enabled: bool) -> ContributionRules {
+    ContributionRules {
+        min_contribution: min,
+        max_contribution: max,
+        contributor_cap: cap,
+        allowlist_enabled,
+    }
+}
+
+#[test]
+fn test_contribution_rules_default_to_unrestricted() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, _creator, _token) = setup_campaign(&test_env, 1000);
+
+    assert_eq!(
+        client.get_contribution_rules(&group_id),
+        rules(0, 0, 0, false)
+    );
+}
+
+#[test]
+#[should_panic(expected = "ContributionBelowMinimum")]
+fn test_contribution_below_minimum_fails() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, token) = setup_campaign(&test_env, 1000);
+    let contributor = test_env.users.get(1).unwrap();
+
+    client.set_contribution_rules(&group_id, &creator, &rules(50, 0, 0, false));
+
+    fund_user_with_tokens(&test_env.env, &token, &contributor, 49);
+    client.contribute(&group_id, &token, &49, &contributor);
+}
+
+#[test]
+#[should_panic(expected = "ContributionLimitExceeded")]
+fn test_contribution_above_maximum_fails() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, token) = setup_campaign(&test_env, 1000);
+    let contributor = test_env.users.get(1).unwrap();
+
+    client.set_contribution_rules(&group_id, &creator, &rules(0, 200, 0, false));
+
+    fund_user_with_tokens(&test_env.env, &token, &contributor, 201);
+    client.contribute(&group_id, &token, &201, &contributor);
+}
+
+#[test]
+fn test_contributor_cap_tracks_campaign_total() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, token) = setup_campaign(&test_env, 1000);
+    let contributor = test_env.users.get(1).unwrap();
+
+    client.set_contribution_rules(&group_id, &creator, &rules(0, 0, 300, false));
+
+    fund_user_with_tokens(&test_env.env, &token, &contributor, 1000);
+    client.contribute(&group_id, &token, &200, &contributor);
+    client.contribute(&group_id, &token, &100, &contributor);
+    assert_eq!(
+        client.get_campaign_contribution(&group_id, &contributor),
+        300
+    );
+
+    let result = client.try_contribute(&group_id, &token, &1, &contributor);
+    assert!(result.is_err());
+
+    // The cap applies per campaign
+    client.close_fundraising(&group_id, &creator);
+    client.start_fundraising(&group_id, &creator, &1000);
+    assert_eq!(client.get_campaign_contribution(&group_id, &contributor), 0);
+    client.contribute(&group_id, &token, &300, &contributor);
+}
+
+#[test]
+fn test_allowlist_restricts_contributors() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, token) = setup_campaign(&test_env, 1000);
+    let partner = test_env.users.get(1).unwrap();
+    let stranger = test_env.users.get(2).unwrap();
+
+    client.add_to_allowlist(&group_id, &creator, &partner);
+    client.set_contribution_rules(&group_id, &creator, &rules(0, 0, 0, true));
+    assert_eq!(client.get_allowlist(&group_id).len(), 1);
+
+    fund_user_with_tokens(&test_env.env, &token, &partner, 100);
+    fund_user_with_tokens(&test_env.env, &token, &stranger, 100);
+
+    client.contribute(&group_id, &token, &100, &partner);
+    assert!(client
+        .try_contribute(&group_id, &token, &100, &stranger)
+        .is_err());
+
+    client.remove_from_allowlist(&group_id, &creator, &partner);
+    assert_eq!(client.get_allowlist(&group_id).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_non_creator_cannot_set_rules() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, _creator, _token) = setup_campaign(&test_env, 1000);
+    let stranger = test_env.users.get(1).unwrap();
+
+    client.set_contribution_rules(&group_id, &stranger, &rules(0, 100, 0, false));
+}
+
+#[test]
+#[should_panic(expected = "AllowlistFull")]
+fn test_allowlist_size_is_capped() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, _token) = setup_campaign(&test_env, 1000);
+
+    for _ in 0..=100 {
+        client.add_to_allowlist(&group_id, &creator, &Address::generate(&test_env.env));
+    }
+}
@@ -0,0 +1,76 @@
+use crate::autoshare_logic::DataKey;
+use crate::test_utils::{create_test_group, create_test_members, setup_test_env, TestEnv};
+use crate::AutoShareContractClient;
+use soroban_sdk::{Address, BytesN};
+
+fn create_group(test_env: &TestEnv, creator: &Address, usages: u32) -> BytesN<32> {
+    let token = test_env.mock_tokens.get(0).unwrap();
+    create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        creator,
+        &create_test_members(&test_env.env, 2),
+        usages,
+        &token,
+    )
+}
+
+#[test]
+fn test_creator_index_follows_create_and_delete() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let other = test_env.users.get(1).unwrap();
+
+    let first = create_group(&test_env, &creator, 1);
+    let second = create_group(&test_env, &creator, 2);
+    create_group(&test_env, &other, 3);
+
+    let page = client.get_groups_by_creator_paginated(&creator, &None, &10);
+    assert_eq!(page.groups.len(), 2);
+    assert_eq!(page.groups.get(0).unwrap().id, second);
+
+    client.deactivate_group(&first, &creator);
+    client.delete_group(&first, &creator);
+
+    let groups = client.get_groups_by_creator(&creator);
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups.get(0).unwrap().id, second);
+    assert_eq!(client.get_groups_by_creator(&other).len(), 1);
+}
+
+#[test]
+fn test_backfill_rebuilds_missing_index() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    for usages in 1..=3 {
+        create_group(&test_env, &creator, usages);
+    }
+
+    // Drop the index, as on a deployment created before it existed
+    test_env.env.as_contract(&test_env.autoshare_contract, || {
+        test_env
+            .env
+            .storage()
+            .persistent()
+            .remove(&DataKey::CreatorGroups(creator.clone()));
+    });
+    assert_eq!(client.get_groups_by_creator(&creator).len(), 0);
+
+    assert_eq!(client.backfill_creator_index(&test_env.admin, &0, &2), 2);
+    assert_eq!(client.backfill_creator_index(&test_env.admin, &2, &2), 1);
+    // Running a batch again does not duplicate entries
+    client.backfill_creator_index(&test_env.admin, &0, &10);
+    assert_eq!(client.get_groups_by_creator(&creator).len(), 3);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_backfill_is_admin_only() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let stranger = test_env.users.get(1).unwrap();
+
+    client.backfill_creator_index(&stranger, &0, &10);
+}
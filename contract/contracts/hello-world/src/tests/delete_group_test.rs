@@ -247,10 +247,10 @@ fn test_delete_group_preserves_payment_history() {
     create_test_group(&env, &contract_id, &token_id, &creator, group_id.clone());
 
     // Verify payment history exists
-    let history_before = client.get_group_payment_history(&group_id);
+    let history_before = client.get_group_payment_history(&group_id, &0, &50);
     assert_eq!(history_before.len(), 1);
 
-    let user_history_before = client.get_user_payment_history(&creator);
+    let user_history_before = client.get_user_payment_history(&creator, &0, &50);
     assert_eq!(user_history_before.len(), 1);
 
     // Deactivate and delete the group
@@ -261,10 +261,10 @@ fn test_delete_group_preserves_payment_history() {
     client.delete_group(&group_id, &creator);
 
     // Verify payment history is preserved
-    let history_after = client.get_group_payment_history(&group_id);
+    let history_after = client.get_group_payment_history(&group_id, &0, &50);
     assert_eq!(history_after.len(), 1);
 
-    let user_history_after = client.get_user_payment_history(&creator);
+    let user_history_after = client.get_user_payment_history(&creator, &0, &50);
     assert_eq!(user_history_after.len(), 1);
 }
 
@@ -358,3 +358,32 @@ fn test_delete_group_when_paused() {
     // Try to delete - should fail
     client.delete_group(&group_id, &creator);
 }
+
+#[test]
+#[should_panic(expected = "AlreadyExists")]
+fn test_deleted_group_id_cannot_be_reused() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let contract_id = deploy_autoshare_contract(&env, &admin);
+    let token_name = String::from_str(&env, "Test Token");
+    let token_symbol = String::from_str(&env, "TEST");
+    let token_id = deploy_mock_token(&env, &token_name, &token_symbol);
+
+    let client = crate::AutoShareContractClient::new(&env, &contract_id);
+
+    // Initialize admin and add supported token
+    client.initialize_admin(&admin);
+    client.add_supported_token(&token_id, &admin);
+
+    // Create and delete a group
+    let group_id = BytesN::from_array(&env, &[12u8; 32]);
+    create_test_group(&env, &contract_id, &token_id, &creator, group_id.clone());
+    client.deactivate_group(&group_id, &creator);
+    client.delete_group(&group_id, &creator);
+
+    // Its statistics and earnings outlive it, so the id cannot be used again
+    create_test_group(&env, &contract_id, &token_id, &creator, group_id.clone());
+}
@@ -58,7 +58,7 @@ fn test_distribute_splits_payment_and_decrements_usage() {
     assert_eq!(remaining, usages - 1);
 
     // Verify distribution history
-    let group_distributions = client.get_group_distributions(&id);
+    let group_distributions = client.get_group_distributions(&id, &0, &50);
     assert_eq!(group_distributions.len(), 1);
     let dist = &group_distributions.get(0).unwrap();
     assert_eq!(dist.group_id, id);
@@ -73,7 +73,7 @@ fn test_distribute_splits_payment_and_decrements_usage() {
     // In a real test, you would query the event log if supported by the test framework.
 
     // Verify member distributions
-    let member1_dists = client.get_member_distributions(&member1);
+    let member1_dists = client.get_member_distributions(&member1, &0, &50);
     assert_eq!(member1_dists.len(), 1);
     let record = member1_dists.get(0).unwrap();
     assert_eq!(record.group_id, id);
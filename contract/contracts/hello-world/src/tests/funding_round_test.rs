@@ -0,0 +1,273 @@
+use crate::test_utils::{
+    assert_balance, create_test_group, create_test_members, fund_user_with_tokens, setup_test_env,
+    TestEnv,
+};
+use crate::AutoShareContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN};
+
+fn setup_campaign(test_env: &TestEnv, usages: u32) -> BytesN<32> {
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let members = create_test_members(&test_env.env, 2);
+    let group_id = create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        &creator,
+        &members,
+        usages,
+        &token,
+    );
+    client.start_fundraising(&group_id, &creator, &1_000_000);
+    group_id
+}
+
+fn contribute_from_new_user(test_env: &TestEnv, group_id: &BytesN<32>, amount: i128) {
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let contributor = Address::generate(&test_env.env);
+    fund_user_with_tokens(&test_env.env, &token, &contributor, amount);
+    client.contribute(group_id, &token, &amount, &contributor);
+}
+
+/// Opens a round with a 1000 pool where group A has four 100 contributors and group B two.
+fn setup_round(test_env: &TestEnv) -> (u32, BytesN<32>, BytesN<32>, Address) {
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let sponsor = Address::generate(&test_env.env);
+
+    let group_a = setup_campaign(test_env, 10);
+    let group_b = setup_campaign(test_env, 11);
+
+    fund_user_with_tokens(&test_env.env, &token, &sponsor, 1000);
+    let round_id = client.create_funding_round(&sponsor, &token, &1000, &0, &100);
+    client.join_funding_round(&round_id, &group_a, &creator);
+    client.join_funding_round(&round_id, &group_b, &creator);
+
+    test_env.env.ledger().set_timestamp(10);
+    for _ in 0..4 {
+        contribute_from_new_user(test_env, &group_a, 100);
+    }
+    for _ in 0..2 {
+        contribute_from_new_user(test_env, &group_b, 100);
+    }
+    (round_id, group_a, group_b, sponsor)
+}
+
+#[test]
+fn test_quadratic_allocation_favours_broad_support() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let sponsor = Address::generate(&test_env.env);
+
+    let group_a = setup_campaign(&test_env, 10);
+    let group_b = setup_campaign(&test_env, 11);
+
+    fund_user_with_tokens(&test_env.env, &token, &sponsor, 1000);
+    let round_id = client.create_funding_round(&sponsor, &token, &1000, &0, &100);
+    client.join_funding_round(&round_id, &group_a, &creator);
+    client.join_funding_round(&round_id, &group_b, &creator);
+
+    test_env.env.ledger().set_timestamp(10);
+    for _ in 0..4 {
+        contribute_from_new_user(&test_env, &group_a, 100);
+    }
+    for _ in 0..2 {
+        contribute_from_new_user(&test_env, &group_b, 100);
+    }
+
+    let tally_a = client.get_round_group_tally(&round_id, &group_a);
+    assert_eq!(tally_a.sum_sqrt, 40);
+    assert_eq!(tally_a.total_contributed, 400);
+    assert_eq!(tally_a.contributor_count, 4);
+
+    test_env.env.ledger().set_timestamp(101);
+    let total_matched = client.finalize_funding_round(&round_id);
+
+    // Weights: A = 40^2 - 400 = 1200, B = 20^2 - 200 = 200
+    assert_eq!(
+        client
+            .get_round_group_tally(&round_id, &group_a)
+            .matched_amount,
+        857
+    );
+    assert_eq!(
+        client
+            .get_round_group_tally(&round_id, &group_b)
+            .matched_amount,
+        142
+    );
+    assert_eq!(total_matched, 999);
+
+    // Rounding dust goes back to the sponsor
+    assert_balance(&test_env.env, &token, &sponsor, 1);
+    assert!(client.get_funding_round(&round_id).is_finalized);
+}
+
+#[test]
+fn test_repeat_contributions_count_once_per_contributor() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let contributor = test_env.users.get(1).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let sponsor = Address::generate(&test_env.env);
+
+    let group_id = setup_campaign(&test_env, 10);
+    fund_user_with_tokens(&test_env.env, &token, &sponsor, 1000);
+    let round_id = client.create_funding_round(&sponsor, &token, &1000, &0, &100);
+    client.join_funding_round(&round_id, &group_id, &creator);
+
+    fund_user_with_tokens(&test_env.env, &token, &contributor, 100);
+    client.contribute(&group_id, &token, &36, &contributor);
+    client.contribute(&group_id, &token, &64, &contributor);
+
+    let tally = client.get_round_group_tally(&round_id, &group_id);
+    assert_eq!(tally.contributor_count, 1);
+    assert_eq!(tally.sum_sqrt, 10);
+    assert_eq!(tally.total_contributed, 100);
+}
+
+#[test]
+fn test_contributions_outside_window_are_not_tallied() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let sponsor = Address::generate(&test_env.env);
+
+    let group_id = setup_campaign(&test_env, 10);
+    fund_user_with_tokens(&test_env.env, &token, &sponsor, 1000);
+    let round_id = client.create_funding_round(&sponsor, &token, &1000, &50, &100);
+    client.join_funding_round(&round_id, &group_id, &creator);
+
+    contribute_from_new_user(&test_env, &group_id, 100);
+    assert_eq!(
+        client
+            .get_round_group_tally(&round_id, &group_id)
+            .contributor_count,
+        0
+    );
+
+    // Without any qualifying support the whole pool returns to the sponsor
+    test_env.env.ledger().set_timestamp(101);
+    assert_eq!(client.finalize_funding_round(&round_id), 0);
+    assert_balance(&test_env.env, &token, &sponsor, 1000);
+}
+
+#[test]
+fn test_claim_pays_each_group_separately() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (round_id, group_a, group_b, _) = setup_round(&test_env);
+
+    test_env.env.ledger().set_timestamp(101);
+    client.finalize_funding_round(&round_id);
+
+    // Finalizing only allocates, each group is paid by its own claim
+    assert!(!client.get_round_group_tally(&round_id, &group_a).claimed);
+    assert_eq!(client.claim_round_match(&round_id, &group_a), 857);
+    assert!(client.get_round_group_tally(&round_id, &group_a).claimed);
+    assert!(!client.get_round_group_tally(&round_id, &group_b).claimed);
+
+    assert_eq!(client.claim_round_match(&round_id, &group_b), 142);
+}
+
+#[test]
+#[should_panic(expected = "AlreadyClaimed")]
+fn test_claim_twice_fails() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (round_id, group_a, _, _) = setup_round(&test_env);
+
+    test_env.env.ledger().set_timestamp(101);
+    client.finalize_funding_round(&round_id);
+    client.claim_round_match(&round_id, &group_a);
+    client.claim_round_match(&round_id, &group_a);
+}
+
+#[test]
+#[should_panic(expected = "RoundNotEnded")]
+fn test_claim_before_finalize_fails() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (round_id, group_a, _, _) = setup_round(&test_env);
+
+    test_env.env.ledger().set_timestamp(101);
+    client.claim_round_match(&round_id, &group_a);
+}
+
+#[test]
+fn test_inactive_group_share_stays_in_pool() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let (round_id, group_a, group_b, sponsor) = setup_round(&test_env);
+
+    client.deactivate_group(&group_b, &creator);
+
+    test_env.env.ledger().set_timestamp(101);
+    assert_eq!(client.finalize_funding_round(&round_id), 1000);
+    assert_eq!(
+        client
+            .get_round_group_tally(&round_id, &group_b)
+            .matched_amount,
+        0
+    );
+    assert_eq!(client.claim_round_match(&round_id, &group_a), 1000);
+    assert_balance(&test_env.env, &token, &sponsor, 0);
+    assert!(client.try_claim_round_match(&round_id, &group_b).is_err());
+}
+
+#[test]
+fn test_share_of_group_deactivated_after_finalize_returns_to_sponsor() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let (round_id, group_a, group_b, sponsor) = setup_round(&test_env);
+
+    test_env.env.ledger().set_timestamp(101);
+    client.finalize_funding_round(&round_id);
+    client.deactivate_group(&group_b, &creator);
+
+    // The failed payout of B leaves A's claim untouched
+    assert_eq!(client.claim_round_match(&round_id, &group_b), 0);
+    assert_balance(&test_env.env, &token, &sponsor, 1 + 142);
+    assert_eq!(client.claim_round_match(&round_id, &group_a), 857);
+}
+
+#[test]
+#[should_panic(expected = "RoundNotEnded")]
+fn test_finalize_before_end_fails() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let sponsor = Address::generate(&test_env.env);
+
+    fund_user_with_tokens(&test_env.env, &token, &sponsor, 1000);
+    let round_id = client.create_funding_round(&sponsor, &token, &1000, &0, &100);
+    client.finalize_funding_round(&round_id);
+}
+
+#[test]
+#[should_panic(expected = "RoundClosed")]
+fn test_join_after_round_end_fails() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let sponsor = Address::generate(&test_env.env);
+
+    let group_id = setup_campaign(&test_env, 10);
+    fund_user_with_tokens(&test_env.env, &token, &sponsor, 1000);
+    let round_id = client.create_funding_round(&sponsor, &token, &1000, &0, &100);
+
+    test_env.env.ledger().set_timestamp(101);
+    client.join_funding_round(&round_id, &group_id, &creator);
+}
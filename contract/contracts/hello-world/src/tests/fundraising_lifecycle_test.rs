@@ -0,0 +1,202 @@
+use crate::autoshare_logic::{DataKey, LegacyFundraisingConfig};
+use crate::test_utils::{create_test_group, create_test_members, setup_test_env, TestEnv};
+use crate::AutoShareContractClient;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{Address, BytesN};
+
+fn setup_campaign(test_env: &TestEnv, target_amount: i128) -> (BytesN<32>, Address, Address) {
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let members = create_test_members(&test_env.env, 2);
+    let group_id = create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        &creator,
+        &members,
+        10,
+        &token,
+    );
+    client.start_fundraising(&group_id, &creator, &target_amount);
+    (group_id, creator, token)
+}
+
+#[test]
+fn test_close_fundraising_early() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, token) = setup_campaign(&test_env, 1000);
+    let contributor = test_env.users.get(1).unwrap();
+
+    crate::test_utils::fund_user_with_tokens(&test_env.env, &token, &contributor, 400);
+    client.contribute(&group_id, &token, &400, &contributor);
+
+    client.close_fundraising(&group_id, &creator);
+
+    let status = client.get_fundraising_status(&group_id);
+    assert!(!status.is_active);
+    assert!(!status.is_cancelled);
+    assert_eq!(status.total_raised, 400);
+
+    // A new campaign can be started once the previous one is closed
+    client.start_fundraising(&group_id, &creator, &500);
+    assert!(client.get_fundraising_status(&group_id).is_active);
+}
+
+#[test]
+fn test_cancel_fundraising() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, _token) = setup_campaign(&test_env, 1000);
+
+    client.cancel_fundraising(&group_id, &creator);
+
+    let status = client.get_fundraising_status(&group_id);
+    assert!(!status.is_active);
+    assert!(status.is_cancelled);
+}
+
+#[test]
+#[should_panic(expected = "FundraisingNotActive")]
+fn test_cancel_closed_fundraising_fails() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, _token) = setup_campaign(&test_env, 1000);
+
+    client.close_fundraising(&group_id, &creator);
+    client.cancel_fundraising(&group_id, &creator);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_close_fundraising_non_creator_fails() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, _creator, _token) = setup_campaign(&test_env, 1000);
+    let stranger = test_env.users.get(1).unwrap();
+
+    client.close_fundraising(&group_id, &stranger);
+}
+
+#[test]
+fn test_extend_fundraising_deadline() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, _token) = setup_campaign(&test_env, 1000);
+
+    test_env.env.ledger().set_timestamp(100);
+    client.extend_fundraising_deadline(&group_id, &creator, &200);
+    assert_eq!(client.get_fundraising_status(&group_id).deadline, 200);
+
+    client.extend_fundraising_deadline(&group_id, &creator, &500);
+    let status = client.get_fundraising_status(&group_id);
+    assert_eq!(status.deadline, 500);
+    assert!(status.is_active);
+
+    // Past the deadline the campaign is reported as inactive
+    test_env.env.ledger().set_timestamp(501);
+    assert!(!client.get_fundraising_status(&group_id).is_active);
+}
+
+#[test]
+#[should_panic(expected = "InvalidDeadline")]
+fn test_extend_fundraising_deadline_cannot_shorten() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, _token) = setup_campaign(&test_env, 1000);
+
+    test_env.env.ledger().set_timestamp(100);
+    client.extend_fundraising_deadline(&group_id, &creator, &500);
+    client.extend_fundraising_deadline(&group_id, &creator, &300);
+}
+
+#[test]
+#[should_panic(expected = "FundraisingExpired")]
+fn test_contribute_after_deadline_fails() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, token) = setup_campaign(&test_env, 1000);
+    let contributor = test_env.users.get(1).unwrap();
+
+    client.extend_fundraising_deadline(&group_id, &creator, &100);
+    test_env.env.ledger().set_timestamp(101);
+
+    crate::test_utils::fund_user_with_tokens(&test_env.env, &token, &contributor, 100);
+    client.contribute(&group_id, &token, &100, &contributor);
+}
+
+#[test]
+fn test_update_fundraising_target() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, token) = setup_campaign(&test_env, 1000);
+    let contributor = test_env.users.get(1).unwrap();
+
+    crate::test_utils::fund_user_with_tokens(&test_env.env, &token, &contributor, 600);
+    client.contribute(&group_id, &token, &600, &contributor);
+
+    client.update_fundraising_target(&group_id, &creator, &2000);
+    let status = client.get_fundraising_status(&group_id);
+    assert_eq!(status.target_amount, 2000);
+    assert!(status.is_active);
+
+    // Lowering the target to the amount already raised completes the campaign
+    client.update_fundraising_target(&group_id, &creator, &600);
+    let status = client.get_fundraising_status(&group_id);
+    assert_eq!(status.target_amount, 600);
+    assert!(!status.is_active);
+}
+
+#[test]
+#[should_panic(expected = "InvalidAmount")]
+fn test_update_fundraising_target_below_raised_fails() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, token) = setup_campaign(&test_env, 1000);
+    let contributor = test_env.users.get(1).unwrap();
+
+    crate::test_utils::fund_user_with_tokens(&test_env.env, &token, &contributor, 600);
+    client.contribute(&group_id, &token, &600, &contributor);
+
+    client.update_fundraising_target(&group_id, &creator, &500);
+}
+
+#[test]
+fn test_legacy_fundraising_config_is_upgraded_on_read() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let group_id = create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        &creator,
+        &create_test_members(&test_env.env, 2),
+        10,
+        &token,
+    );
+
+    // A campaign stored before deadlines and overfunding policies existed
+    test_env.env.as_contract(&test_env.autoshare_contract, || {
+        test_env.env.storage().persistent().set(
+            &DataKey::GroupFundraising(group_id.clone()),
+            &LegacyFundraisingConfig {
+                target_amount: 1000,
+                total_raised: 100,
+                is_active: true,
+            },
+        );
+    });
+
+    let status = client.get_fundraising_status(&group_id);
+    assert!(status.is_active);
+    assert_eq!(status.total_raised, 100);
+    assert_eq!(status.deadline, 0);
+    assert_eq!(status.campaign_id, 1);
+
+    let contributor = test_env.users.get(1).unwrap();
+    crate::test_utils::fund_user_with_tokens(&test_env.env, &token, &contributor, 400);
+    client.contribute(&group_id, &token, &400, &contributor);
+    assert_eq!(client.get_fundraising_status(&group_id).total_raised, 500);
+    assert_eq!(client.get_fundraising_remaining(&group_id), 500);
+}
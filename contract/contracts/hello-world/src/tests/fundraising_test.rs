@@ -30,6 +30,11 @@ fn test_get_fundraising_status_existing() {
         target_amount: 1000,
         total_raised: 500,
         is_active: true,
+        deadline: 0,
+        is_cancelled: false,
+        overfunding_policy: crate::base::types::OverfundingPolicy::CloseAtTarget,
+        hard_cap: 0,
+        campaign_id: 1,
     };
 
     let key = DataKey::GroupFundraising(group_id.clone());
@@ -1,4 +1,5 @@
-use crate::{base::types::GroupMember, test_utils::setup_test_env, AutoShareContractClient};
+use crate::base::types::{GroupMember, RebalanceStrategy};
+use crate::{test_utils::setup_test_env, AutoShareContractClient};
 use soroban_sdk::{BytesN, String, Vec};
 
 #[test]
@@ -36,13 +37,13 @@ fn test_get_groups_by_member() {
     assert_eq!(groups.len(), 0);
 
     // Add member1 to group 1
-    client.add_group_member(&id1, &creator1, &member1, &100);
+    client.add_group_member(&id1, &creator1, &member1, &100, &RebalanceStrategy::Exact);
     let groups = client.get_groups_by_member(&member1);
     assert_eq!(groups.len(), 1);
     assert_eq!(groups.get(0).unwrap().id, id1);
 
     // Add member1 to group 2
-    client.add_group_member(&id2, &creator2, &member1, &100);
+    client.add_group_member(&id2, &creator2, &member1, &100, &RebalanceStrategy::Exact);
     let groups = client.get_groups_by_member(&member1);
     assert_eq!(groups.len(), 2);
 
@@ -66,7 +67,7 @@ fn test_get_groups_by_member() {
     assert_eq!(m1_groups.get(0).unwrap().id, id2);
 
     // Remove member1 from group 2 via remove_group_member
-    client.remove_group_member(&id2, &creator2, &member1);
+    client.remove_group_member(&id2, &creator2, &member1, &RebalanceStrategy::Exact);
     let m1_groups_final = client.get_groups_by_member(&member1);
     assert_eq!(m1_groups_final.len(), 0);
 
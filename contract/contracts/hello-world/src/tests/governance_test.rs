@@ -0,0 +1,249 @@
+use crate::base::types::{
+    GovernanceConfig, GroupMember, ProposalAction, ProposalStatus, RebalanceStrategy,
+};
+use crate::test_utils::{create_test_group, setup_test_env, TestEnv};
+use crate::AutoShareContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN, Vec};
+
+const VOTING_PERIOD: u64 = 100;
+
+/// Creates a governed group split 50/30/20 between three members.
+fn setup_governed_group(test_env: &TestEnv) -> (BytesN<32>, Address, Vec<Address>) {
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+
+    let mut addresses = Vec::new(&test_env.env);
+    let mut members = Vec::new(&test_env.env);
+    for percentage in [50u32, 30, 20] {
+        let address = Address::generate(&test_env.env);
+        addresses.push_back(address.clone());
+        members.push_back(GroupMember {
+            address,
+            percentage,
+        });
+    }
+    let group_id = create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        &creator,
+        &members,
+        10,
+        &token,
+    );
+
+    client.set_governance(
+        &group_id,
+        &creator,
+        &GovernanceConfig {
+            enabled: true,
+            quorum: 50,
+            threshold: 60,
+            voting_period: VOTING_PERIOD,
+        },
+    );
+    (group_id, creator, addresses)
+}
+
+fn even_split(test_env: &TestEnv, addresses: &Vec<Address>) -> Vec<GroupMember> {
+    let mut members = Vec::new(&test_env.env);
+    members.push_back(GroupMember {
+        address: addresses.get(0).unwrap(),
+        percentage: 34,
+    });
+    members.push_back(GroupMember {
+        address: addresses.get(1).unwrap(),
+        percentage: 33,
+    });
+    members.push_back(GroupMember {
+        address: addresses.get(2).unwrap(),
+        percentage: 33,
+    });
+    members
+}
+
+#[test]
+fn test_direct_member_changes_blocked_in_governance_mode() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, addresses) = setup_governed_group(&test_env);
+
+    assert!(client
+        .try_update_members(&group_id, &creator, &even_split(&test_env, &addresses))
+        .is_err());
+    assert!(client
+        .try_remove_group_member(
+            &group_id,
+            &creator,
+            &addresses.get(2).unwrap(),
+            &RebalanceStrategy::Exact
+        )
+        .is_err());
+    assert!(client
+        .try_add_group_member(
+            &group_id,
+            &creator,
+            &Address::generate(&test_env.env),
+            &10,
+            &RebalanceStrategy::Exact
+        )
+        .is_err());
+}
+
+#[test]
+fn test_weighted_vote_executes_after_period() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, addresses) = setup_governed_group(&test_env);
+    let new_split = even_split(&test_env, &addresses);
+
+    let proposal_id = client.propose_change(
+        &group_id,
+        &creator,
+        &ProposalAction::UpdateMembers(new_split.clone()),
+    );
+    client.vote(&proposal_id, &addresses.get(1).unwrap(), &true);
+    client.vote(&proposal_id, &addresses.get(2).unwrap(), &true);
+
+    let proposal = client.get_proposal(&proposal_id);
+    assert_eq!(proposal.votes_for, 50);
+    assert!(client.try_execute_proposal(&proposal_id).is_err());
+
+    test_env.env.ledger().set_timestamp(VOTING_PERIOD);
+    assert!(client.execute_proposal(&proposal_id));
+
+    assert_eq!(client.get_group_members(&group_id), new_split);
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        ProposalStatus::Executed
+    );
+}
+
+#[test]
+fn test_proposal_rejected_below_threshold() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, _creator, addresses) = setup_governed_group(&test_env);
+    let largest = addresses.get(0).unwrap();
+
+    let proposal_id = client.propose_change(
+        &group_id,
+        &addresses.get(2).unwrap(),
+        &ProposalAction::RemoveMember(largest.clone(), RebalanceStrategy::Exact),
+    );
+    client.vote(&proposal_id, &largest, &false);
+    client.vote(&proposal_id, &addresses.get(2).unwrap(), &true);
+
+    test_env.env.ledger().set_timestamp(VOTING_PERIOD);
+    assert!(!client.execute_proposal(&proposal_id));
+
+    assert!(client.is_group_member(&group_id, &largest));
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        ProposalStatus::Rejected
+    );
+}
+
+#[test]
+fn test_decisive_majority_executes_early() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, addresses) = setup_governed_group(&test_env);
+
+    let proposal_id = client.propose_change(
+        &group_id,
+        &creator,
+        &ProposalAction::SetGovernance(GovernanceConfig {
+            enabled: false,
+            quorum: 0,
+            threshold: 0,
+            voting_period: 0,
+        }),
+    );
+    client.vote(&proposal_id, &addresses.get(0).unwrap(), &true);
+    client.vote(&proposal_id, &addresses.get(1).unwrap(), &true);
+
+    assert!(client.execute_proposal(&proposal_id));
+    assert!(!client.get_governance_config(&group_id).enabled);
+
+    // Governance is off again, so the creator can edit members directly
+    client.update_members(&group_id, &creator, &even_split(&test_env, &addresses));
+    assert_eq!(client.get_group_proposals(&group_id).len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "AlreadyExists")]
+fn test_member_cannot_vote_twice() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, addresses) = setup_governed_group(&test_env);
+    let voter = addresses.get(1).unwrap();
+
+    let proposal_id = client.propose_change(
+        &group_id,
+        &creator,
+        &ProposalAction::RemoveMember(addresses.get(2).unwrap(), RebalanceStrategy::Exact),
+    );
+    client.vote(&proposal_id, &voter, &true);
+    client.vote(&proposal_id, &voter, &true);
+}
+
+#[test]
+#[should_panic(expected = "GovernanceRequired")]
+fn test_creator_cannot_disable_governance_directly() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, _addresses) = setup_governed_group(&test_env);
+
+    client.set_governance(
+        &group_id,
+        &creator,
+        &GovernanceConfig {
+            enabled: false,
+            quorum: 0,
+            threshold: 1,
+            voting_period: 1,
+        },
+    );
+}
+
+#[test]
+fn test_self_service_changes_need_proposal() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, _creator, addresses) = setup_governed_group(&test_env);
+    let member = addresses.get(1).unwrap();
+    let second_address = Address::generate(&test_env.env);
+
+    // Member changes in a governed group go through proposals
+    assert!(client
+        .try_update_payout_address(&group_id, &member, &second_address)
+        .is_err());
+    assert!(client.try_leave_group(&group_id, &member).is_err());
+    assert_eq!(client.get_group_members(&group_id).len(), 3);
+}
+
+#[test]
+fn test_unappliable_proposal_is_marked_failed() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, addresses) = setup_governed_group(&test_env);
+    let newcomer = Address::generate(&test_env.env);
+
+    // Adding a share without shrinking the others cannot keep the split at 100%
+    let proposal_id = client.propose_change(
+        &group_id,
+        &creator,
+        &ProposalAction::AddMember(newcomer.clone(), 10, RebalanceStrategy::Exact),
+    );
+    client.vote(&proposal_id, &addresses.get(0).unwrap(), &true);
+    client.vote(&proposal_id, &addresses.get(1).unwrap(), &true);
+
+    assert!(!client.execute_proposal(&proposal_id));
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        ProposalStatus::Failed
+    );
+    assert!(!client.is_group_member(&group_id, &newcomer));
+}
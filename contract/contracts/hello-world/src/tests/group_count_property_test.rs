@@ -1,3 +1,4 @@
+use crate::base::types::GroupFilter;
 use crate::test_utils::{create_test_group, setup_test_env};
 use crate::AutoShareContractClient;
 use quickcheck::TestResult;
@@ -49,7 +50,7 @@ mod property_tests {
         TestResult::from_bool(actual_count == group_count as u32)
     }
 
-    // Feature: get-group-count-function, Property 2: Consistency with get_groups_paginated
+    // Feature: get-group-count-function, Property 2: Consistency with query_groups
     // Validates: Requirements 4.1, 5.3, 5.5
     #[quickcheck]
     fn prop_consistent_with_paginated(group_count: u8, page_size: u8) -> TestResult {
@@ -84,10 +85,27 @@ mod property_tests {
 
         // Get count from both methods
         let count_direct = client.get_group_count();
-        let page = client.get_groups_paginated(&0, &(page_size as u32));
+        let filter = GroupFilter {
+            is_active: None,
+            creator: None,
+            member: None,
+            fundraising_active: false,
+            usages_below: None,
+            token: None,
+        };
+        let mut count_queried = 0;
+        let mut cursor = None;
+        loop {
+            let page = client.query_groups(&filter, &cursor, &(page_size as u32));
+            count_queried += page.groups.len();
+            cursor = Some(page.next_cursor.clone());
+            if page.next_cursor.remaining == 0 {
+                break;
+            }
+        }
 
         // Verify consistency
-        TestResult::from_bool(count_direct == page.total)
+        TestResult::from_bool(count_direct == count_queried)
     }
 
     // Feature: get-group-count-function, Property 3: Count updates after create/delete
@@ -0,0 +1,141 @@
+use crate::base::types::{GroupMember, ManagerScope};
+use crate::test_utils::{create_test_group, create_test_members, setup_test_env, TestEnv};
+use crate::AutoShareContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
+
+fn setup_group(test_env: &TestEnv) -> (BytesN<32>, Address) {
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let members = create_test_members(&test_env.env, 2);
+    let group_id = create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        &creator,
+        &members,
+        10,
+        &token,
+    );
+    (group_id, creator)
+}
+
+fn scope(manage_members: bool, fundraising: bool, activation: bool) -> ManagerScope {
+    ManagerScope {
+        manage_members,
+        change_splits: false,
+        rename: false,
+        fundraising,
+        activation,
+    }
+}
+
+fn single_member(env: &Env) -> Vec<GroupMember> {
+    let mut members = Vec::new(env);
+    members.push_back(GroupMember {
+        address: Address::generate(env),
+        percentage: 100,
+    });
+    members
+}
+
+#[test]
+fn test_manager_acts_within_scope() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator) = setup_group(&test_env);
+    let manager = Address::generate(&test_env.env);
+
+    client.set_manager(&group_id, &creator, &manager, &scope(false, true, true));
+
+    client.start_fundraising(&group_id, &manager, &1000);
+    assert!(client.get_fundraising_status(&group_id).is_active);
+    client.close_fundraising(&group_id, &manager);
+
+    client.deactivate_group(&group_id, &manager);
+    assert!(!client.is_group_active(&group_id));
+    client.activate_group(&group_id, &manager);
+    assert!(client.is_group_active(&group_id));
+}
+
+#[test]
+fn test_manager_blocked_outside_scope() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator) = setup_group(&test_env);
+    let manager = Address::generate(&test_env.env);
+
+    client.set_manager(&group_id, &creator, &manager, &scope(true, false, false));
+
+    let new_name = String::from_str(&test_env.env, "Renamed");
+    assert!(client
+        .try_update_group_name(&group_id, &manager, &new_name)
+        .is_err());
+    assert!(client
+        .try_update_members(&group_id, &manager, &single_member(&test_env.env))
+        .is_err());
+    assert!(client.try_deactivate_group(&group_id, &manager).is_err());
+    assert!(client
+        .try_start_fundraising(&group_id, &manager, &1000)
+        .is_err());
+
+    // Member management is in scope
+    let invitee = Address::generate(&test_env.env);
+    client.invite_member(&group_id, &manager, &invitee, &10, &1000);
+    assert_eq!(client.get_pending_invites(&group_id).len(), 1);
+}
+
+#[test]
+fn test_update_scope_and_remove_manager() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator) = setup_group(&test_env);
+    let manager = Address::generate(&test_env.env);
+
+    client.set_manager(&group_id, &creator, &manager, &scope(false, false, false));
+    let mut split_scope = scope(false, false, false);
+    split_scope.change_splits = true;
+    client.set_manager(&group_id, &creator, &manager, &split_scope);
+
+    let managers = client.get_managers(&group_id);
+    assert_eq!(managers.len(), 1);
+    assert_eq!(managers.get(0).unwrap().scope, split_scope);
+
+    client.update_members(&group_id, &manager, &single_member(&test_env.env));
+    assert_eq!(client.get_group_members(&group_id).len(), 1);
+
+    client.remove_manager(&group_id, &creator, &manager);
+    assert_eq!(client.get_managers(&group_id).len(), 0);
+    assert!(client
+        .try_update_members(&group_id, &manager, &single_member(&test_env.env))
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_manager_cannot_appoint_managers() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator) = setup_group(&test_env);
+    let manager = Address::generate(&test_env.env);
+    let other = Address::generate(&test_env.env);
+
+    client.set_manager(&group_id, &creator, &manager, &scope(true, true, true));
+    client.set_manager(&group_id, &manager, &other, &scope(true, true, true));
+}
+
+#[test]
+#[should_panic(expected = "MaxManagersExceeded")]
+fn test_manager_count_is_capped() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator) = setup_group(&test_env);
+
+    for _ in 0..=20 {
+        client.set_manager(
+            &group_id,
+            &creator,
+            &Address::generate(&test_env.env),
+            &scope(true, false, false),
+        );
+    }
+}
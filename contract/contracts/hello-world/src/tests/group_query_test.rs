@@ -0,0 +1,199 @@
+use crate::autoshare_logic::DataKey;
+use crate::base::types::{GroupCursor, GroupFilter, GroupMember};
+use crate::test_utils::{create_test_group, fund_user_with_tokens, setup_test_env, TestEnv};
+use crate::AutoShareContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Vec};
+
+fn no_filter() -> GroupFilter {
+    GroupFilter {
+        is_active: None,
+        creator: None,
+        member: None,
+        fundraising_active: false,
+        usages_below: None,
+        token: None,
+    }
+}
+
+fn create_group(
+    test_env: &TestEnv,
+    creator: &Address,
+    member: &Address,
+    usages: u32,
+) -> BytesN<32> {
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let mut members = Vec::new(&test_env.env);
+    members.push_back(GroupMember {
+        address: member.clone(),
+        percentage: 100,
+    });
+    create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        creator,
+        &members,
+        usages,
+        &token,
+    )
+}
+
+#[test]
+fn test_cursor_walks_all_groups() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let member = Address::generate(&test_env.env);
+    for usages in 1..=7 {
+        create_group(&test_env, &creator, &member, usages);
+    }
+
+    let first = client.query_groups(&no_filter(), &None, &3);
+    assert_eq!(first.groups.len(), 3);
+    assert_eq!(first.next_cursor.remaining, 4);
+
+    let mut seen = first.groups.len();
+    let mut cursor = first.next_cursor;
+    while cursor.remaining > 0 {
+        let page = client.query_groups(&no_filter(), &Some(cursor), &3);
+        seen += page.groups.len();
+        cursor = page.next_cursor;
+    }
+    assert_eq!(seen, 7);
+}
+
+/// Walks the rest of a query from `cursor`, collecting the ids it returns.
+fn walk_ids(
+    client: &AutoShareContractClient,
+    filter: &GroupFilter,
+    mut cursor: GroupCursor,
+    seen: &mut Vec<BytesN<32>>,
+) {
+    while cursor.remaining > 0 {
+        let page = client.query_groups(filter, &Some(cursor), &2);
+        for group in page.groups.iter() {
+            seen.push_back(group.id);
+        }
+        cursor = page.next_cursor;
+    }
+}
+
+#[test]
+fn test_deleting_mid_walk_skips_no_group() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let member = Address::generate(&test_env.env);
+    let mut ids = Vec::new(&test_env.env);
+    for usages in 1..=6 {
+        ids.push_back(create_group(&test_env, &creator, &member, usages));
+    }
+
+    // Registry walk: deleting an unvisited group moves a visited one into its slot
+    let first = client.query_groups(&no_filter(), &None, &2);
+    let mut seen = Vec::new(&test_env.env);
+    for group in first.groups.iter() {
+        seen.push_back(group.id);
+    }
+    let deleted = ids.get(1).unwrap();
+    client.deactivate_group(&deleted, &creator);
+    client.delete_group(&deleted, &creator);
+    walk_ids(&client, &no_filter(), first.next_cursor, &mut seen);
+    for id in ids.iter() {
+        assert_eq!(seen.contains(&id), id != deleted);
+    }
+
+    // Index walk: deleting an unvisited group shifts the visited ones down
+    let mut filter = no_filter();
+    filter.member = Some(member.clone());
+    let first = client.query_groups(&filter, &None, &2);
+    let mut seen = Vec::new(&test_env.env);
+    for group in first.groups.iter() {
+        seen.push_back(group.id);
+    }
+    let deleted_too = ids.get(0).unwrap();
+    client.deactivate_group(&deleted_too, &creator);
+    client.delete_group(&deleted_too, &creator);
+    walk_ids(&client, &filter, first.next_cursor, &mut seen);
+    assert_eq!(seen.len(), 4);
+    for id in ids.iter() {
+        assert_eq!(seen.contains(&id), id != deleted && id != deleted_too);
+    }
+}
+
+#[test]
+fn test_token_index_is_backfilled_from_history() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let creator = test_env.users.get(0).unwrap();
+    let id = create_group(&test_env, &creator, &Address::generate(&test_env.env), 1);
+    fund_user_with_tokens(&test_env.env, &token, &creator, 100);
+    client.distribute(&id, &token, &100, &creator);
+
+    // Rewind storage to before the token index existed
+    test_env.env.as_contract(&test_env.autoshare_contract, || {
+        test_env
+            .env
+            .storage()
+            .persistent()
+            .remove(&DataKey::GroupTokens(id.clone()));
+    });
+    let mut filter = no_filter();
+    filter.token = Some(token.clone());
+    assert_eq!(client.query_groups(&filter, &None, &10).groups.len(), 0);
+
+    assert_eq!(
+        client.backfill_group_tokens(&test_env.admin, &id, &0, &10),
+        1
+    );
+    let page = client.query_groups(&filter, &None, &10);
+    assert_eq!(page.groups.len(), 1);
+    assert_eq!(page.groups.get(0).unwrap().id, id);
+}
+
+#[test]
+fn test_filters_combine() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let creator = test_env.users.get(0).unwrap();
+    let other_creator = test_env.users.get(1).unwrap();
+    let member = Address::generate(&test_env.env);
+    let stranger = Address::generate(&test_env.env);
+
+    let few_usages = create_group(&test_env, &creator, &member, 2);
+    let inactive = create_group(&test_env, &creator, &member, 20);
+    let fundraising = create_group(&test_env, &other_creator, &member, 30);
+    let unrelated = create_group(&test_env, &other_creator, &stranger, 40);
+
+    client.deactivate_group(&inactive, &creator);
+    client.start_fundraising(&fundraising, &other_creator, &1000);
+    fund_user_with_tokens(&test_env.env, &token, &creator, 100);
+    client.distribute(&unrelated, &token, &100, &creator);
+
+    let mut filter = no_filter();
+    filter.is_active = Some(true);
+    filter.member = Some(member.clone());
+    let page = client.query_groups(&filter, &None, &10);
+    assert_eq!(page.groups.len(), 2);
+    assert_eq!(page.next_cursor.remaining, 0);
+
+    filter.usages_below = Some(5);
+    let page = client.query_groups(&filter, &None, &10);
+    assert_eq!(page.groups.get(0).unwrap().id, few_usages);
+    assert_eq!(page.groups.len(), 1);
+
+    let mut filter = no_filter();
+    filter.fundraising_active = true;
+    let page = client.query_groups(&filter, &None, &10);
+    assert_eq!(page.groups.len(), 1);
+    assert_eq!(page.groups.get(0).unwrap().id, fundraising);
+
+    let mut filter = no_filter();
+    filter.creator = Some(other_creator.clone());
+    filter.token = Some(token.clone());
+    let page = client.query_groups(&filter, &None, &10);
+    assert_eq!(page.groups.len(), 1);
+    assert_eq!(page.groups.get(0).unwrap().id, unrelated);
+}
@@ -0,0 +1,94 @@
+use crate::autoshare_logic::{DataKey, RegistryKey};
+use crate::test_utils::{create_test_group, create_test_members, setup_test_env, TestEnv};
+use crate::AutoShareContractClient;
+use soroban_sdk::{BytesN, Vec};
+
+/// Creates groups with usage counts `first..first + count`, which also makes their ids distinct.
+fn create_groups(test_env: &TestEnv, first: u32, count: u32) -> Vec<BytesN<32>> {
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let mut ids = Vec::new(&test_env.env);
+    for usages in first..first + count {
+        ids.push_back(create_test_group(
+            &test_env.env,
+            &test_env.autoshare_contract,
+            &creator,
+            &create_test_members(&test_env.env, 2),
+            usages,
+            &token,
+        ));
+    }
+    ids
+}
+
+/// Reads registry slots `start..start + limit` straight from storage.
+fn slot_ids(test_env: &TestEnv, start: u32, limit: u32) -> Vec<BytesN<32>> {
+    let mut ids = Vec::new(&test_env.env);
+    test_env.env.as_contract(&test_env.autoshare_contract, || {
+        for index in start..start + limit {
+            if let Some(id) = test_env
+                .env
+                .storage()
+                .persistent()
+                .get(&RegistryKey::IdAt(index))
+            {
+                ids.push_back(id);
+            }
+        }
+    });
+    ids
+}
+
+#[test]
+fn test_delete_moves_last_group_into_freed_slot() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let ids = create_groups(&test_env, 1, 4);
+
+    let deleted = ids.get(1).unwrap();
+    client.deactivate_group(&deleted, &creator);
+    client.delete_group(&deleted, &creator);
+
+    assert_eq!(client.get_group_count(), 3);
+    assert_eq!(
+        slot_ids(&test_env, 0, 10),
+        Vec::from_array(
+            &test_env.env,
+            [
+                ids.get(0).unwrap(),
+                ids.get(3).unwrap(),
+                ids.get(2).unwrap()
+            ]
+        )
+    );
+
+    // The freed slot is reused by the next group
+    let newest = create_groups(&test_env, 5, 1).get(0).unwrap();
+    assert_eq!(client.get_group_count(), 4);
+    assert_eq!(
+        slot_ids(&test_env, 3, 1),
+        Vec::from_array(&test_env.env, [newest])
+    );
+}
+
+#[test]
+fn test_legacy_group_list_is_migrated() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let ids = create_groups(&test_env, 1, 2);
+    let legacy = Vec::from_array(&test_env.env, [ids.get(1).unwrap(), ids.get(0).unwrap()]);
+
+    // Rewind storage to the single-list layout used before the registry existed
+    test_env.env.as_contract(&test_env.autoshare_contract, || {
+        let storage = test_env.env.storage().persistent();
+        storage.remove(&RegistryKey::Count);
+        storage.set(&DataKey::AllGroups, &legacy);
+    });
+
+    assert_eq!(client.get_group_count(), 2);
+    assert_eq!(slot_ids(&test_env, 0, 10), legacy);
+    test_env.env.as_contract(&test_env.autoshare_contract, || {
+        assert!(!test_env.env.storage().persistent().has(&DataKey::AllGroups));
+    });
+}
@@ -0,0 +1,70 @@
+use crate::base::types::TokenAmount;
+use crate::test_utils::{
+    create_test_group, create_test_members, fund_user_with_tokens, setup_test_env,
+};
+use crate::AutoShareContractClient;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{BytesN, Vec};
+
+#[test]
+fn test_group_stats_aggregate_activity() {
+    let test_env = setup_test_env();
+    let env = &test_env.env;
+    let client = AutoShareContractClient::new(env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let sender = test_env.users.get(1).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let id = create_test_group(
+        env,
+        &test_env.autoshare_contract,
+        &creator,
+        &create_test_members(env, 2),
+        5,
+        &token,
+    );
+    fund_user_with_tokens(env, &token, &sender, 1000);
+    fund_user_with_tokens(env, &token, &creator, 1000);
+
+    client.distribute(&id, &token, &100, &sender);
+    client.distribute(&id, &token, &200, &sender);
+    client.distribute(&id, &token, &50, &creator);
+    client.start_fundraising(&id, &creator, &1000);
+    env.ledger().set_timestamp(777);
+    client.contribute(&id, &token, &40, &sender);
+
+    let stats = client.get_group_stats(&id);
+    assert_eq!(stats.distribution_count, 3);
+    assert_eq!(
+        stats.distributed,
+        Vec::from_array(
+            env,
+            [TokenAmount {
+                token: token.clone(),
+                amount: 350
+            }]
+        )
+    );
+    assert_eq!(stats.unique_senders, 2);
+    assert_eq!(stats.contribution_count, 1);
+    assert_eq!(
+        stats.raised,
+        Vec::from_array(
+            env,
+            [TokenAmount {
+                token: token.clone(),
+                amount: 40
+            }]
+        )
+    );
+    assert_eq!(stats.last_activity, 777);
+    assert_eq!(stats.usages_consumed, 3);
+}
+
+#[test]
+#[should_panic(expected = "NotFound")]
+fn test_group_stats_for_unknown_group() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+
+    client.get_group_stats(&BytesN::from_array(&test_env.env, &[9u8; 32]));
+}
@@ -0,0 +1,193 @@
+use crate::autoshare_logic::{
+    DataKey, EarningsKey, GovernanceKey, HistoryKey, HistoryLog, ReportKey, StatsKey,
+};
+use crate::base::types::{GovernanceConfig, GroupMember, ProposalAction, ReportInterval};
+use crate::test_utils::{
+    create_test_group, create_test_members, deploy_stellar_asset, fund_user_with_tokens,
+    mint_stellar_asset, setup_test_env,
+};
+use crate::AutoShareContractClient;
+use soroban_sdk::testutils::storage::Persistent as _;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, BytesN, IntoVal, Val, Vec};
+
+#[test]
+fn test_extend_group_ttl_reaches_every_entry() {
+    let test_env = setup_test_env();
+    let env = &test_env.env;
+    let client = AutoShareContractClient::new(env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let sender = test_env.users.get(1).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let members = create_test_members(env, 2);
+    let member = members.get(0).unwrap().address;
+    let id = create_test_group(
+        env,
+        &test_env.autoshare_contract,
+        &creator,
+        &members,
+        5,
+        &token,
+    );
+    fund_user_with_tokens(env, &token, &sender, 1000);
+    client.distribute(&id, &token, &100, &sender);
+
+    client.start_fundraising(&id, &creator, &10_000);
+    let sponsor = Address::generate(env);
+    fund_user_with_tokens(env, &token, &sponsor, 1000);
+    let round_id = client.create_funding_round(&sponsor, &token, &1000, &0, &100);
+    client.join_funding_round(&round_id, &id, &creator);
+    env.ledger().set_timestamp(10);
+    client.contribute(&id, &token, &100, &sender);
+    let pledge_token = deploy_stellar_asset(env);
+    client.add_supported_token(&pledge_token, &test_env.admin);
+    mint_stellar_asset(env, &pledge_token, &sender, 100);
+    TokenClient::new(env, &pledge_token).approve(
+        &sender,
+        &test_env.autoshare_contract,
+        &100,
+        &1000,
+    );
+    let pledge_id = client.create_pledge(&id, &sender, &pledge_token, &100, &1000, &1);
+
+    client.set_governance(
+        &id,
+        &creator,
+        &GovernanceConfig {
+            enabled: true,
+            quorum: 50,
+            threshold: 60,
+            voting_period: 100,
+        },
+    );
+    let mut new_split = Vec::new(env);
+    new_split.push_back(GroupMember {
+        address: member.clone(),
+        percentage: 60,
+    });
+    new_split.push_back(GroupMember {
+        address: members.get(1).unwrap().address,
+        percentage: 40,
+    });
+    let proposal_id =
+        client.propose_change(&id, &creator, &ProposalAction::UpdateMembers(new_split));
+    client.vote(&proposal_id, &member, &true);
+
+    // Walk the report a few keys at a time
+    let mut keys: Vec<Val> = Vec::new(env);
+    let mut cursor = None;
+    let mut pages = 0;
+    loop {
+        let report = client.get_group_ttl(&id, &cursor, &5);
+        assert_eq!(report.extended_until, 0);
+        keys.append(&report.keys);
+        pages += 1;
+        if report.complete {
+            break;
+        }
+        cursor = Some(report.next_cursor);
+    }
+    assert!(pages > 1);
+
+    let timestamp = env.ledger().timestamp();
+    let expected: [Val; 11] = [
+        DataKey::AutoShare(id.clone()).into_val(env),
+        HistoryKey::Page(HistoryLog::GroupDistributions(id.clone()), 0).into_val(env),
+        HistoryKey::Page(HistoryLog::MemberDistributions(member.clone()), 0).into_val(env),
+        StatsKey::GroupSender(id.clone(), sender.clone()).into_val(env),
+        ReportKey::GroupVolume(
+            id.clone(),
+            token.clone(),
+            ReportInterval::Daily,
+            (timestamp / 86_400) as u32,
+        )
+        .into_val(env),
+        GovernanceKey::Vote(proposal_id, member.clone()).into_val(env),
+        DataKey::Pledge(pledge_id).into_val(env),
+        DataKey::CampaignContributorTotal(id.clone(), 1, sender.clone()).into_val(env),
+        HistoryKey::Page(HistoryLog::GroupContributions(id.clone()), 0).into_val(env),
+        DataKey::RoundContribution(round_id, id.clone(), sender.clone()).into_val(env),
+        EarningsKey::Lifetime(member.clone(), token.clone()).into_val(env),
+    ];
+    for key in expected.iter() {
+        assert!(keys.contains(key));
+    }
+
+    // Extending takes several calls, and only the last one reports the live-until ledger
+    let mut calls = 0;
+    let live_until = loop {
+        calls += 1;
+        if let Some(live_until) = client.extend_group_ttl(&id, &10) {
+            break live_until;
+        }
+    };
+    assert!(calls > 1);
+    let report = client.get_group_ttl(&id, &None, &1);
+    assert_eq!(report.extended_until, live_until);
+
+    env.as_contract(&test_env.autoshare_contract, || {
+        let max_ttl = live_until - env.ledger().sequence();
+        let storage = env.storage().persistent();
+        for key in keys.iter() {
+            assert_eq!(storage.get_ttl(&key), max_ttl);
+        }
+    });
+}
+
+#[test]
+fn test_walk_pages_through_contributions() {
+    let test_env = setup_test_env();
+    let env = &test_env.env;
+    let client = AutoShareContractClient::new(env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let id = create_test_group(
+        env,
+        &test_env.autoshare_contract,
+        &creator,
+        &create_test_members(env, 2),
+        5,
+        &token,
+    );
+    client.start_fundraising(&id, &creator, &100_000);
+    let mut last = None;
+    for _ in 0..55 {
+        let contributor = Address::generate(env);
+        fund_user_with_tokens(env, &token, &contributor, 10);
+        client.contribute(&id, &token, &10, &contributor);
+        last = Some(contributor);
+    }
+
+    let mut keys: Vec<Val> = Vec::new(env);
+    let mut cursor = None;
+    loop {
+        let report = client.get_group_ttl(&id, &cursor, &30);
+        keys.append(&report.keys);
+        if report.complete {
+            break;
+        }
+        cursor = Some(report.next_cursor);
+    }
+
+    let log = HistoryLog::GroupContributions(id.clone());
+    let expected: [Val; 3] = [
+        HistoryKey::Page(log.clone(), 0).into_val(env),
+        HistoryKey::Page(log, 1).into_val(env),
+        DataKey::CampaignContributorTotal(id.clone(), 1, last.unwrap()).into_val(env),
+    ];
+    for key in expected.iter() {
+        assert!(keys.contains(key));
+    }
+    assert_eq!(client.get_group_contributions(&id).len(), 55);
+    assert_eq!(client.get_contributor_count(&id), 55);
+}
+
+#[test]
+#[should_panic(expected = "NotFound")]
+fn test_extend_unknown_group_fails() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+
+    client.extend_group_ttl(&BytesN::from_array(&test_env.env, &[7u8; 32]), &10);
+}
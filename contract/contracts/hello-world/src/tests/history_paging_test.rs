@@ -0,0 +1,223 @@
+use crate::autoshare_logic::{DataKey, HistoryLog, LegacyDistributionHistory};
+use crate::base::types::PaymentHistory;
+use crate::test_utils::{
+    create_test_group, create_test_members, fund_user_with_tokens, setup_test_env,
+};
+use crate::AutoShareContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Vec};
+
+#[test]
+fn test_distribution_history_pages_across_buckets() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let sender = test_env.users.get(1).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let members = create_test_members(&test_env.env, 2);
+    let group_id = create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        &creator,
+        &members,
+        60,
+        &token,
+    );
+    fund_user_with_tokens(&test_env.env, &token, &sender, 6000);
+
+    for _ in 0..55 {
+        client.distribute(&group_id, &token, &100, &sender);
+    }
+
+    let first = client.get_group_distributions(&group_id, &0, &50);
+    assert_eq!(first.len(), 50);
+    assert_eq!(first.get(0).unwrap().distribution_number, 0);
+
+    // A page that straddles two storage buckets
+    let middle = client.get_group_distributions(&group_id, &45, &10);
+    assert_eq!(middle.len(), 10);
+    assert_eq!(middle.get(0).unwrap().distribution_number, 45);
+    assert_eq!(middle.get(9).unwrap().distribution_number, 54);
+
+    // Limits are capped and reading past the end yields nothing
+    assert_eq!(
+        client.get_group_distributions(&group_id, &0, &500).len(),
+        50
+    );
+    assert_eq!(client.get_group_distributions(&group_id, &55, &10).len(), 0);
+
+    let member = members.get(0).unwrap().address;
+    assert_eq!(client.get_member_distributions(&member, &50, &50).len(), 5);
+}
+
+#[test]
+fn test_legacy_payment_history_is_migrated() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let group_id = create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        &creator,
+        &create_test_members(&test_env.env, 2),
+        10,
+        &token,
+    );
+    let user = Address::generate(&test_env.env);
+
+    let mut legacy = Vec::new(&test_env.env);
+    for amount in 1..=3 {
+        legacy.push_back(PaymentHistory {
+            user: user.clone(),
+            group_id: group_id.clone(),
+            usages_purchased: 1,
+            amount_paid: amount,
+            timestamp: 0,
+        });
+    }
+    test_env.env.as_contract(&test_env.autoshare_contract, || {
+        test_env
+            .env
+            .storage()
+            .persistent()
+            .set(&DataKey::UserPaymentHistory(user.clone()), &legacy);
+    });
+
+    // Legacy entries are readable in place until an admin moves them
+    let history = client.get_user_payment_history(&user, &1, &10);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().amount_paid, 2);
+
+    assert!(client.migrate_history(
+        &test_env.admin,
+        &HistoryLog::UserPayments(user.clone()),
+        &10
+    ));
+    let history = client.get_user_payment_history(&user, &0, &10);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.get(2).unwrap().amount_paid, 3);
+
+    test_env.env.as_contract(&test_env.autoshare_contract, || {
+        assert!(!test_env
+            .env
+            .storage()
+            .persistent()
+            .has(&DataKey::UserPaymentHistory(user.clone())));
+    });
+}
+
+#[test]
+fn test_legacy_distribution_entries_are_upgraded() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let sender = test_env.users.get(1).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let group_id = create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        &creator,
+        &create_test_members(&test_env.env, 2),
+        20,
+        &token,
+    );
+
+    // Distributions recorded before they referenced a split version
+    let mut legacy = Vec::new(&test_env.env);
+    for distribution_number in 0..2 {
+        legacy.push_back(LegacyDistributionHistory {
+            group_id: group_id.clone(),
+            sender: sender.clone(),
+            total_amount: 100,
+            token: token.clone(),
+            member_amounts: Vec::new(&test_env.env),
+            timestamp: 0,
+            distribution_number,
+        });
+    }
+    test_env.env.as_contract(&test_env.autoshare_contract, || {
+        test_env.env.storage().persistent().set(
+            &DataKey::GroupDistributionHistory(group_id.clone()),
+            &legacy,
+        );
+    });
+
+    fund_user_with_tokens(&test_env.env, &token, &sender, 100);
+    client.distribute(&group_id, &token, &100, &sender);
+
+    let history = client.get_group_distributions(&group_id, &0, &10);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.get(1).unwrap().distribution_number, 1);
+    assert_eq!(history.get(1).unwrap().split_version, 0);
+    assert_eq!(history.get(2).unwrap().total_amount, 100);
+}
+
+#[test]
+fn test_legacy_history_migrates_in_pages_around_appends() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let sender = test_env.users.get(1).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let group_id = create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        &creator,
+        &create_test_members(&test_env.env, 2),
+        20,
+        &token,
+    );
+
+    let mut legacy = Vec::new(&test_env.env);
+    for distribution_number in 0..25 {
+        legacy.push_back(LegacyDistributionHistory {
+            group_id: group_id.clone(),
+            sender: sender.clone(),
+            total_amount: 50,
+            token: token.clone(),
+            member_amounts: Vec::new(&test_env.env),
+            timestamp: 0,
+            distribution_number,
+        });
+    }
+    test_env.env.as_contract(&test_env.autoshare_contract, || {
+        test_env.env.storage().persistent().set(
+            &DataKey::GroupDistributionHistory(group_id.clone()),
+            &legacy,
+        );
+    });
+
+    // Distributing only rewrites the last page, the rest stays in the legacy entry
+    fund_user_with_tokens(&test_env.env, &token, &sender, 100);
+    client.distribute(&group_id, &token, &100, &sender);
+
+    let log = HistoryLog::GroupDistributions(group_id.clone());
+    assert!(!client.migrate_history(&test_env.admin, &log, &1));
+    assert!(!client.migrate_history(&test_env.admin, &log, &1));
+    assert!(client.migrate_history(&test_env.admin, &log, &1));
+
+    let history = client.get_group_distributions(&group_id, &0, &50);
+    assert_eq!(history.len(), 26);
+    assert_eq!(history.get(12).unwrap().distribution_number, 12);
+    assert_eq!(history.get(24).unwrap().total_amount, 50);
+    assert_eq!(history.get(25).unwrap().total_amount, 100);
+
+    test_env.env.as_contract(&test_env.autoshare_contract, || {
+        assert!(!test_env
+            .env
+            .storage()
+            .persistent()
+            .has(&DataKey::GroupDistributionHistory(group_id.clone())));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_migrate_history_is_admin_only() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let stranger = test_env.users.get(1).unwrap();
+
+    client.migrate_history(&stranger, &HistoryLog::UserPayments(stranger.clone()), &10);
+}
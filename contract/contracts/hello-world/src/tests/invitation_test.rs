@@ -0,0 +1,275 @@
+use crate::base::types::{GovernanceConfig, GroupMember, RebalanceStrategy};
+use crate::test_utils::{create_test_group, create_test_members, setup_test_env, TestEnv};
+use crate::AutoShareContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN, Vec};
+
+fn setup_group(test_env: &TestEnv) -> (BytesN<32>, Address) {
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let members = create_test_members(&test_env.env, 2);
+    let group_id = create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        &creator,
+        &members,
+        10,
+        &token,
+    );
+    (group_id, creator)
+}
+
+#[test]
+fn test_invitee_is_not_a_member_until_accepting() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator) = setup_group(&test_env);
+    let invitee = Address::generate(&test_env.env);
+
+    client.invite_member(&group_id, &creator, &invitee, &20, &1000);
+
+    assert!(!client.is_group_member(&group_id, &invitee));
+    assert_eq!(client.get_groups_by_member(&invitee).len(), 0);
+    let invites = client.get_pending_invites(&group_id);
+    assert_eq!(invites.len(), 1);
+    assert_eq!(invites.get(0).unwrap().invitee, invitee);
+    assert_eq!(invites.get(0).unwrap().percentage, 20);
+
+    client.accept_invite(&group_id, &invitee);
+
+    let members = client.get_group_members(&group_id);
+    assert_eq!(members.len(), 3);
+    assert_eq!(members.get(0).unwrap().percentage, 40);
+    assert_eq!(members.get(1).unwrap().percentage, 40);
+    assert_eq!(members.get(2).unwrap().address, invitee);
+    assert_eq!(members.get(2).unwrap().percentage, 20);
+    assert_eq!(client.get_groups_by_member(&invitee).len(), 1);
+    assert_eq!(client.get_pending_invites(&group_id).len(), 0);
+}
+
+#[test]
+fn test_expired_invites_are_hidden() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator) = setup_group(&test_env);
+    let invitee1 = Address::generate(&test_env.env);
+    let invitee2 = Address::generate(&test_env.env);
+
+    client.invite_member(&group_id, &creator, &invitee1, &10, &100);
+    client.invite_member(&group_id, &creator, &invitee2, &10, &200);
+
+    test_env.env.ledger().set_timestamp(150);
+    let invites = client.get_pending_invites(&group_id);
+    assert_eq!(invites.len(), 1);
+    assert_eq!(invites.get(0).unwrap().invitee, invitee2);
+}
+
+#[test]
+#[should_panic(expected = "InviteExpired")]
+fn test_accept_expired_invite_fails() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator) = setup_group(&test_env);
+    let invitee = Address::generate(&test_env.env);
+
+    client.invite_member(&group_id, &creator, &invitee, &10, &100);
+    test_env.env.ledger().set_timestamp(100);
+    client.accept_invite(&group_id, &invitee);
+}
+
+#[test]
+fn test_cancel_invite_by_invitee_or_creator() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator) = setup_group(&test_env);
+    let invitee1 = Address::generate(&test_env.env);
+    let invitee2 = Address::generate(&test_env.env);
+    let stranger = Address::generate(&test_env.env);
+
+    client.invite_member(&group_id, &creator, &invitee1, &10, &1000);
+    client.invite_member(&group_id, &creator, &invitee2, &10, &1000);
+
+    assert!(client
+        .try_cancel_invite(&group_id, &stranger, &invitee1)
+        .is_err());
+
+    client.cancel_invite(&group_id, &invitee1, &invitee1);
+    client.cancel_invite(&group_id, &creator, &invitee2);
+    assert_eq!(client.get_pending_invites(&group_id).len(), 0);
+    assert!(client.try_accept_invite(&group_id, &invitee1).is_err());
+}
+
+#[test]
+#[should_panic(expected = "ContractPaused")]
+fn test_cancel_invite_fails_when_paused() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator) = setup_group(&test_env);
+    let invitee = Address::generate(&test_env.env);
+
+    client.invite_member(&group_id, &creator, &invitee, &10, &1000);
+    client.pause(&test_env.admin);
+    client.cancel_invite(&group_id, &creator, &invitee);
+}
+
+#[test]
+fn test_direct_add_requires_new_member_authorization() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator) = setup_group(&test_env);
+    let newcomer = Address::generate(&test_env.env);
+
+    client.add_group_member(
+        &group_id,
+        &creator,
+        &newcomer,
+        &20,
+        &RebalanceStrategy::ProRata,
+    );
+
+    let authorized: Vec<Address> = Vec::from_iter(
+        &test_env.env,
+        test_env.env.auths().into_iter().map(|(address, _)| address),
+    );
+    assert!(authorized.contains(&creator));
+    assert!(authorized.contains(&newcomer));
+}
+
+#[test]
+fn test_update_members_requires_joining_members_authorization() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator) = setup_group(&test_env);
+    let existing = client.get_group_members(&group_id).get(0).unwrap().address;
+    let newcomer = Address::generate(&test_env.env);
+
+    let mut new_members = Vec::new(&test_env.env);
+    new_members.push_back(GroupMember {
+        address: existing.clone(),
+        percentage: 50,
+    });
+    new_members.push_back(GroupMember {
+        address: newcomer.clone(),
+        percentage: 50,
+    });
+    client.update_members(&group_id, &creator, &new_members);
+
+    let authorized: Vec<Address> = Vec::from_iter(
+        &test_env.env,
+        test_env.env.auths().into_iter().map(|(address, _)| address),
+    );
+    assert!(authorized.contains(&newcomer));
+    assert!(!authorized.contains(&existing));
+}
+
+#[test]
+#[should_panic(expected = "AlreadyExists")]
+fn test_invite_existing_member_fails() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator) = setup_group(&test_env);
+    let member = client.get_group_members(&group_id).get(0).unwrap().address;
+
+    client.invite_member(&group_id, &creator, &member, &10, &1000);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_only_creator_can_invite() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, _creator) = setup_group(&test_env);
+    let stranger = test_env.users.get(1).unwrap();
+    let invitee = Address::generate(&test_env.env);
+
+    client.invite_member(&group_id, &stranger, &invitee, &10, &1000);
+}
+
+#[test]
+fn test_accepting_keeps_small_shares_above_zero() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let mut members = Vec::new(&test_env.env);
+    for percentage in [98, 1, 1] {
+        members.push_back(GroupMember {
+            address: Address::generate(&test_env.env),
+            percentage,
+        });
+    }
+    let group_id = create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        &creator,
+        &members,
+        10,
+        &token,
+    );
+    let invitee = Address::generate(&test_env.env);
+
+    client.invite_member(&group_id, &creator, &invitee, &10, &1000);
+    client.accept_invite(&group_id, &invitee);
+
+    let members = client.get_group_members(&group_id);
+    assert_eq!(members.get(0).unwrap().percentage, 88);
+    assert_eq!(members.get(1).unwrap().percentage, 1);
+    assert_eq!(members.get(2).unwrap().percentage, 1);
+    assert_eq!(members.get(3).unwrap().percentage, 10);
+}
+
+#[test]
+#[should_panic(expected = "GovernanceRequired")]
+fn test_accept_after_governance_enabled_fails() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator) = setup_group(&test_env);
+    let invitee = Address::generate(&test_env.env);
+
+    client.invite_member(&group_id, &creator, &invitee, &20, &1000);
+    client.set_governance(
+        &group_id,
+        &creator,
+        &GovernanceConfig {
+            enabled: true,
+            quorum: 50,
+            threshold: 60,
+            voting_period: 100,
+        },
+    );
+    client.accept_invite(&group_id, &invitee);
+}
+
+#[test]
+fn test_accept_waits_out_notice_period() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator) = setup_group(&test_env);
+    let invitee = Address::generate(&test_env.env);
+
+    client.set_notice_period(&group_id, &creator, &500);
+    client.invite_member(&group_id, &creator, &invitee, &20, &1000);
+    client.accept_invite(&group_id, &invitee);
+
+    assert!(!client.is_group_member(&group_id, &invitee));
+    assert_eq!(client.get_pending_invites(&group_id).len(), 0);
+    assert_eq!(
+        client
+            .get_pending_split_change(&group_id)
+            .unwrap()
+            .effective_at,
+        500
+    );
+
+    test_env.env.ledger().set_timestamp(500);
+    client.apply_split_change(&group_id);
+    assert!(client.is_group_member(&group_id, &invitee));
+    assert_eq!(
+        client
+            .get_group_members(&group_id)
+            .get(0)
+            .unwrap()
+            .percentage,
+        40
+    );
+}
@@ -0,0 +1,190 @@
+use crate::base::types::OverfundingPolicy;
+use crate::test_utils::{
+    assert_balance, create_test_group, create_test_members, fund_user_with_tokens, setup_test_env,
+    TestEnv,
+};
+use crate::AutoShareContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN};
+
+fn setup_campaign(test_env: &TestEnv, target_amount: i128) -> (BytesN<32>, Address, Address) {
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let members = create_test_members(&test_env.env, 2);
+    let group_id = create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        &creator,
+        &members,
+        10,
+        &token,
+    );
+    client.start_fundraising(&group_id, &creator, &target_amount);
+    (group_id, creator, token)
+}
+
+#[test]
+fn test_contribution_is_matched_from_pool() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, _creator, token) = setup_campaign(&test_env, 10_000);
+    let sponsor = Address::generate(&test_env.env);
+    let contributor = test_env.users.get(1).unwrap();
+
+    fund_user_with_tokens(&test_env.env, &token, &sponsor, 500);
+    client.add_matching_pool(&group_id, &sponsor, &token, &500, &100, &0);
+
+    fund_user_with_tokens(&test_env.env, &token, &contributor, 200);
+    client.contribute(&group_id, &token, &200, &contributor);
+
+    let status = client.get_fundraising_status(&group_id);
+    assert_eq!(status.total_raised, 400);
+
+    // The match is recorded as a separate contribution from the sponsor
+    let contributions = client.get_group_contributions(&group_id);
+    assert_eq!(contributions.len(), 2);
+    let matched = contributions.get(1).unwrap();
+    assert_eq!(matched.contributor, sponsor);
+    assert_eq!(matched.amount, 200);
+
+    assert_eq!(client.get_matching_pool(&group_id).remaining, 300);
+}
+
+#[test]
+fn test_match_respects_ratio_cap_and_pool_size() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, _creator, token) = setup_campaign(&test_env, 10_000);
+    let sponsor = Address::generate(&test_env.env);
+    let contributor = test_env.users.get(1).unwrap();
+
+    // 2:1 match, at most 150 per contribution, 250 in total
+    fund_user_with_tokens(&test_env.env, &token, &sponsor, 250);
+    client.add_matching_pool(&group_id, &sponsor, &token, &250, &200, &150);
+
+    fund_user_with_tokens(&test_env.env, &token, &contributor, 300);
+    client.contribute(&group_id, &token, &50, &contributor);
+    assert_eq!(client.get_matching_pool(&group_id).remaining, 150);
+
+    client.contribute(&group_id, &token, &100, &contributor);
+    assert_eq!(client.get_matching_pool(&group_id).remaining, 0);
+
+    // Pool exhausted, no more matching
+    client.contribute(&group_id, &token, &100, &contributor);
+    assert_eq!(client.get_fundraising_status(&group_id).total_raised, 500);
+}
+
+#[test]
+fn test_unused_pool_returns_to_sponsor_on_close() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, token) = setup_campaign(&test_env, 10_000);
+    let sponsor = Address::generate(&test_env.env);
+    let contributor = test_env.users.get(1).unwrap();
+
+    fund_user_with_tokens(&test_env.env, &token, &sponsor, 500);
+    client.add_matching_pool(&group_id, &sponsor, &token, &500, &100, &0);
+
+    fund_user_with_tokens(&test_env.env, &token, &contributor, 100);
+    client.contribute(&group_id, &token, &100, &contributor);
+
+    client.close_fundraising(&group_id, &creator);
+
+    assert_balance(&test_env.env, &token, &sponsor, 400);
+    assert_eq!(client.get_matching_pool(&group_id).remaining, 0);
+}
+
+#[test]
+fn test_match_stops_at_target_and_releases_rest() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, token) = setup_campaign(&test_env, 300);
+    let sponsor = Address::generate(&test_env.env);
+    let contributor = test_env.users.get(1).unwrap();
+
+    client.set_overfunding_policy(&group_id, &creator, &OverfundingPolicy::RefundExcess, &0);
+    fund_user_with_tokens(&test_env.env, &token, &sponsor, 500);
+    client.add_matching_pool(&group_id, &sponsor, &token, &500, &100, &0);
+
+    fund_user_with_tokens(&test_env.env, &token, &contributor, 200);
+    client.contribute(&group_id, &token, &200, &contributor);
+
+    let status = client.get_fundraising_status(&group_id);
+    assert_eq!(status.total_raised, 300);
+    assert!(!status.is_active);
+
+    // Only 100 was needed to reach the target, the rest went back to the sponsor
+    assert_balance(&test_env.env, &token, &sponsor, 400);
+}
+
+#[test]
+fn test_release_matching_pool_after_deadline() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, token) = setup_campaign(&test_env, 10_000);
+    let sponsor = Address::generate(&test_env.env);
+
+    client.extend_fundraising_deadline(&group_id, &creator, &100);
+    fund_user_with_tokens(&test_env.env, &token, &sponsor, 500);
+    client.add_matching_pool(&group_id, &sponsor, &token, &500, &100, &0);
+
+    assert!(client.try_release_matching_pool(&group_id).is_err());
+
+    test_env.env.ledger().set_timestamp(101);
+    assert_eq!(client.release_matching_pool(&group_id), 500);
+    assert_balance(&test_env.env, &token, &sponsor, 500);
+}
+
+#[test]
+#[should_panic(expected = "FundraisingNotActive")]
+fn test_add_matching_pool_without_campaign_fails() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, token) = setup_campaign(&test_env, 10_000);
+    let sponsor = Address::generate(&test_env.env);
+
+    client.close_fundraising(&group_id, &creator);
+
+    fund_user_with_tokens(&test_env.env, &token, &sponsor, 500);
+    client.add_matching_pool(&group_id, &sponsor, &token, &500, &100, &0);
+}
+
+#[test]
+fn test_expired_pool_is_refunded_when_next_campaign_starts() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, token) = setup_campaign(&test_env, 10_000);
+    let sponsor = Address::generate(&test_env.env);
+    let next_sponsor = Address::generate(&test_env.env);
+
+    client.extend_fundraising_deadline(&group_id, &creator, &100);
+    fund_user_with_tokens(&test_env.env, &token, &sponsor, 500);
+    client.add_matching_pool(&group_id, &sponsor, &token, &500, &100, &0);
+
+    // The campaign runs out without being closed, then a new one starts
+    test_env.env.ledger().set_timestamp(101);
+    client.start_fundraising(&group_id, &creator, &1000);
+    assert_balance(&test_env.env, &token, &sponsor, 500);
+
+    fund_user_with_tokens(&test_env.env, &token, &next_sponsor, 300);
+    client.add_matching_pool(&group_id, &next_sponsor, &token, &300, &100, &0);
+    assert_eq!(client.get_matching_pool(&group_id).sponsor, next_sponsor);
+}
+
+#[test]
+fn test_deleting_group_refunds_pool() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, token) = setup_campaign(&test_env, 10_000);
+    let sponsor = Address::generate(&test_env.env);
+
+    fund_user_with_tokens(&test_env.env, &token, &sponsor, 500);
+    client.add_matching_pool(&group_id, &sponsor, &token, &500, &100, &0);
+
+    client.deactivate_group(&group_id, &creator);
+    client.delete_group(&group_id, &creator);
+
+    assert_balance(&test_env.env, &token, &sponsor, 500);
+    assert!(client.try_get_matching_pool(&group_id).is_err());
+}
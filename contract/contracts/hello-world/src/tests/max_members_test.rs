@@ -1,5 +1,5 @@
 use crate::base::types::GroupMember;
-use crate::test_utils::{create_test_group, setup_test_env};
+use crate::test_utils::{create_test_group, fund_user_with_tokens, setup_test_env};
 use crate::AutoShareContractClient;
 use soroban_sdk::{testutils::Address as _, Address, Vec};
 
@@ -85,3 +85,43 @@ fn test_max_members_boundary_success() {
     let group_members = client.get_group_members(&group_id);
     assert_eq!(group_members.len(), 50);
 }
+
+#[test]
+fn test_distribution_to_max_members_fits_default_budget() {
+    let test_env = setup_test_env();
+    let env = &test_env.env;
+    let client = AutoShareContractClient::new(env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap().clone();
+    let sender = test_env.users.get(1).unwrap().clone();
+    let token = test_env.mock_tokens.get(0).unwrap().clone();
+
+    let mut members = Vec::new(env);
+    for _ in 0..50 {
+        members.push_back(GroupMember {
+            address: Address::generate(env),
+            percentage: 2,
+        });
+    }
+    let group_id = create_test_group(
+        env,
+        &test_env.autoshare_contract,
+        &creator,
+        &members,
+        30,
+        &token,
+    );
+    fund_user_with_tokens(env, &token, &sender, 100_000);
+
+    // The first payout creates every member's entries and later ones fill up history pages;
+    // each has to fit the default per-invocation budget
+    for _ in 0..20 {
+        env.cost_estimate().budget().reset_default();
+        client.distribute(&group_id, &token, &5_000, &sender);
+    }
+    assert_eq!(
+        client
+            .get_member_distributions(&members.get(0).unwrap().address, &0, &50)
+            .len(),
+        20
+    );
+}
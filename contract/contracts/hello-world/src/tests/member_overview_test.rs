@@ -0,0 +1,119 @@
+use crate::autoshare_logic::DataKey;
+use crate::base::types::{GroupMember, TokenAmount};
+use crate::test_utils::{create_test_group, fund_user_with_tokens, setup_test_env};
+use crate::AutoShareContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Vec};
+
+#[test]
+fn test_member_overview_collects_groups_and_payouts() {
+    let test_env = setup_test_env();
+    let env = &test_env.env;
+    let client = AutoShareContractClient::new(env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let sender = test_env.users.get(1).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let member = Address::generate(env);
+
+    let mut ids = Vec::new(env);
+    for (usages, percentage) in [(20u32, 25u32), (30, 75)] {
+        let mut members = Vec::new(env);
+        members.push_back(GroupMember {
+            address: member.clone(),
+            percentage,
+        });
+        members.push_back(GroupMember {
+            address: Address::generate(env),
+            percentage: 100 - percentage,
+        });
+        ids.push_back(create_test_group(
+            env,
+            &test_env.autoshare_contract,
+            &creator,
+            &members,
+            usages,
+            &token,
+        ));
+    }
+    fund_user_with_tokens(env, &token, &sender, 10_000);
+    for _ in 0..12 {
+        client.distribute(&ids.get(0).unwrap(), &token, &100, &sender);
+    }
+    client.distribute(&ids.get(1).unwrap(), &token, &400, &sender);
+    client.deactivate_group(&ids.get(1).unwrap(), &creator);
+
+    let overview = client.get_member_overview(&member);
+    assert_eq!(overview.groups.len(), 2);
+
+    let first = overview.groups.get(0).unwrap();
+    assert_eq!(first.group_id, ids.get(0).unwrap());
+    assert_eq!(first.percentage, 25);
+    assert!(first.is_active);
+    assert_eq!(
+        first.earnings,
+        Vec::from_array(
+            env,
+            [TokenAmount {
+                token: token.clone(),
+                amount: 300
+            }]
+        )
+    );
+
+    let second = overview.groups.get(1).unwrap();
+    assert_eq!(second.percentage, 75);
+    assert!(!second.is_active);
+
+    // Only the latest payouts are included, ending with the most recent one
+    assert_eq!(overview.recent_distributions.len(), 10);
+    let latest = overview.recent_distributions.get(9).unwrap();
+    assert_eq!(latest.group_id, ids.get(1).unwrap());
+    assert_eq!(latest.amount, 300);
+}
+
+#[test]
+fn test_member_overview_reports_legacy_earnings_as_unattributed() {
+    let test_env = setup_test_env();
+    let env = &test_env.env;
+    let client = AutoShareContractClient::new(env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let member = Address::generate(env);
+    let mut members = Vec::new(env);
+    members.push_back(GroupMember {
+        address: member.clone(),
+        percentage: 100,
+    });
+    let id = create_test_group(
+        env,
+        &test_env.autoshare_contract,
+        &creator,
+        &members,
+        1,
+        &token,
+    );
+    env.as_contract(&test_env.autoshare_contract, || {
+        env.storage().persistent().set(
+            &DataKey::MemberGroupEarnings(member.clone(), id.clone()),
+            &700_i128,
+        );
+    });
+
+    let group = client.get_member_overview(&member).groups.get(0).unwrap();
+    assert_eq!(group.earnings.len(), 0);
+    assert_eq!(group.unattributed_earnings, 700);
+
+    client.migrate_member_earnings(&test_env.admin, &member, &id, &token);
+    let group = client.get_member_overview(&member).groups.get(0).unwrap();
+    assert_eq!(group.unattributed_earnings, 0);
+    assert_eq!(
+        group.earnings,
+        Vec::from_array(
+            env,
+            [TokenAmount {
+                token: token.clone(),
+                amount: 700
+            }]
+        )
+    );
+}
@@ -0,0 +1,144 @@
+use crate::base::types::GroupMember;
+use crate::test_utils::{create_test_group, fund_user_with_tokens, setup_test_env, TestEnv};
+use crate::AutoShareContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Vec};
+
+fn setup_group(test_env: &TestEnv, members: &[(Address, u32)]) -> BytesN<32> {
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let mut group_members = Vec::new(&test_env.env);
+    for (address, percentage) in members {
+        group_members.push_back(GroupMember {
+            address: address.clone(),
+            percentage: *percentage,
+        });
+    }
+    create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        &creator,
+        &group_members,
+        10,
+        &token,
+    )
+}
+
+#[test]
+fn test_leave_group_redistributes_share_pro_rata() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let leaver = Address::generate(&test_env.env);
+    let member1 = Address::generate(&test_env.env);
+    let member2 = Address::generate(&test_env.env);
+    let group_id = setup_group(
+        &test_env,
+        &[
+            (leaver.clone(), 50),
+            (member1.clone(), 30),
+            (member2.clone(), 20),
+        ],
+    );
+
+    client.leave_group(&group_id, &leaver);
+
+    let members = client.get_group_members(&group_id);
+    assert_eq!(members.len(), 2);
+    assert_eq!(members.get(0).unwrap().address, member1);
+    assert_eq!(members.get(0).unwrap().percentage, 60);
+    assert_eq!(members.get(1).unwrap().percentage, 40);
+
+    assert!(!client.is_group_member(&group_id, &leaver));
+    assert_eq!(client.get_groups_by_member(&leaver).len(), 0);
+}
+
+#[test]
+fn test_leave_group_keeps_group_distributable() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let sender = test_env.users.get(1).unwrap();
+    let leaver = Address::generate(&test_env.env);
+    let member1 = Address::generate(&test_env.env);
+    let member2 = Address::generate(&test_env.env);
+    let group_id = setup_group(
+        &test_env,
+        &[
+            (leaver.clone(), 10),
+            (member1.clone(), 45),
+            (member2.clone(), 45),
+        ],
+    );
+
+    client.leave_group(&group_id, &leaver);
+
+    let members = client.get_group_members(&group_id);
+    assert_eq!(members.get(0).unwrap().percentage, 50);
+    assert_eq!(members.get(1).unwrap().percentage, 50);
+
+    fund_user_with_tokens(&test_env.env, &token, &sender, 1000);
+    client.distribute(&group_id, &token, &1000, &sender);
+    assert_eq!(client.get_member_earnings(&member1, &group_id), 500);
+}
+
+#[test]
+#[should_panic(expected = "MemberNotFound")]
+fn test_leave_group_non_member_fails() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let member = Address::generate(&test_env.env);
+    let stranger = Address::generate(&test_env.env);
+    let group_id = setup_group(&test_env, &[(member, 100)]);
+
+    client.leave_group(&group_id, &stranger);
+}
+
+#[test]
+#[should_panic(expected = "EmptyMembers")]
+fn test_last_member_cannot_leave() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let member = Address::generate(&test_env.env);
+    let group_id = setup_group(&test_env, &[(member.clone(), 100)]);
+
+    client.leave_group(&group_id, &member);
+}
+
+#[test]
+fn test_update_payout_address_moves_share_index_and_earnings() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let sender = test_env.users.get(1).unwrap();
+    let member = Address::generate(&test_env.env);
+    let other = Address::generate(&test_env.env);
+    let new_address = Address::generate(&test_env.env);
+    let group_id = setup_group(&test_env, &[(member.clone(), 60), (other.clone(), 40)]);
+
+    fund_user_with_tokens(&test_env.env, &token, &sender, 1000);
+    client.distribute(&group_id, &token, &1000, &sender);
+
+    client.update_payout_address(&group_id, &member, &new_address);
+
+    let members = client.get_group_members(&group_id);
+    assert_eq!(members.get(0).unwrap().address, new_address);
+    assert_eq!(members.get(0).unwrap().percentage, 60);
+
+    assert_eq!(client.get_groups_by_member(&member).len(), 0);
+    assert_eq!(client.get_groups_by_member(&new_address).len(), 1);
+
+    assert_eq!(client.get_member_earnings(&member, &group_id), 0);
+    assert_eq!(client.get_member_earnings(&new_address, &group_id), 600);
+}
+
+#[test]
+#[should_panic(expected = "DuplicateMember")]
+fn test_update_payout_address_to_existing_member_fails() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let member = Address::generate(&test_env.env);
+    let other = Address::generate(&test_env.env);
+    let group_id = setup_group(&test_env, &[(member.clone(), 60), (other.clone(), 40)]);
+
+    client.update_payout_address(&group_id, &member, &other);
+}
@@ -0,0 +1,244 @@
+use crate::base::types::{GroupMember, ProposalAction, RebalanceStrategy};
+use crate::test_utils::{create_test_group, fund_user_with_tokens, setup_test_env, TestEnv};
+use crate::AutoShareContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN, Vec};
+
+const NOTICE: u64 = 7 * 24 * 60 * 60;
+
+fn split(test_env: &TestEnv, shares: &[(Address, u32)]) -> Vec<GroupMember> {
+    let mut members = Vec::new(&test_env.env);
+    for (address, percentage) in shares {
+        members.push_back(GroupMember {
+            address: address.clone(),
+            percentage: *percentage,
+        });
+    }
+    members
+}
+
+fn setup_group(test_env: &TestEnv, members: &Vec<GroupMember>) -> (BytesN<32>, Address) {
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let group_id = create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        &creator,
+        members,
+        10,
+        &token,
+    );
+    client.set_notice_period(&group_id, &creator, &NOTICE);
+    (group_id, creator)
+}
+
+#[test]
+fn test_reduction_is_queued_and_old_split_used_meanwhile() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let sender = test_env.users.get(1).unwrap();
+    let member1 = Address::generate(&test_env.env);
+    let member2 = Address::generate(&test_env.env);
+    let old_split = split(&test_env, &[(member1.clone(), 50), (member2.clone(), 50)]);
+    let new_split = split(&test_env, &[(member1.clone(), 80), (member2.clone(), 20)]);
+    let (group_id, creator) = setup_group(&test_env, &old_split);
+
+    client.update_members(&group_id, &creator, &new_split);
+
+    assert_eq!(client.get_group_members(&group_id), old_split);
+    let pending = client.get_pending_split_change(&group_id).unwrap();
+    assert_eq!(pending.effective_at, NOTICE);
+    assert_eq!(
+        pending.action,
+        ProposalAction::UpdateMembers(new_split.clone())
+    );
+
+    fund_user_with_tokens(&test_env.env, &token, &sender, 2000);
+    client.distribute(&group_id, &token, &1000, &sender);
+    assert_eq!(client.get_member_earnings(&member2, &group_id), 500);
+
+    // The first distribution after the notice period pays out under the new split
+    test_env.env.ledger().set_timestamp(NOTICE);
+    client.distribute(&group_id, &token, &1000, &sender);
+    assert_eq!(client.get_member_earnings(&member2, &group_id), 700);
+    assert_eq!(client.get_group_members(&group_id), new_split);
+    assert!(client.get_pending_split_change(&group_id).is_none());
+}
+
+#[test]
+fn test_cancelled_change_and_non_reducing_change() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let member1 = Address::generate(&test_env.env);
+    let member2 = Address::generate(&test_env.env);
+    let newcomer = Address::generate(&test_env.env);
+    let old_split = split(&test_env, &[(member1.clone(), 50), (member2.clone(), 50)]);
+    let (group_id, creator) = setup_group(&test_env, &old_split);
+
+    // Swapping in a newcomer for a departing member still lowers member2's share to zero
+    let new_split = split(&test_env, &[(member1.clone(), 50), (newcomer, 50)]);
+    client.update_members(&group_id, &creator, &new_split);
+    assert!(client.get_pending_split_change(&group_id).is_some());
+
+    client.cancel_split_change(&group_id, &creator);
+    assert!(client.get_pending_split_change(&group_id).is_none());
+
+    let same_split = split(&test_env, &[(member2.clone(), 50), (member1.clone(), 50)]);
+    client.update_members(&group_id, &creator, &same_split);
+    assert_eq!(client.get_group_members(&group_id), same_split);
+}
+
+#[test]
+#[should_panic(expected = "ContractPaused")]
+fn test_cancel_split_change_fails_when_paused() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let member1 = Address::generate(&test_env.env);
+    let member2 = Address::generate(&test_env.env);
+    let old_split = split(&test_env, &[(member1.clone(), 50), (member2.clone(), 50)]);
+    let (group_id, creator) = setup_group(&test_env, &old_split);
+
+    client.update_members(
+        &group_id,
+        &creator,
+        &split(&test_env, &[(member1, 70), (member2, 30)]),
+    );
+    client.pause(&test_env.admin);
+    client.cancel_split_change(&group_id, &creator);
+}
+
+#[test]
+fn test_removal_is_queued_until_applied() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let member1 = Address::generate(&test_env.env);
+    let member2 = Address::generate(&test_env.env);
+    let old_split = split(&test_env, &[(member1.clone(), 50), (member2.clone(), 50)]);
+    let (group_id, creator) = setup_group(&test_env, &old_split);
+
+    client.remove_group_member(&group_id, &creator, &member2, &RebalanceStrategy::Exact);
+    assert!(client.is_group_member(&group_id, &member2));
+    assert!(client.try_apply_split_change(&group_id).is_err());
+
+    test_env.env.ledger().set_timestamp(NOTICE);
+    client.apply_split_change(&group_id);
+    assert!(!client.is_group_member(&group_id, &member2));
+}
+
+#[test]
+fn test_shortened_notice_waits_for_previous_period() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let members = split(&test_env, &[(Address::generate(&test_env.env), 100)]);
+    let (group_id, creator) = setup_group(&test_env, &members);
+
+    client.set_notice_period(&group_id, &creator, &0);
+    assert_eq!(client.get_notice_period(&group_id), NOTICE);
+
+    test_env.env.ledger().set_timestamp(NOTICE);
+    assert_eq!(client.get_notice_period(&group_id), 0);
+}
+
+#[test]
+fn test_leaving_cancels_queued_change() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let sender = test_env.users.get(1).unwrap();
+    let member1 = Address::generate(&test_env.env);
+    let member2 = Address::generate(&test_env.env);
+    let old_split = split(&test_env, &[(member1.clone(), 50), (member2.clone(), 50)]);
+    let (group_id, creator) = setup_group(&test_env, &old_split);
+
+    client.remove_group_member(&group_id, &creator, &member2, &RebalanceStrategy::Exact);
+    client.leave_group(&group_id, &member2);
+
+    // The queued removal was written for the old member set, so leaving cancels it
+    assert!(client.get_pending_split_change(&group_id).is_none());
+    test_env.env.ledger().set_timestamp(NOTICE);
+    fund_user_with_tokens(&test_env.env, &token, &sender, 1000);
+    client.distribute(&group_id, &token, &1000, &sender);
+    assert_eq!(client.get_member_earnings(&member1, &group_id), 1000);
+    assert!(client.get_pending_split_change(&group_id).is_none());
+}
+
+#[test]
+fn test_queued_change_follows_new_payout_address() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let member1 = Address::generate(&test_env.env);
+    let member2 = Address::generate(&test_env.env);
+    let new_address = Address::generate(&test_env.env);
+    let old_split = split(&test_env, &[(member1.clone(), 50), (member2.clone(), 50)]);
+    let new_split = split(&test_env, &[(member1.clone(), 80), (member2.clone(), 20)]);
+    let (group_id, creator) = setup_group(&test_env, &old_split);
+
+    client.update_members(&group_id, &creator, &new_split);
+    client.update_payout_address(&group_id, &member2, &new_address);
+
+    let pending = client.get_pending_split_change(&group_id).unwrap();
+    assert_eq!(
+        pending.action,
+        ProposalAction::UpdateMembers(split(
+            &test_env,
+            &[(member1.clone(), 80), (new_address.clone(), 20)]
+        ))
+    );
+
+    test_env.env.ledger().set_timestamp(NOTICE);
+    client.apply_split_change(&group_id);
+    let members = client.get_group_members(&group_id);
+    assert_eq!(members.get(1).unwrap().address, new_address);
+    assert_eq!(members.get(1).unwrap().percentage, 20);
+}
+
+#[test]
+fn test_added_member_taking_shares_is_queued() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let member1 = Address::generate(&test_env.env);
+    let member2 = Address::generate(&test_env.env);
+    let newcomer = Address::generate(&test_env.env);
+    let old_split = split(&test_env, &[(member1.clone(), 50), (member2.clone(), 50)]);
+    let (group_id, creator) = setup_group(&test_env, &old_split);
+
+    client.add_group_member(
+        &group_id,
+        &creator,
+        &newcomer,
+        &20,
+        &RebalanceStrategy::ProRata,
+    );
+    assert_eq!(client.get_group_members(&group_id), old_split);
+
+    test_env.env.ledger().set_timestamp(NOTICE);
+    client.apply_split_change(&group_id);
+    assert!(client.is_group_member(&group_id, &newcomer));
+    assert_eq!(
+        client
+            .get_group_members(&group_id)
+            .get(0)
+            .unwrap()
+            .percentage,
+        40
+    );
+}
+
+#[test]
+fn test_immediate_change_keeps_queued_change() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let member1 = Address::generate(&test_env.env);
+    let member2 = Address::generate(&test_env.env);
+    let old_split = split(&test_env, &[(member1.clone(), 50), (member2.clone(), 50)]);
+    let (group_id, creator) = setup_group(&test_env, &old_split);
+
+    client.remove_group_member(&group_id, &creator, &member2, &RebalanceStrategy::ProRata);
+
+    let reordered = split(&test_env, &[(member2.clone(), 50), (member1.clone(), 50)]);
+    client.update_members(&group_id, &creator, &reordered);
+    assert_eq!(client.get_group_members(&group_id), reordered);
+    assert!(client.get_pending_split_change(&group_id).is_some());
+}
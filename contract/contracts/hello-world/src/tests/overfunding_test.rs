@@ -0,0 +1,116 @@
+use crate::base::types::OverfundingPolicy;
+use crate::test_utils::{
+    assert_balance, create_test_group, create_test_members, fund_user_with_tokens, setup_test_env,
+    TestEnv,
+};
+use crate::AutoShareContractClient;
+use soroban_sdk::{Address, BytesN};
+
+fn setup_campaign(test_env: &TestEnv, target_amount: i128) -> (BytesN<32>, Address, Address) {
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let members = create_test_members(&test_env.env, 2);
+    let group_id = create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        &creator,
+        &members,
+        10,
+        &token,
+    );
+    client.start_fundraising(&group_id, &creator, &target_amount);
+    (group_id, creator, token)
+}
+
+#[test]
+fn test_default_policy_accepts_whole_contribution() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, _creator, token) = setup_campaign(&test_env, 1000);
+    let contributor = test_env.users.get(1).unwrap();
+
+    fund_user_with_tokens(&test_env.env, &token, &contributor, 1500);
+    let accepted = client.contribute(&group_id, &token, &1500, &contributor);
+
+    assert_eq!(accepted, 1500);
+    let status = client.get_fundraising_status(&group_id);
+    assert_eq!(status.overfunding_policy, OverfundingPolicy::CloseAtTarget);
+    assert_eq!(status.total_raised, 1500);
+    assert!(!status.is_active);
+}
+
+#[test]
+fn test_refund_excess_accepts_only_remainder() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, token) = setup_campaign(&test_env, 1000);
+    let contributor = test_env.users.get(1).unwrap();
+
+    client.set_overfunding_policy(&group_id, &creator, &OverfundingPolicy::RefundExcess, &0);
+
+    fund_user_with_tokens(&test_env.env, &token, &contributor, 1500);
+    client.contribute(&group_id, &token, &700, &contributor);
+    let accepted = client.contribute(&group_id, &token, &800, &contributor);
+
+    assert_eq!(accepted, 300);
+    assert_balance(&test_env.env, &token, &contributor, 500);
+
+    let status = client.get_fundraising_status(&group_id);
+    assert_eq!(status.total_raised, 1000);
+    assert!(!status.is_active);
+
+    let contributions = client.get_group_contributions(&group_id);
+    assert_eq!(contributions.get(1).unwrap().amount, 300);
+}
+
+#[test]
+fn test_allow_up_to_cap_keeps_campaign_open_past_target() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, token) = setup_campaign(&test_env, 1000);
+    let contributor = test_env.users.get(1).unwrap();
+
+    client.set_overfunding_policy(&group_id, &creator, &OverfundingPolicy::AllowUpToCap, &1500);
+
+    fund_user_with_tokens(&test_env.env, &token, &contributor, 2000);
+    client.contribute(&group_id, &token, &1200, &contributor);
+
+    let status = client.get_fundraising_status(&group_id);
+    assert_eq!(status.total_raised, 1200);
+    assert!(status.is_active);
+    assert!(client.is_fundraising_target_reached(&group_id));
+
+    // Everything above the hard cap is refunded
+    let accepted = client.contribute(&group_id, &token, &800, &contributor);
+    assert_eq!(accepted, 300);
+    assert_balance(&test_env.env, &token, &contributor, 500);
+
+    let status = client.get_fundraising_status(&group_id);
+    assert_eq!(status.total_raised, 1500);
+    assert!(!status.is_active);
+}
+
+#[test]
+#[should_panic(expected = "ContributionExceedsTarget")]
+fn test_reject_policy_rejects_overfunding() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, token) = setup_campaign(&test_env, 1000);
+    let contributor = test_env.users.get(1).unwrap();
+
+    client.set_overfunding_policy(&group_id, &creator, &OverfundingPolicy::Reject, &0);
+
+    fund_user_with_tokens(&test_env.env, &token, &contributor, 1001);
+    client.contribute(&group_id, &token, &1001, &contributor);
+}
+
+#[test]
+#[should_panic(expected = "InvalidAmount")]
+fn test_hard_cap_below_target_fails() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, _token) = setup_campaign(&test_env, 1000);
+
+    client.set_overfunding_policy(&group_id, &creator, &OverfundingPolicy::AllowUpToCap, &900);
+}
@@ -0,0 +1,130 @@
+use crate::base::types::ManagerScope;
+use crate::test_utils::{create_test_group, create_test_members, setup_test_env, TestEnv};
+use crate::AutoShareContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, String};
+
+fn setup_group(test_env: &TestEnv) -> (BytesN<32>, Address) {
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let members = create_test_members(&test_env.env, 2);
+    let group_id = create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        &creator,
+        &members,
+        10,
+        &token,
+    );
+    (group_id, creator)
+}
+
+#[test]
+fn test_two_step_ownership_transfer() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator) = setup_group(&test_env);
+    let new_creator = Address::generate(&test_env.env);
+
+    client.propose_ownership_transfer(&group_id, &creator, &new_creator);
+    assert_eq!(
+        client.get_pending_owner(&group_id),
+        Some(new_creator.clone())
+    );
+    assert_eq!(client.get(&group_id).creator, creator);
+
+    client.accept_ownership_transfer(&group_id, &new_creator);
+
+    assert_eq!(client.get(&group_id).creator, new_creator);
+    assert_eq!(client.get_pending_owner(&group_id), None);
+    assert_eq!(client.get_groups_by_creator(&creator).len(), 0);
+    assert_eq!(client.get_groups_by_creator(&new_creator).len(), 1);
+    assert_eq!(
+        client
+            .get_groups_by_creator_paginated(&new_creator, &None, &10)
+            .groups
+            .len(),
+        1
+    );
+
+    // Only the new creator can administer the group from now on
+    let name = String::from_str(&test_env.env, "New Owner");
+    assert!(client
+        .try_update_group_name(&group_id, &creator, &name)
+        .is_err());
+    client.update_group_name(&group_id, &new_creator, &name);
+}
+
+#[test]
+fn test_accepting_manager_loses_manager_entry() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator) = setup_group(&test_env);
+    let manager = Address::generate(&test_env.env);
+
+    let scope = ManagerScope {
+        manage_members: true,
+        change_splits: false,
+        rename: false,
+        fundraising: false,
+        activation: false,
+    };
+    client.set_manager(&group_id, &creator, &manager, &scope);
+    client.propose_ownership_transfer(&group_id, &creator, &manager);
+    client.accept_ownership_transfer(&group_id, &manager);
+
+    assert_eq!(client.get_managers(&group_id).len(), 0);
+}
+
+#[test]
+fn test_cancel_ownership_transfer() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator) = setup_group(&test_env);
+    let new_creator = Address::generate(&test_env.env);
+
+    client.propose_ownership_transfer(&group_id, &creator, &new_creator);
+    client.cancel_ownership_transfer(&group_id, &creator);
+
+    assert_eq!(client.get_pending_owner(&group_id), None);
+    assert!(client
+        .try_accept_ownership_transfer(&group_id, &new_creator)
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "ContractPaused")]
+fn test_cancel_ownership_transfer_fails_when_paused() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator) = setup_group(&test_env);
+    let new_creator = Address::generate(&test_env.env);
+
+    client.propose_ownership_transfer(&group_id, &creator, &new_creator);
+    client.pause(&test_env.admin);
+    client.cancel_ownership_transfer(&group_id, &creator);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_only_proposed_creator_can_accept() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator) = setup_group(&test_env);
+    let new_creator = Address::generate(&test_env.env);
+    let stranger = Address::generate(&test_env.env);
+
+    client.propose_ownership_transfer(&group_id, &creator, &new_creator);
+    client.accept_ownership_transfer(&group_id, &stranger);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_only_creator_can_propose() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, _creator) = setup_group(&test_env);
+    let stranger = test_env.users.get(1).unwrap();
+
+    client.propose_ownership_transfer(&group_id, &stranger, &stranger);
+}
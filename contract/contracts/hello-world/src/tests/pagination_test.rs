@@ -1,9 +1,35 @@
+use crate::base::types::GroupFilter;
 use crate::test_utils::{create_test_group, setup_test_env};
 use crate::AutoShareContractClient;
 use soroban_sdk::{testutils::Address as _, Address, Vec};
 
+fn no_filter() -> GroupFilter {
+    GroupFilter {
+        is_active: None,
+        creator: None,
+        member: None,
+        fundraising_active: false,
+        usages_below: None,
+        token: None,
+    }
+}
+
+/// Counts every group by walking `query_groups` to the end.
+fn count_queried_groups(client: &AutoShareContractClient) -> u32 {
+    let mut count = 0;
+    let mut cursor = None;
+    loop {
+        let page = client.query_groups(&no_filter(), &cursor, &50);
+        count += page.groups.len();
+        cursor = Some(page.next_cursor.clone());
+        if page.next_cursor.remaining == 0 {
+            return count;
+        }
+    }
+}
+
 #[test]
-fn test_get_groups_paginated() {
+fn test_query_groups_pages() {
     let test_env = setup_test_env();
     let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
 
@@ -29,47 +55,38 @@ fn test_get_groups_paginated() {
     }
 
     // Test first page
-    let page1 = client.get_groups_paginated(&0, &10);
+    let page1 = client.query_groups(&no_filter(), &None, &10);
     assert_eq!(page1.groups.len(), 10);
-    assert_eq!(page1.total, 25);
-    assert_eq!(page1.offset, 0);
-    assert_eq!(page1.limit, 10);
+    assert_eq!(page1.next_cursor.remaining, 15);
 
     // Test second page
-    let page2 = client.get_groups_paginated(&10, &10);
+    let page2 = client.query_groups(&no_filter(), &Some(page1.next_cursor), &10);
     assert_eq!(page2.groups.len(), 10);
-    assert_eq!(page2.offset, 10);
 
     // Test third page (remaining 5)
-    let page3 = client.get_groups_paginated(&20, &10);
+    let page3 = client.query_groups(&no_filter(), &Some(page2.next_cursor), &10);
     assert_eq!(page3.groups.len(), 5);
-    assert_eq!(page3.offset, 20);
+    assert_eq!(page3.next_cursor.remaining, 0);
 
-    // Test limit cap (should cap at 20)
-    let page_capped = client.get_groups_paginated(&0, &50);
-    assert_eq!(page_capped.groups.len(), 20);
-    assert_eq!(page_capped.limit, 20);
-
-    // Test offset out of bounds
-    let page_empty = client.get_groups_paginated(&30, &10);
-    assert_eq!(page_empty.groups.len(), 0);
-    assert_eq!(page_empty.total, 25);
+    // Test one page holding every group
+    let page_all = client.query_groups(&no_filter(), &None, &50);
+    assert_eq!(page_all.groups.len(), 25);
+    assert_eq!(page_all.next_cursor.remaining, 0);
 
     // Test zero limit
-    let page_zero_limit = client.get_groups_paginated(&0, &0);
+    let page_zero_limit = client.query_groups(&no_filter(), &None, &0);
     assert_eq!(page_zero_limit.groups.len(), 0);
-    assert_eq!(page_zero_limit.total, 25);
-    assert_eq!(page_zero_limit.limit, 0);
+    assert_eq!(page_zero_limit.next_cursor.remaining, 25);
 }
 
 #[test]
-fn test_get_groups_paginated_empty() {
+fn test_query_groups_empty() {
     let test_env = setup_test_env();
     let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
 
-    let page = client.get_groups_paginated(&0, &10);
+    let page = client.query_groups(&no_filter(), &None, &10);
     assert_eq!(page.groups.len(), 0);
-    assert_eq!(page.total, 0);
+    assert_eq!(page.next_cursor.remaining, 0);
 }
 
 #[test]
@@ -156,32 +173,71 @@ fn test_get_groups_by_creator_paginated() {
     }
 
     // Test Creator 1 - first page
-    let c1_page1 = client.get_groups_by_creator_paginated(&creator1, &0, &10);
+    let c1_page1 = client.get_groups_by_creator_paginated(&creator1, &None, &10);
     assert_eq!(c1_page1.groups.len(), 10);
-    assert_eq!(c1_page1.total, 15);
-    assert_eq!(c1_page1.offset, 0);
+    assert_eq!(c1_page1.next_cursor.remaining, 5);
 
     // Test Creator 1 - second page
-    let c1_page2 = client.get_groups_by_creator_paginated(&creator1, &10, &10);
+    let c1_page2 =
+        client.get_groups_by_creator_paginated(&creator1, &Some(c1_page1.next_cursor), &10);
     assert_eq!(c1_page2.groups.len(), 5);
-    assert_eq!(c1_page2.total, 15);
-    assert_eq!(c1_page2.offset, 10);
+    assert_eq!(c1_page2.next_cursor.remaining, 0);
 
     // Test Creator 2 - first page
-    let c2_page1 = client.get_groups_by_creator_paginated(&creator2, &0, &5);
+    let c2_page1 = client.get_groups_by_creator_paginated(&creator2, &None, &5);
     assert_eq!(c2_page1.groups.len(), 5);
-    assert_eq!(c2_page1.total, 10);
+    assert!(c2_page1
+        .groups
+        .iter()
+        .all(|group| group.creator == creator2));
 
     // Test limit cap for Creator 1
-    let c1_capped = client.get_groups_by_creator_paginated(&creator1, &0, &50);
+    let c1_capped = client.get_groups_by_creator_paginated(&creator1, &None, &50);
     assert_eq!(c1_capped.groups.len(), 15); // only 15 exist
-    assert_eq!(c1_capped.limit, 20);
 
     // Test Creator 3 (none)
     let creator3 = Address::generate(&test_env.env);
-    let c3_page = client.get_groups_by_creator_paginated(&creator3, &0, &10);
+    let c3_page = client.get_groups_by_creator_paginated(&creator3, &None, &10);
     assert_eq!(c3_page.groups.len(), 0);
-    assert_eq!(c3_page.total, 0);
+    assert_eq!(c3_page.next_cursor.remaining, 0);
+}
+
+#[test]
+fn test_get_groups_paginated_counts_from_newest() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap().clone();
+    let token = test_env.mock_tokens.get(0).unwrap().clone();
+
+    let mut members = Vec::new(&test_env.env);
+    members.push_back(crate::base::types::GroupMember {
+        address: Address::generate(&test_env.env),
+        percentage: 100,
+    });
+    let mut ids = Vec::new(&test_env.env);
+    for i in 1..=3 {
+        ids.push_back(create_test_group(
+            &test_env.env,
+            &test_env.autoshare_contract,
+            &creator,
+            &members,
+            i,
+            &token,
+        ));
+    }
+
+    let page1 = client.get_groups_paginated(&0, &2);
+    assert_eq!(page1.total, 3);
+    assert_eq!(page1.groups.len(), 2);
+    assert_eq!(page1.groups.get(0).unwrap().id, ids.get(2).unwrap());
+
+    let page2 = client.get_groups_paginated(&2, &50);
+    assert_eq!(page2.offset, 2);
+    assert_eq!(page2.limit, 20);
+    assert_eq!(page2.groups.len(), 1);
+    assert_eq!(page2.groups.get(0).unwrap().id, ids.get(0).unwrap());
+
+    assert_eq!(client.get_groups_paginated(&3, &10).groups.len(), 0);
 }
 
 #[test]
@@ -212,9 +268,7 @@ fn test_get_group_count_single_group() {
     assert_eq!(client.get_group_count(), 1);
 
     // Verify consistency with paginated query
-    let page = client.get_groups_paginated(&0, &10);
-    assert_eq!(page.total, 1);
-    assert_eq!(client.get_group_count(), page.total);
+    assert_eq!(count_queried_groups(&client), 1);
 }
 
 #[test]
@@ -248,9 +302,7 @@ fn test_get_group_count_large_scale() {
     assert_eq!(count, 1000);
 
     // Verify consistency with paginated query
-    let page = client.get_groups_paginated(&0, &1);
-    assert_eq!(page.total, 1000);
-    assert_eq!(count, page.total);
+    assert_eq!(count_queried_groups(&client), count);
 }
 
 #[test]
@@ -329,9 +381,7 @@ fn test_get_group_count_after_deletion() {
     assert_eq!(client.get_group_count(), 1);
 
     // Verify consistency with paginated query
-    let page = client.get_groups_paginated(&0, &10);
-    assert_eq!(page.total, 1);
-    assert_eq!(client.get_group_count(), page.total);
+    assert_eq!(count_queried_groups(&client), 1);
 
     // Delete the last group
     client.deactivate_group(&id3, &creator);
@@ -339,9 +389,7 @@ fn test_get_group_count_after_deletion() {
     assert_eq!(client.get_group_count(), 0);
 
     // Verify empty state
-    let page_empty = client.get_groups_paginated(&0, &10);
-    assert_eq!(page_empty.total, 0);
-    assert_eq!(client.get_group_count(), page_empty.total);
+    assert_eq!(count_queried_groups(&client), 0);
 }
 
 #[test]
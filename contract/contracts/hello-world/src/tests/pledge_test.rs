@@ -0,0 +1,222 @@
+use crate::test_utils::{
+    create_test_group, create_test_members, deploy_stellar_asset, mint_stellar_asset,
+    setup_test_env, TestEnv,
+};
+use crate::AutoShareContractClient;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, BytesN};
+
+const MONTH: u64 = 30 * 24 * 60 * 60;
+
+/// Creates a group with a running campaign and a supported token that supports allowances.
+fn setup_pledge_campaign(test_env: &TestEnv, target_amount: i128) -> (BytesN<32>, Address) {
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let fee_token = test_env.mock_tokens.get(0).unwrap();
+    let members = create_test_members(&test_env.env, 2);
+    let group_id = create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        &creator,
+        &members,
+        10,
+        &fee_token,
+    );
+    client.start_fundraising(&group_id, &creator, &target_amount);
+
+    let token = deploy_stellar_asset(&test_env.env);
+    client.add_supported_token(&token, &test_env.admin);
+    (group_id, token)
+}
+
+fn approve_pledge(test_env: &TestEnv, token: &Address, pledger: &Address, amount: i128) {
+    mint_stellar_asset(&test_env.env, token, pledger, amount);
+    TokenClient::new(&test_env.env, token).approve(
+        pledger,
+        &test_env.autoshare_contract,
+        &amount,
+        &1000,
+    );
+}
+
+#[test]
+fn test_create_and_collect_pledge() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, token) = setup_pledge_campaign(&test_env, 10_000);
+    let pledger = test_env.users.get(1).unwrap();
+
+    approve_pledge(&test_env, &token, &pledger, 300);
+    let pledge_id = client.create_pledge(&group_id, &pledger, &token, &100, &MONTH, &3);
+
+    // First payment is due immediately
+    assert_eq!(client.collect_pledge(&pledge_id), 100);
+
+    let pledge = client.get_pledge(&pledge_id);
+    assert_eq!(pledge.payments_made, 1);
+    assert_eq!(pledge.next_payment_at, MONTH);
+    assert!(pledge.is_active);
+
+    // Collected payments go through the contribution path
+    let status = client.get_fundraising_status(&group_id);
+    assert_eq!(status.total_raised, 100);
+    let contributions = client.get_group_contributions(&group_id);
+    assert_eq!(contributions.len(), 1);
+    assert_eq!(contributions.get(0).unwrap().contributor, pledger);
+    assert_eq!(
+        TokenClient::new(&test_env.env, &token).balance(&pledger),
+        200
+    );
+}
+
+#[test]
+fn test_pledge_completes_after_max_payments() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, token) = setup_pledge_campaign(&test_env, 10_000);
+    let pledger = test_env.users.get(1).unwrap();
+
+    approve_pledge(&test_env, &token, &pledger, 200);
+    let pledge_id = client.create_pledge(&group_id, &pledger, &token, &100, &MONTH, &2);
+
+    client.collect_pledge(&pledge_id);
+    test_env.env.ledger().set_timestamp(MONTH);
+    client.collect_pledge(&pledge_id);
+
+    assert!(!client.get_pledge(&pledge_id).is_active);
+    assert_eq!(client.get_fundraising_status(&group_id).total_raised, 200);
+    assert!(client.try_collect_pledge(&pledge_id).is_err());
+}
+
+#[test]
+#[should_panic(expected = "PledgeNotDue")]
+fn test_collect_pledge_before_due_fails() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, token) = setup_pledge_campaign(&test_env, 10_000);
+    let pledger = test_env.users.get(1).unwrap();
+
+    approve_pledge(&test_env, &token, &pledger, 300);
+    let pledge_id = client.create_pledge(&group_id, &pledger, &token, &100, &MONTH, &3);
+
+    client.collect_pledge(&pledge_id);
+    test_env.env.ledger().set_timestamp(MONTH - 1);
+    client.collect_pledge(&pledge_id);
+}
+
+#[test]
+#[should_panic(expected = "PledgeNotActive")]
+fn test_cancelled_pledge_cannot_be_collected() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, token) = setup_pledge_campaign(&test_env, 10_000);
+    let pledger = test_env.users.get(1).unwrap();
+
+    approve_pledge(&test_env, &token, &pledger, 300);
+    let pledge_id = client.create_pledge(&group_id, &pledger, &token, &100, &MONTH, &3);
+
+    client.cancel_pledge(&pledge_id, &pledger);
+    client.collect_pledge(&pledge_id);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_only_pledger_can_cancel() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, token) = setup_pledge_campaign(&test_env, 10_000);
+    let pledger = test_env.users.get(1).unwrap();
+    let stranger = test_env.users.get(2).unwrap();
+
+    approve_pledge(&test_env, &token, &pledger, 100);
+    let pledge_id = client.create_pledge(&group_id, &pledger, &token, &100, &MONTH, &3);
+    client.cancel_pledge(&pledge_id, &stranger);
+}
+
+#[test]
+#[should_panic(expected = "ContractPaused")]
+fn test_cancel_pledge_fails_when_paused() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, token) = setup_pledge_campaign(&test_env, 10_000);
+    let pledger = test_env.users.get(1).unwrap();
+
+    approve_pledge(&test_env, &token, &pledger, 100);
+    let pledge_id = client.create_pledge(&group_id, &pledger, &token, &100, &MONTH, &3);
+    client.pause(&test_env.admin);
+    client.cancel_pledge(&pledge_id, &pledger);
+}
+
+#[test]
+#[should_panic(expected = "InsufficientAllowance")]
+fn test_pledge_requires_allowance() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, token) = setup_pledge_campaign(&test_env, 10_000);
+    let pledger = test_env.users.get(1).unwrap();
+
+    approve_pledge(&test_env, &token, &pledger, 99);
+    client.create_pledge(&group_id, &pledger, &token, &100, &MONTH, &3);
+}
+
+#[test]
+fn test_missed_periods_are_skipped() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, token) = setup_pledge_campaign(&test_env, 10_000);
+    let pledger = test_env.users.get(1).unwrap();
+
+    approve_pledge(&test_env, &token, &pledger, 500);
+    let pledge_id = client.create_pledge(&group_id, &pledger, &token, &100, &MONTH, &5);
+
+    // Three and a half periods pass without a collection
+    test_env.env.ledger().set_timestamp(3 * MONTH + MONTH / 2);
+    assert_eq!(client.collect_pledge(&pledge_id), 100);
+    assert_eq!(client.get_pledge(&pledge_id).next_payment_at, 4 * MONTH);
+    assert!(client.try_collect_pledge(&pledge_id).is_err());
+    assert_eq!(client.get_fundraising_status(&group_id).total_raised, 100);
+}
+
+#[test]
+fn test_group_pledge_slots_are_capped_and_freed() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, token) = setup_pledge_campaign(&test_env, 10_000);
+    let pledger = test_env.users.get(1).unwrap();
+
+    approve_pledge(&test_env, &token, &pledger, 100);
+    let mut first = 0;
+    for index in 0..50 {
+        let pledge_id = client.create_pledge(&group_id, &pledger, &token, &1, &MONTH, &3);
+        if index == 0 {
+            first = pledge_id;
+        }
+    }
+    assert!(client
+        .try_create_pledge(&group_id, &pledger, &token, &1, &MONTH, &3)
+        .is_err());
+
+    // Cancelled pledges leave the group's list
+    client.cancel_pledge(&first, &pledger);
+    assert_eq!(client.get_group_pledges(&group_id).len(), 49);
+    client.create_pledge(&group_id, &pledger, &token, &1, &MONTH, &3);
+}
+
+#[test]
+fn test_get_group_pledges() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, token) = setup_pledge_campaign(&test_env, 10_000);
+    let pledger1 = test_env.users.get(1).unwrap();
+    let pledger2 = test_env.users.get(2).unwrap();
+
+    approve_pledge(&test_env, &token, &pledger1, 300);
+    approve_pledge(&test_env, &token, &pledger2, 600);
+    client.create_pledge(&group_id, &pledger1, &token, &100, &MONTH, &3);
+    client.create_pledge(&group_id, &pledger2, &token, &50, &MONTH, &12);
+
+    let pledges = client.get_group_pledges(&group_id);
+    assert_eq!(pledges.len(), 2);
+    assert_eq!(pledges.get(1).unwrap().pledger, pledger2);
+}
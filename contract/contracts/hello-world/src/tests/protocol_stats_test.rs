@@ -0,0 +1,147 @@
+use crate::autoshare_logic::StatsKey;
+use crate::base::types::TokenAmount;
+use crate::test_utils::{
+    create_test_group, create_test_members, fund_user_with_tokens, setup_test_env,
+};
+use crate::AutoShareContractClient;
+use soroban_sdk::Vec;
+
+#[test]
+fn test_protocol_stats_track_activity() {
+    let test_env = setup_test_env();
+    let env = &test_env.env;
+    let client = AutoShareContractClient::new(env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let sender = test_env.users.get(1).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+
+    let first = create_test_group(
+        env,
+        &test_env.autoshare_contract,
+        &creator,
+        &create_test_members(env, 2),
+        3,
+        &token,
+    );
+    let second = create_test_group(
+        env,
+        &test_env.autoshare_contract,
+        &creator,
+        &create_test_members(env, 3),
+        4,
+        &token,
+    );
+    fund_user_with_tokens(env, &token, &sender, 1000);
+    client.distribute(&first, &token, &400, &sender);
+    client.start_fundraising(&second, &creator, &1000);
+    client.contribute(&second, &token, &100, &sender);
+
+    let stats = client.get_protocol_stats();
+    assert_eq!(stats.total_groups, 2);
+    assert_eq!(stats.active_groups, 2);
+    assert_eq!(stats.total_members, 5);
+    assert_eq!(stats.total_distributions, 1);
+    assert_eq!(stats.total_contributions, 1);
+    assert_eq!(
+        stats.distributed_volume,
+        Vec::from_array(
+            env,
+            [TokenAmount {
+                token: token.clone(),
+                amount: 500
+            }]
+        )
+    );
+    assert_eq!(
+        stats.fees_collected,
+        Vec::from_array(
+            env,
+            [TokenAmount {
+                token: token.clone(),
+                amount: 70
+            }]
+        )
+    );
+    assert_eq!(
+        stats.amount_raised,
+        Vec::from_array(
+            env,
+            [TokenAmount {
+                token: token.clone(),
+                amount: 100
+            }]
+        )
+    );
+
+    client.deactivate_group(&first, &creator);
+    assert_eq!(client.get_protocol_stats().active_groups, 1);
+
+    client.delete_group(&first, &creator);
+    let stats = client.get_protocol_stats();
+    assert_eq!(stats.total_groups, 1);
+    assert_eq!(stats.active_groups, 1);
+    assert_eq!(stats.total_members, 3);
+}
+
+#[test]
+fn test_backfill_counts_groups_that_predate_the_counters() {
+    let test_env = setup_test_env();
+    let env = &test_env.env;
+    let client = AutoShareContractClient::new(env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+
+    let first = create_test_group(
+        env,
+        &test_env.autoshare_contract,
+        &creator,
+        &create_test_members(env, 2),
+        3,
+        &token,
+    );
+    let second = create_test_group(
+        env,
+        &test_env.autoshare_contract,
+        &creator,
+        &create_test_members(env, 3),
+        4,
+        &token,
+    );
+
+    // Forget both groups, as on a deployment created before the counters existed
+    env.as_contract(&test_env.autoshare_contract, || {
+        let storage = env.storage().persistent();
+        storage.remove(&StatsKey::ActiveGroups);
+        storage.remove(&StatsKey::TotalMembers);
+        storage.remove(&StatsKey::GroupCounted(first.clone()));
+        storage.remove(&StatsKey::GroupCounted(second.clone()));
+    });
+
+    // Changes to uncounted groups leave the counters alone instead of driving them negative
+    client.deactivate_group(&first, &creator);
+    let stats = client.get_protocol_stats();
+    assert_eq!(stats.active_groups, 0);
+    assert_eq!(stats.total_members, 0);
+
+    assert_eq!(client.backfill_protocol_stats(&test_env.admin, &0, &1), 1);
+    assert_eq!(client.backfill_protocol_stats(&test_env.admin, &1, &10), 1);
+    // Running a batch again does not count groups twice
+    assert_eq!(client.backfill_protocol_stats(&test_env.admin, &0, &10), 0);
+
+    let stats = client.get_protocol_stats();
+    assert_eq!(stats.active_groups, 1);
+    assert_eq!(stats.total_members, 5);
+
+    client.activate_group(&first, &creator);
+    assert_eq!(client.get_protocol_stats().active_groups, 2);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_backfill_protocol_stats_is_admin_only() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let stranger = test_env.users.get(1).unwrap();
+
+    client.backfill_protocol_stats(&stranger, &0, &10);
+}
@@ -0,0 +1,136 @@
+use crate::base::types::{GroupMember, RebalanceStrategy};
+use crate::test_utils::{create_test_group, fund_user_with_tokens, setup_test_env, TestEnv};
+use crate::AutoShareContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Vec};
+
+/// Creates a group split 50/30/20 and returns its member addresses in order.
+fn setup_group(test_env: &TestEnv) -> (BytesN<32>, Address, Vec<Address>) {
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let mut addresses = Vec::new(&test_env.env);
+    let mut members = Vec::new(&test_env.env);
+    for percentage in [50u32, 30, 20] {
+        let address = Address::generate(&test_env.env);
+        addresses.push_back(address.clone());
+        members.push_back(GroupMember {
+            address,
+            percentage,
+        });
+    }
+    let group_id = create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        &creator,
+        &members,
+        10,
+        &token,
+    );
+    (group_id, creator, addresses)
+}
+
+fn percentages(client: &AutoShareContractClient, group_id: &BytesN<32>) -> Vec<u32> {
+    let mut result = Vec::new(&client.env);
+    for member in client.get_group_members(group_id).iter() {
+        result.push_back(member.percentage);
+    }
+    result
+}
+
+#[test]
+fn test_remove_pro_rata_keeps_group_distributable() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let sender = test_env.users.get(1).unwrap();
+    let (group_id, creator, addresses) = setup_group(&test_env);
+
+    client.remove_group_member(
+        &group_id,
+        &creator,
+        &addresses.get(0).unwrap(),
+        &RebalanceStrategy::ProRata,
+    );
+    assert_eq!(
+        percentages(&client, &group_id),
+        Vec::from_array(&test_env.env, [60, 40])
+    );
+
+    fund_user_with_tokens(&test_env.env, &token, &sender, 1000);
+    client.distribute(&group_id, &token, &1000, &sender);
+}
+
+#[test]
+fn test_remove_gives_freed_share_to_named_member() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, addresses) = setup_group(&test_env);
+
+    client.remove_group_member(
+        &group_id,
+        &creator,
+        &addresses.get(1).unwrap(),
+        &RebalanceStrategy::Member(addresses.get(2).unwrap()),
+    );
+    assert_eq!(
+        percentages(&client, &group_id),
+        Vec::from_array(&test_env.env, [50, 50])
+    );
+}
+
+#[test]
+fn test_add_dilutes_existing_members() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, _addresses) = setup_group(&test_env);
+    let newcomer = Address::generate(&test_env.env);
+
+    client.add_group_member(
+        &group_id,
+        &creator,
+        &newcomer,
+        &50,
+        &RebalanceStrategy::ProRata,
+    );
+    assert_eq!(
+        percentages(&client, &group_id),
+        Vec::from_array(&test_env.env, [25, 15, 10, 50])
+    );
+}
+
+#[test]
+fn test_add_takes_share_from_named_member() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, addresses) = setup_group(&test_env);
+    let newcomer = Address::generate(&test_env.env);
+
+    client.add_group_member(
+        &group_id,
+        &creator,
+        &newcomer,
+        &10,
+        &RebalanceStrategy::Member(addresses.get(0).unwrap()),
+    );
+    assert_eq!(
+        percentages(&client, &group_id),
+        Vec::from_array(&test_env.env, [40, 30, 20, 10])
+    );
+}
+
+#[test]
+#[should_panic(expected = "InvalidInput")]
+fn test_named_member_cannot_give_up_whole_share() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, addresses) = setup_group(&test_env);
+    let newcomer = Address::generate(&test_env.env);
+
+    client.add_group_member(
+        &group_id,
+        &creator,
+        &newcomer,
+        &20,
+        &RebalanceStrategy::Member(addresses.get(2).unwrap()),
+    );
+}
@@ -0,0 +1,148 @@
+use crate::base::types::{GroupMember, ReportBucket, ReportInterval};
+use crate::test_utils::{create_test_group, fund_user_with_tokens, setup_test_env};
+use crate::AutoShareContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Vec};
+
+// 2024-01-31 23:00 UTC and 2024-02-01 01:00 UTC
+const JAN_31: u64 = 1_706_742_000;
+const FEB_01: u64 = 1_706_749_200;
+
+#[test]
+fn test_distributions_fill_daily_and_monthly_buckets() {
+    let test_env = setup_test_env();
+    let env = &test_env.env;
+    let client = AutoShareContractClient::new(env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let sender = test_env.users.get(1).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let member = Address::generate(env);
+    let mut members = Vec::new(env);
+    members.push_back(GroupMember {
+        address: member.clone(),
+        percentage: 60,
+    });
+    members.push_back(GroupMember {
+        address: Address::generate(env),
+        percentage: 40,
+    });
+    let id = create_test_group(
+        env,
+        &test_env.autoshare_contract,
+        &creator,
+        &members,
+        5,
+        &token,
+    );
+    fund_user_with_tokens(env, &token, &sender, 1000);
+
+    env.ledger().set_timestamp(JAN_31);
+    client.distribute(&id, &token, &100, &sender);
+    client.distribute(&id, &token, &200, &sender);
+    env.ledger().set_timestamp(FEB_01);
+    client.distribute(&id, &token, &500, &sender);
+
+    let daily =
+        client.get_group_volume_report(&id, &token, &ReportInterval::Daily, &19_750, &19_760);
+    assert_eq!(
+        daily,
+        Vec::from_array(
+            env,
+            [
+                ReportBucket {
+                    period: 19_753,
+                    amount: 300
+                },
+                ReportBucket {
+                    period: 19_754,
+                    amount: 500
+                }
+            ]
+        )
+    );
+
+    // The range crosses a year boundary
+    let monthly = client.get_member_earnings_report(
+        &member,
+        &token,
+        &ReportInterval::Monthly,
+        &202_312,
+        &202_403,
+    );
+    assert_eq!(monthly.len(), 2);
+    assert_eq!(
+        monthly.get(0).unwrap(),
+        ReportBucket {
+            period: 202_401,
+            amount: 180
+        }
+    );
+    assert_eq!(
+        monthly.get(1).unwrap(),
+        ReportBucket {
+            period: 202_402,
+            amount: 300
+        }
+    );
+
+    // Daily member buckets across the month boundary
+    let daily = client.get_member_earnings_report(
+        &member,
+        &token,
+        &ReportInterval::Daily,
+        &19_750,
+        &19_760,
+    );
+    assert_eq!(
+        daily,
+        Vec::from_array(
+            env,
+            [
+                ReportBucket {
+                    period: 19_753,
+                    amount: 180
+                },
+                ReportBucket {
+                    period: 19_754,
+                    amount: 300
+                }
+            ]
+        )
+    );
+}
+
+#[test]
+fn test_usage_fees_are_reported_per_token() {
+    let test_env = setup_test_env();
+    let env = &test_env.env;
+    let client = AutoShareContractClient::new(env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let id = create_test_group(
+        env,
+        &test_env.autoshare_contract,
+        &creator,
+        &Vec::new(env),
+        3,
+        &token,
+    );
+
+    env.ledger().set_timestamp(FEB_01);
+    client.topup_subscription(&id, &2, &token, &creator);
+
+    let monthly =
+        client.get_fee_revenue_report(&token, &ReportInterval::Monthly, &197_001, &197_001);
+    assert_eq!(monthly.get(0).unwrap().amount, 30);
+    let daily = client.get_fee_revenue_report(&token, &ReportInterval::Daily, &19_754, &19_754);
+    assert_eq!(daily.get(0).unwrap().amount, 20);
+}
+
+#[test]
+#[should_panic(expected = "InvalidInput")]
+fn test_report_range_is_bounded() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let token = test_env.mock_tokens.get(0).unwrap();
+
+    client.get_fee_revenue_report(&token, &ReportInterval::Daily, &0, &1_000);
+}
@@ -0,0 +1,88 @@
+use crate::base::types::GroupMember;
+use crate::test_utils::{
+    create_test_group, create_test_members, fund_user_with_tokens, setup_test_env, TestEnv,
+};
+use crate::AutoShareContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN, Vec};
+
+fn setup_group(test_env: &TestEnv, members: &Vec<GroupMember>) -> (BytesN<32>, Address) {
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let group_id = create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        &creator,
+        members,
+        10,
+        &token,
+    );
+    (group_id, creator)
+}
+
+#[test]
+fn test_each_member_change_records_a_version() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let first_split = create_test_members(&test_env.env, 2);
+    let (group_id, creator) = setup_group(&test_env, &first_split);
+    let versions_before = client.get_split_versions(&group_id).len();
+
+    test_env.env.ledger().set_timestamp(500);
+    let second_split = create_test_members(&test_env.env, 3);
+    client.update_members(&group_id, &creator, &second_split);
+
+    let versions = client.get_split_versions(&group_id);
+    assert_eq!(versions.len(), versions_before + 1);
+
+    let latest = client.get_split_version(&group_id, &versions.len());
+    assert_eq!(latest.version, versions.len());
+    assert_eq!(latest.members, second_split);
+    assert_eq!(latest.author, creator);
+    assert_eq!(latest.created_at, 500);
+
+    // Self-service changes are versioned too, authored by the member
+    let leaver = second_split.get(0).unwrap().address;
+    client.leave_group(&group_id, &leaver);
+    let after_leave = client.get_split_version(&group_id, &(versions.len() + 1));
+    assert_eq!(after_leave.author, leaver);
+    assert_eq!(after_leave.members.len(), 2);
+}
+
+#[test]
+fn test_distribution_references_split_version() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let sender = test_env.users.get(1).unwrap();
+    let (group_id, creator) = setup_group(&test_env, &create_test_members(&test_env.env, 2));
+    fund_user_with_tokens(&test_env.env, &token, &sender, 2000);
+
+    client.distribute(&group_id, &token, &1000, &sender);
+    let first_version = client.get_split_versions(&group_id).len();
+
+    let mut new_split = Vec::new(&test_env.env);
+    new_split.push_back(GroupMember {
+        address: Address::generate(&test_env.env),
+        percentage: 100,
+    });
+    client.update_members(&group_id, &creator, &new_split);
+    client.distribute(&group_id, &token, &1000, &sender);
+
+    let history = client.get_group_distributions(&group_id, &0, &50);
+    assert_eq!(history.get(0).unwrap().split_version, first_version);
+    assert_eq!(history.get(1).unwrap().split_version, first_version + 1);
+
+    let used = client.get_split_version(&group_id, &history.get(1).unwrap().split_version);
+    assert_eq!(used.members, new_split);
+}
+
+#[test]
+#[should_panic(expected = "NotFound")]
+fn test_get_unknown_split_version_fails() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, _creator) = setup_group(&test_env, &create_test_members(&test_env.env, 2));
+
+    client.get_split_version(&group_id, &99);
+}
@@ -63,6 +63,16 @@ pub fn deploy_mock_token(env: &Env, name: &String, symbol: &String) -> Address {
     contract_id
 }
 
+/// Deploys a Stellar asset contract, which unlike MockToken supports allowances.
+pub fn deploy_stellar_asset(env: &Env) -> Address {
+    let issuer = Address::generate(env);
+    env.register_stellar_asset_contract_v2(issuer).address()
+}
+
+pub fn mint_stellar_asset(env: &Env, token: &Address, to: &Address, amount: i128) {
+    soroban_sdk::token::StellarAssetClient::new(env, token).mint(to, &amount);
+}
+
 pub fn mint_tokens(env: &Env, token: &Address, to: &Address, amount: i128) {
     let client = MockTokenClient::new(env, token);
     client.mint(to, &amount);
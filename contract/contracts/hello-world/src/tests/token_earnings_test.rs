@@ -0,0 +1,110 @@
+use crate::autoshare_logic::DataKey;
+use crate::base::types::GroupMember;
+use crate::test_utils::{create_test_group, deploy_mock_token, mint_tokens, setup_test_env};
+use crate::AutoShareContractClient;
+use soroban_sdk::{testutils::Address as _, Address, String, Vec};
+
+#[test]
+fn test_earnings_are_tracked_per_token() {
+    let test_env = setup_test_env();
+    let env = &test_env.env;
+    let client = AutoShareContractClient::new(env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let sender = test_env.users.get(1).unwrap();
+    let token_a = test_env.mock_tokens.get(0).unwrap();
+    let token_b = deploy_mock_token(
+        env,
+        &String::from_str(env, "Second Token"),
+        &String::from_str(env, "SEC"),
+    );
+    client.add_supported_token(&token_b, &test_env.admin);
+
+    let member = Address::generate(env);
+    let mut members = Vec::new(env);
+    members.push_back(GroupMember {
+        address: member.clone(),
+        percentage: 100,
+    });
+    let first = create_test_group(
+        env,
+        &test_env.autoshare_contract,
+        &creator,
+        &members,
+        1,
+        &token_a,
+    );
+    let second = create_test_group(
+        env,
+        &test_env.autoshare_contract,
+        &creator,
+        &members,
+        2,
+        &token_a,
+    );
+
+    mint_tokens(env, &token_a, &sender, 1000);
+    mint_tokens(env, &token_b, &sender, 1000);
+    client.distribute(&first, &token_a, &300, &sender);
+    client.distribute(&second, &token_a, &200, &sender);
+    client.distribute(&second, &token_b, &50, &sender);
+
+    assert_eq!(
+        client.get_member_earnings_by_token(&member, &second, &token_a),
+        200
+    );
+    assert_eq!(
+        client.get_member_earnings_by_token(&member, &second, &token_b),
+        50
+    );
+    assert_eq!(client.get_member_lifetime_earnings(&member, &token_a), 500);
+    assert_eq!(client.get_member_lifetime_earnings(&member, &token_b), 50);
+
+    // A single total only makes sense while a group pays out in one token
+    assert_eq!(client.get_member_earnings(&member, &first), 300);
+    assert!(client.try_get_member_earnings(&member, &second).is_err());
+}
+
+#[test]
+fn test_legacy_earnings_are_migrated_to_a_token() {
+    let test_env = setup_test_env();
+    let env = &test_env.env;
+    let client = AutoShareContractClient::new(env, &test_env.autoshare_contract);
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let member = Address::generate(env);
+    let mut members = Vec::new(env);
+    members.push_back(GroupMember {
+        address: member.clone(),
+        percentage: 100,
+    });
+    let id = create_test_group(
+        env,
+        &test_env.autoshare_contract,
+        &creator,
+        &members,
+        1,
+        &token,
+    );
+
+    env.as_contract(&test_env.autoshare_contract, || {
+        env.storage().persistent().set(
+            &DataKey::MemberGroupEarnings(member.clone(), id.clone()),
+            &700_i128,
+        );
+    });
+    assert_eq!(client.get_member_earnings(&member, &id), 700);
+
+    assert_eq!(
+        client.migrate_member_earnings(&test_env.admin, &member, &id, &token),
+        700
+    );
+    assert_eq!(
+        client.get_member_earnings_by_token(&member, &id, &token),
+        700
+    );
+    assert_eq!(client.get_member_lifetime_earnings(&member, &token), 700);
+    assert_eq!(client.get_member_earnings(&member, &id), 700);
+    assert!(client
+        .try_migrate_member_earnings(&test_env.admin, &member, &id, &token)
+        .is_err());
+}
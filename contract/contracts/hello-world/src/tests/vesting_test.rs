@@ -0,0 +1,212 @@
+use crate::base::types::{GroupMember, UnvestedRedirect, VestingSchedule};
+use crate::test_utils::{create_test_group, fund_user_with_tokens, setup_test_env, TestEnv};
+use crate::AutoShareContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN, Vec};
+
+/// Creates a group split 50/50 between two fresh members.
+fn setup_group(test_env: &TestEnv) -> (BytesN<32>, Address, Address, Address) {
+    let creator = test_env.users.get(0).unwrap();
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let member1 = Address::generate(&test_env.env);
+    let member2 = Address::generate(&test_env.env);
+    let mut members = Vec::new(&test_env.env);
+    members.push_back(GroupMember {
+        address: member1.clone(),
+        percentage: 50,
+    });
+    members.push_back(GroupMember {
+        address: member2.clone(),
+        percentage: 50,
+    });
+    let group_id = create_test_group(
+        &test_env.env,
+        &test_env.autoshare_contract,
+        &creator,
+        &members,
+        10,
+        &token,
+    );
+    (group_id, creator, member1, member2)
+}
+
+fn schedule(redirect: UnvestedRedirect) -> VestingSchedule {
+    VestingSchedule {
+        start: 0,
+        cliff: 250,
+        end: 1000,
+        redirect,
+    }
+}
+
+#[test]
+fn test_unvested_share_goes_to_creator() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let sender = test_env.users.get(1).unwrap();
+    let (group_id, creator, member1, member2) = setup_group(&test_env);
+    fund_user_with_tokens(&test_env.env, &token, &sender, 3000);
+
+    client.set_vesting_schedule(
+        &group_id,
+        &creator,
+        &member2,
+        &schedule(UnvestedRedirect::Creator),
+    );
+    assert_eq!(
+        client.get_vesting_schedule(&group_id, &member2),
+        Some(schedule(UnvestedRedirect::Creator))
+    );
+
+    // Before the cliff nothing of member2's share is vested
+    test_env.env.ledger().set_timestamp(100);
+    client.distribute(&group_id, &token, &1000, &sender);
+    assert_eq!(client.get_member_earnings(&member1, &group_id), 500);
+    assert_eq!(client.get_member_earnings(&member2, &group_id), 0);
+    assert_eq!(client.get_member_earnings(&creator, &group_id), 500);
+
+    // Halfway through the schedule half the share is vested
+    test_env.env.ledger().set_timestamp(500);
+    client.distribute(&group_id, &token, &1000, &sender);
+    assert_eq!(client.get_member_earnings(&member2, &group_id), 250);
+    assert_eq!(client.get_member_earnings(&creator, &group_id), 750);
+
+    // Fully vested afterwards
+    test_env.env.ledger().set_timestamp(1000);
+    client.distribute(&group_id, &token, &1000, &sender);
+    assert_eq!(client.get_member_earnings(&member2, &group_id), 750);
+    assert_eq!(client.get_member_earnings(&creator, &group_id), 750);
+}
+
+#[test]
+fn test_unvested_share_goes_to_vested_members() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let sender = test_env.users.get(1).unwrap();
+    let (group_id, creator, member1, member2) = setup_group(&test_env);
+    fund_user_with_tokens(&test_env.env, &token, &sender, 1000);
+
+    client.set_vesting_schedule(
+        &group_id,
+        &creator,
+        &member2,
+        &schedule(UnvestedRedirect::VestedMembers),
+    );
+    test_env.env.ledger().set_timestamp(500);
+    client.distribute(&group_id, &token, &1000, &sender);
+
+    assert_eq!(client.get_member_earnings(&member1, &group_id), 750);
+    assert_eq!(client.get_member_earnings(&member2, &group_id), 250);
+    assert_eq!(client.get_member_earnings(&creator, &group_id), 0);
+}
+
+#[test]
+fn test_removed_schedule_restores_full_share() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let token = test_env.mock_tokens.get(0).unwrap();
+    let sender = test_env.users.get(1).unwrap();
+    let (group_id, creator, _member1, member2) = setup_group(&test_env);
+    fund_user_with_tokens(&test_env.env, &token, &sender, 1000);
+
+    client.set_vesting_schedule(
+        &group_id,
+        &creator,
+        &member2,
+        &schedule(UnvestedRedirect::Creator),
+    );
+    client.remove_vesting_schedule(&group_id, &creator, &member2);
+    assert_eq!(client.get_vesting_schedule(&group_id, &member2), None);
+
+    client.distribute(&group_id, &token, &1000, &sender);
+    assert_eq!(client.get_member_earnings(&member2, &group_id), 500);
+}
+
+#[test]
+fn test_replaced_member_loses_schedule() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, member1, member2) = setup_group(&test_env);
+
+    client.set_vesting_schedule(
+        &group_id,
+        &creator,
+        &member2,
+        &schedule(UnvestedRedirect::Creator),
+    );
+    let mut members = Vec::new(&test_env.env);
+    members.push_back(GroupMember {
+        address: member1.clone(),
+        percentage: 100,
+    });
+    client.update_members(&group_id, &creator, &members);
+
+    // Adding the address again does not bring the old schedule back
+    let mut members = Vec::new(&test_env.env);
+    for address in [member1, member2.clone()] {
+        members.push_back(GroupMember {
+            address,
+            percentage: 50,
+        });
+    }
+    client.update_members(&group_id, &creator, &members);
+    assert_eq!(client.get_vesting_schedule(&group_id, &member2), None);
+}
+
+#[test]
+#[should_panic(expected = "InvalidInput")]
+fn test_cliff_after_end_rejected() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, member1, _member2) = setup_group(&test_env);
+
+    client.set_vesting_schedule(
+        &group_id,
+        &creator,
+        &member1,
+        &VestingSchedule {
+            start: 0,
+            cliff: 2000,
+            end: 1000,
+            redirect: UnvestedRedirect::Creator,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "MemberNotFound")]
+fn test_schedule_requires_member() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, _member1, _member2) = setup_group(&test_env);
+
+    client.set_vesting_schedule(
+        &group_id,
+        &creator,
+        &Address::generate(&test_env.env),
+        &schedule(UnvestedRedirect::Creator),
+    );
+}
+
+#[test]
+fn test_schedule_requires_member_authorization() {
+    let test_env = setup_test_env();
+    let client = AutoShareContractClient::new(&test_env.env, &test_env.autoshare_contract);
+    let (group_id, creator, _member1, member2) = setup_group(&test_env);
+
+    client.set_vesting_schedule(
+        &group_id,
+        &creator,
+        &member2,
+        &schedule(UnvestedRedirect::Creator),
+    );
+
+    let authorized: Vec<Address> = Vec::from_iter(
+        &test_env.env,
+        test_env.env.auths().into_iter().map(|(address, _)| address),
+    );
+    assert!(authorized.contains(&creator));
+    assert!(authorized.contains(&member2));
+}